@@ -11,16 +11,30 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use crate::{actions::invoke::InvokeResult, common::tag::Tag};
+use crate::{actions::invoke::InvokeResult, common::tag::Tag, program::StepControl};
 
+use kyron::core::types::UniqueWorkerId;
 use kyron::futures::reusable_box_future::{ReusableBoxFuture, ReusableBoxFuturePool};
+#[cfg(not(any(test, feature = "runtime-api-mock")))]
+use kyron::safety;
+#[cfg(not(any(test, feature = "runtime-api-mock")))]
+use kyron::safety::spawn_from_reusable_on_dedicated;
+#[cfg(any(test, feature = "runtime-api-mock"))]
+use kyron::testing::mock::safety;
+#[cfg(any(test, feature = "runtime-api-mock"))]
+use kyron::testing::mock::spawn_from_reusable_on_dedicated;
 use kyron::JoinHandle;
-use kyron_foundation::prelude::CommonErrors;
+use kyron_foundation::prelude::{CommonErrors, FoundationAtomicBool};
 
 use ::core::{
     fmt::{Debug, Formatter},
+    future::{poll_fn, Future},
     ops::Deref,
+    pin::Pin,
+    sync::atomic::Ordering,
+    task::{Context, Poll},
 };
+use std::sync::Arc;
 
 /// Represents a user-defined error value that can be propagated through the action execution chain.
 /// This allows user code to signal specific errors that can be handled or logged by the orchestrator.
@@ -47,6 +61,16 @@ impl From<UserErrValue> for InvokeResult {
     }
 }
 
+/// Maps typed user error into [`UserErrValue`] and back, so handlers can match on meaningful variants instead of raw codes.
+/// Usually implemented by `#[derive(OrchUserError)]` from `orchestration_macros`.
+pub trait OrchUserError: Sized {
+    /// Converts the error into its [`UserErrValue`] code.
+    fn to_user_err_value(&self) -> UserErrValue;
+
+    /// Converts [`UserErrValue`] code back into the error. Returns `None` when code does not belong to this error type.
+    fn from_user_err_value(value: UserErrValue) -> Option<Self>;
+}
+
 #[allow(clippy::from_over_into)]
 impl Into<ActionExecError> for UserErrValue {
     fn into(self) -> ActionExecError {
@@ -59,13 +83,18 @@ impl Into<ActionExecError> for UserErrValue {
 /// Variants:
 /// - `UserError(UserErrValue)`: Indicates an error returned by user code, allowing it to propagate through the chain. It means signature to `Invoke` needs to capture Futures/functions with Result<(), UserErrValue>
 /// - `NonRecoverableFailure`: Represents a failure that cannot be recovered from.
+/// - `UserPanic(UserErrValue)`: User code panicked and the panic was converted into an error, see `InvokePanicPolicy`.
 /// - `Internal`: Placeholder for internal errors, with potential for expansion as needed.
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// - `Multiple(Vec<(Tag, ActionExecError)>)`: Errors of all failed branches of `Concurrency` or nodes of
+///   `LocalGraphAction` collecting them, with the tag of the failed action, in the order they failed.
+#[derive(Clone, Debug, PartialEq)]
 pub enum ActionExecError {
     UserError(UserErrValue),
     NonRecoverableFailure,
     Timeout,
+    UserPanic(UserErrValue),
     Internal, // TODO add more errors if needed
+    Multiple(Vec<(Tag, ActionExecError)>),
 }
 
 ///
@@ -73,6 +102,41 @@ pub enum ActionExecError {
 ///
 pub type ActionResult = Result<(), ActionExecError>;
 
+/// Switch of the program an action belongs to, enabling detailed tracing of the action, see
+/// [`crate::api::OrchProgramManager::set_trace`]. Actions not attached to a program are always traced.
+#[derive(Clone, Default)]
+pub struct TraceSwitch(Option<Arc<FoundationAtomicBool>>);
+
+impl TraceSwitch {
+    pub(crate) fn new(switch: Arc<FoundationAtomicBool>) -> Self {
+        Self(Some(switch))
+    }
+
+    /// Returns `true` if detailed tracing of the action is enabled.
+    pub fn is_on(&self) -> bool {
+        self.0.as_ref().is_none_or(|switch| switch.load(Ordering::Relaxed))
+    }
+}
+
+/// Debug stepping gate of the program an action belongs to, see [`crate::api::ProgramStepper`]. Attached only to the
+/// run action of the program, so only steps of a top-level `Sequence` wait on it. Actions not attached to a program
+/// are never stopped.
+#[derive(Clone, Default)]
+pub struct StepGate(Option<Arc<StepControl>>);
+
+impl StepGate {
+    pub(crate) fn new(control: Arc<StepControl>) -> Self {
+        Self(Some(control))
+    }
+
+    /// Waits until the `step` with `tag` may be executed, immediately if stepping of the program is disabled.
+    pub(crate) async fn wait(&self, step: usize, tag: Tag) {
+        if let Some(control) = &self.0 {
+            poll_fn(|cx| control.poll_step(step, tag, cx)).await
+        }
+    }
+}
+
 ///
 /// Result to indicate the acquisition status of the reusable (boxed) future. [`Ok(ReusableBoxFuture<ActionResult>)`] if everything went fine, Err(CommonErrors) to mark error in execution.
 ///
@@ -96,10 +160,30 @@ pub trait ActionTrait: Send {
     ///
     fn name(&self) -> &'static str;
 
+    ///
+    /// Provide tag identifying the action in error reports, see `ActionExecError::Multiple`. Invokes return the tag
+    /// they were registered with, other actions the tag created from their name.
+    ///
+    fn tag(&self) -> Tag {
+        Tag::from_str_static(self.name())
+    }
+
     ///
     /// Since we store actions behind dyn ActionTrait, we need an API that we can call from program to print constructed representation
     ///
     fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result;
+
+    ///
+    /// Attaches the trace switch of the program the action belongs to. Actions with child actions shall forward it to
+    /// them, so all actions of the program are traced together.
+    ///
+    fn attach_trace_switch(&mut self, _switch: &TraceSwitch) {}
+
+    ///
+    /// Attaches the debug stepping gate of the program to its run action. Actions executing their children step by
+    /// step (the `Sequence`) wait on it before each step, it is not forwarded to nested actions.
+    ///
+    fn attach_step_gate(&mut self, _gate: &StepGate) {}
 }
 
 pub struct ActionBaseMeta {
@@ -113,12 +197,25 @@ impl Debug for ActionBaseMeta {
     }
 }
 
+/// Defines how child actions of `Concurrency`, `LocalGraphAction` and `Select` are executed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ExecutionStrategy {
+    /// Each child action is spawned as a separate task.
+    #[default]
+    Spawn,
+    /// Child actions are polled inline by the parent action's task. This avoids task queuing latency
+    /// for small sets of child actions, e.g. on a single worker runtime. Actions bound to a worker
+    /// (like `Invoke` with a worker binding) still spawn their work on that worker.
+    Inline,
+}
+
 /// Represents the state of an action's execution.
-/// Can be empty, a future, or a running handle.
+/// Can be empty, a future, a running handle or a future polled inline.
 pub enum ActionMeta {
     Empty,
     Future(ReusableBoxFuture<ActionResult>),
     Handle(JoinHandle<ActionResult>),
+    Inline(Pin<ReusableBoxFuture<ActionResult>>),
 }
 
 impl ActionMeta {
@@ -147,4 +244,53 @@ impl ActionMeta {
     pub fn clear(&mut self) {
         *self = ActionMeta::Empty;
     }
+
+    /// Cancels the action and clears the ActionMeta. A spawned action is aborted, so it releases its pooled future
+    /// instead of running detached, an inline or not started one is dropped.
+    pub fn abort(&mut self) {
+        if let ActionMeta::Handle(handle) = self {
+            handle.abort();
+        }
+        self.clear();
+    }
+
+    /// Starts the future according to the given strategy, either spawning it or pinning it for inline polling.
+    /// Returns `false` if there is no future to start.
+    pub fn start(&mut self, strategy: ExecutionStrategy) -> bool {
+        let Some(future) = self.take_future() else {
+            return false;
+        };
+
+        match strategy {
+            ExecutionStrategy::Spawn => self.assign_handle(safety::spawn_from_reusable(future)),
+            ExecutionStrategy::Inline => *self = ActionMeta::Inline(future.into_pin()),
+        }
+        true
+    }
+
+    /// Spawns the future on the dedicated worker with `worker_id`.
+    /// Returns `false` if there is no future to start.
+    pub fn start_on_dedicated(&mut self, worker_id: UniqueWorkerId) -> bool {
+        let Some(future) = self.take_future() else {
+            return false;
+        };
+
+        self.assign_handle(spawn_from_reusable_on_dedicated(future, worker_id));
+        true
+    }
+
+    /// Polls the started action, either its join handle or the inline future.
+    /// Returns `None` if the action is not started (or already finished).
+    pub fn poll_started(&mut self, cx: &mut Context<'_>) -> Option<Poll<ActionResult>> {
+        match self {
+            // The JoinResult error is not the future error, so map it to internal one
+            ActionMeta::Handle(handle) => Some(
+                Pin::new(handle)
+                    .poll(cx)
+                    .map(|res| res.unwrap_or(Err(ActionExecError::Internal))),
+            ),
+            ActionMeta::Inline(future) => Some(future.as_mut().poll(cx)),
+            ActionMeta::Empty | ActionMeta::Future(_) => None,
+        }
+    }
 }
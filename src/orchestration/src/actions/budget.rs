@@ -0,0 +1,347 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionResult, ActionTrait, ReusableBoxFutureResult};
+use crate::{api::design::Design, common::tag::Tag};
+use ::core::{
+    future::poll_fn,
+    task::{Poll, Waker},
+    time::Duration,
+};
+use kyron::{
+    futures::{reusable_box_future::ReusableBoxFuturePool, sleep},
+    time::clock::{Clock, Instant},
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+///
+/// Token bucket limiting the rate of a resource shared by programs, e.g. accelerator invocations per second.
+/// Bucket holds up to `capacity` tokens and gets one token back every `refill_period`.
+/// Waiters are served in FIFO order, so small requests cannot starve large ones.
+///
+pub(crate) struct TokenBucket {
+    capacity: u32,
+    available: u32,
+    refill_period: Duration,
+    last_refill: Option<Instant>,
+    carry: Duration, // Time accumulated towards the next token
+    waiters: VecDeque<(u64, Option<Waker>)>,
+    next_ticket: u64,
+}
+
+impl TokenBucket {
+    pub(crate) fn new(capacity: u32, refill_period: Duration) -> Self {
+        assert!(capacity > 0, "Token bucket capacity shall be greater than zero.");
+        assert!(
+            !refill_period.is_zero(),
+            "Token bucket refill period shall be greater than zero."
+        );
+
+        Self {
+            capacity,
+            available: capacity,
+            refill_period,
+            last_refill: None,
+            carry: Duration::ZERO,
+            waiters: VecDeque::new(),
+            next_ticket: 0,
+        }
+    }
+
+    /// Puts a new waiter at the end of the queue and returns its ticket.
+    fn enqueue(&mut self) -> u64 {
+        let ticket = self.next_ticket;
+        self.next_ticket += 1;
+        self.waiters.push_back((ticket, None));
+        ticket
+    }
+
+    /// Ready when `ticket` is first in the queue, otherwise `waker` is woken once the waiter gets to the front.
+    fn poll_turn(&mut self, ticket: u64, waker: &Waker) -> Poll<()> {
+        match self.waiters.iter_mut().position(|(waiting, _)| *waiting == ticket) {
+            Some(0) | None => Poll::Ready(()),
+            Some(index) => {
+                let slot = &mut self.waiters[index].1;
+                if !slot.as_ref().is_some_and(|registered| registered.will_wake(waker)) {
+                    *slot = Some(waker.clone());
+                }
+                Poll::Pending
+            },
+        }
+    }
+
+    /// Removes `ticket` from the queue, returns waker of the next waiter if it got to the front.
+    fn dequeue(&mut self, ticket: u64) -> Option<Waker> {
+        let index = self.waiters.iter().position(|(waiting, _)| *waiting == ticket)?;
+        self.waiters.remove(index);
+        if index == 0 {
+            self.waiters.front_mut().and_then(|(_, waker)| waker.take())
+        } else {
+            None
+        }
+    }
+
+    /// Takes `tokens` if available, otherwise returns time after which it makes sense to retry.
+    fn try_acquire(&mut self, tokens: u32) -> Result<(), Duration> {
+        let now = Clock::now();
+        let elapsed = self
+            .last_refill
+            .map_or(Duration::ZERO, |last| now.saturating_duration_since(last));
+        self.last_refill = Some(now);
+
+        self.try_acquire_after(tokens, elapsed)
+    }
+
+    fn try_acquire_after(&mut self, tokens: u32, elapsed: Duration) -> Result<(), Duration> {
+        self.refill(elapsed);
+
+        if self.available >= tokens {
+            self.available -= tokens;
+            Ok(())
+        } else {
+            let missing = tokens - self.available;
+            Err(self.refill_period * missing - self.carry)
+        }
+    }
+
+    fn refill(&mut self, elapsed: Duration) {
+        let total = self.carry + elapsed;
+        let new_tokens = total.as_nanos() / self.refill_period.as_nanos();
+        let available = (self.available as u128 + new_tokens).min(self.capacity as u128) as u32;
+
+        self.carry = if available == self.capacity {
+            Duration::ZERO
+        } else {
+            total - self.refill_period * (new_tokens as u32)
+        };
+        self.available = available;
+    }
+}
+
+///
+/// Place of a single `AcquireBudget` execution in the waiter queue of the bucket. Leaves the queue when dropped, so a
+/// finished or aborted execution never blocks the waiters behind it.
+///
+struct QueueTicket {
+    bucket: Arc<Mutex<TokenBucket>>,
+    ticket: u64,
+}
+
+impl QueueTicket {
+    fn enqueue(bucket: Arc<Mutex<TokenBucket>>) -> Self {
+        let ticket = bucket.lock().unwrap().enqueue();
+        Self { bucket, ticket }
+    }
+
+    /// Waits until all earlier waiters left the queue.
+    async fn first_in_queue(&self) {
+        poll_fn(|cx| self.bucket.lock().unwrap().poll_turn(self.ticket, cx.waker())).await
+    }
+}
+
+impl Drop for QueueTicket {
+    fn drop(&mut self) {
+        let next = self.bucket.lock().unwrap().dequeue(self.ticket);
+        if let Some(waker) = next {
+            waker.wake();
+        }
+    }
+}
+
+///
+/// Builder for [`AcquireBudget`] action.
+///
+pub struct AcquireBudgetBuilder {
+    tag: Tag,
+    tokens: u32,
+}
+
+impl AcquireBudgetBuilder {
+    /// Creates a builder for action taking `tokens` from the rate limiter registered under `tag` with
+    /// [`Design::register_rate_limiter`].
+    pub fn new(tag: Tag, tokens: u32) -> Self {
+        Self { tag, tokens }
+    }
+
+    /// Builds the `AcquireBudget` action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no rate limiter is registered under the tag, or if requested tokens exceed its capacity.
+    pub fn build(self, design: &Design) -> Box<AcquireBudget> {
+        let Self { tag, tokens } = self;
+        let bucket = design.rate_limiter(tag);
+        assert!(
+            bucket.is_some(),
+            "Failed to create AcquireBudget with tag {:?}, rate limiter is not registered in design {:?}.",
+            tag,
+            design
+        );
+
+        let bucket = bucket.unwrap();
+        assert!(
+            tokens <= bucket.lock().unwrap().capacity,
+            "AcquireBudget with tag {:?} requests more tokens than capacity of the rate limiter.",
+            tag
+        );
+
+        Box::new(AcquireBudget {
+            base: ActionBaseMeta {
+                tag,
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    AcquireBudget::execute_impl(Arc::clone(&bucket), tokens),
+                ),
+            },
+            bucket,
+            tokens,
+        })
+    }
+}
+
+///
+/// Waits until requested number of tokens is available in the shared rate limiter and takes them. Programs using
+/// the same rate limiter contend for it, so the resource guarded by it is not used more often than configured.
+/// Executions are served in the order they started waiting.
+///
+pub struct AcquireBudget {
+    base: ActionBaseMeta,
+    bucket: Arc<Mutex<TokenBucket>>,
+    tokens: u32,
+}
+
+impl AcquireBudget {
+    async fn execute_impl(bucket: Arc<Mutex<TokenBucket>>, tokens: u32) -> ActionResult {
+        let ticket = QueueTicket::enqueue(Arc::clone(&bucket));
+        ticket.first_in_queue().await;
+
+        loop {
+            let result = bucket.lock().unwrap().try_acquire(tokens);
+            match result {
+                Ok(()) => return Ok(()),
+                Err(retry_after) => sleep::sleep(retry_after).await,
+            }
+        }
+    }
+}
+
+impl ActionTrait for AcquireBudget {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base
+            .reusable_future_pool
+            .next(AcquireBudget::execute_impl(Arc::clone(&self.bucket), self.tokens))
+    }
+
+    fn name(&self) -> &'static str {
+        "AcquireBudget"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{common::DesignConfig, testing::OrchTestingPoller};
+
+    #[test]
+    fn bucket_refills_one_token_per_period() {
+        let mut bucket = TokenBucket::new(2, Duration::from_millis(10));
+
+        assert_eq!(bucket.try_acquire_after(2, Duration::ZERO), Ok(()));
+        assert_eq!(
+            bucket.try_acquire_after(1, Duration::from_millis(4)),
+            Err(Duration::from_millis(6))
+        );
+        assert_eq!(bucket.try_acquire_after(1, Duration::from_millis(6)), Ok(()));
+        assert_eq!(
+            bucket.try_acquire_after(2, Duration::from_millis(15)),
+            Err(Duration::from_millis(5))
+        );
+    }
+
+    #[test]
+    fn bucket_does_not_exceed_capacity() {
+        let mut bucket = TokenBucket::new(2, Duration::from_millis(10));
+
+        assert_eq!(bucket.try_acquire_after(1, Duration::from_secs(10)), Ok(()));
+        assert_eq!(bucket.try_acquire_after(1, Duration::ZERO), Ok(()));
+        assert_eq!(
+            bucket.try_acquire_after(1, Duration::ZERO),
+            Err(Duration::from_millis(10))
+        );
+    }
+
+    #[test]
+    fn bucket_serves_waiters_in_order() {
+        let mut bucket = TokenBucket::new(2, Duration::from_millis(10));
+        let waker = kyron::testing::get_task_based_waker();
+
+        let first = bucket.enqueue();
+        let second = bucket.enqueue();
+        let third = bucket.enqueue();
+        assert_eq!(bucket.poll_turn(first, &waker), Poll::Ready(()));
+        assert_eq!(bucket.poll_turn(second, &waker), Poll::Pending);
+        assert_eq!(bucket.poll_turn(third, &waker), Poll::Pending);
+
+        // Leaving from the middle of the queue does not let anyone in
+        assert!(bucket.dequeue(third).is_none());
+        assert_eq!(bucket.poll_turn(second, &waker), Poll::Pending);
+
+        assert!(bucket.dequeue(first).is_some());
+        assert_eq!(bucket.poll_turn(second, &waker), Poll::Ready(()));
+    }
+
+    #[test]
+    fn acquire_budget_waits_for_earlier_waiter() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design
+            .register_rate_limiter("accelerator".into(), 2, Duration::from_secs(1))
+            .unwrap();
+        let earlier = QueueTicket::enqueue(design.rate_limiter("accelerator".into()).unwrap());
+
+        let mut action = AcquireBudgetBuilder::new("accelerator".into(), 1).build(&design);
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+
+        // Tokens are available, but the earlier waiter is served first
+        assert_eq!(poller.poll(), Poll::Pending);
+
+        drop(earlier);
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn acquire_budget_completes_when_tokens_are_available() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design
+            .register_rate_limiter("accelerator".into(), 2, Duration::from_secs(1))
+            .unwrap();
+
+        let mut action = AcquireBudgetBuilder::new("accelerator".into(), 2).build(&design);
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    #[should_panic(expected = "rate limiter is not registered")]
+    fn acquire_budget_panics_for_unknown_rate_limiter() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let _ = AcquireBudgetBuilder::new("accelerator".into(), 1).build(&design);
+    }
+}
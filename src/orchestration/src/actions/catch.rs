@@ -12,7 +12,7 @@
 // *******************************************************************************
 
 #![allow(dead_code)]
-use ::core::ops::BitOr;
+use ::core::ops::{BitOr, RangeInclusive};
 use std::sync::{Arc, Mutex};
 
 use kyron::futures::reusable_box_future::*;
@@ -21,14 +21,49 @@ use kyron_foundation::not_recoverable_error;
 use kyron_foundation::prelude::*;
 
 use crate::api::design::Design;
+use crate::common::tag::Tag;
 
 use super::action::*;
 
 // Error that will be propagated to user handler
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum HandlerErrors {
     UserErr(UserErrValue),
     Timeout,
+    Internal(InternalDiagnostic),
+    UserPanic(UserErrValue),
+    /// Errors of all failed branches with the tags of their actions, see [`ErrorFilter::MultipleErrors`].
+    Multiple(std::vec::Vec<(Tag, ActionExecError)>),
+}
+
+/// Origin of an internal error handled by `Catch`, see [`ErrorFilter::Internals`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InternalErrorCode {
+    /// The guarded action failed with `ActionExecError::Internal`, e.g. its spawned task could not be joined.
+    Execution,
+    /// No future of the guarded action was available, e.g. its reusable future pool was exhausted.
+    FutureUnavailable,
+}
+
+/// Details of an internal error passed to the handler of `Catch`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InternalDiagnostic {
+    pub code: InternalErrorCode,
+    /// Tag of the action guarded by the `Catch`, see `ActionTrait::tag`.
+    pub tag: Tag,
+}
+
+impl HandlerErrors {
+    /// Converts user error into typed error `E`. Returns `None` for timeouts or codes not belonging to `E`.
+    pub fn downcast<E: OrchUserError>(&self) -> Option<E> {
+        match self {
+            HandlerErrors::UserErr(user_err) => E::from_user_err_value(*user_err),
+            HandlerErrors::Timeout
+            | HandlerErrors::Internal(_)
+            | HandlerErrors::UserPanic(_)
+            | HandlerErrors::Multiple(_) => None,
+        }
+    }
 }
 
 /// Filter for which catch action shall react. This supports bitwise-or `|`.
@@ -40,8 +75,39 @@ pub enum ErrorFilter {
 
     /// Catch action will handle timeouts that are monitored by [`Timeout`] action
     Timeouts = 0x2,
+
+    /// Catch action will handle internal errors of the guarded action, see [`InternalErrorCode`]
+    Internals = 0x4,
+
+    /// Catch action will handle panics of user code converted into errors, see [`super::invoke::InvokePanicPolicy`]
+    UserPanics = 0x8,
+
+    /// Catch action will handle errors of all failed branches collected by `Concurrency` or `LocalGraphAction`, see
+    /// `ConcurrencyBuilder::collect_errors`
+    MultipleErrors = 0x10,
+}
+
+/// Narrows [`ErrorFilter::UserErrors`] to a set of user error codes, see [`CatchBuilder::with_user_error_codes`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserErrorCodes {
+    /// Only the listed codes are handled.
+    List(&'static [u64]),
+    /// Only codes within the inclusive range are handled.
+    Range(RangeInclusive<u64>),
+}
+
+impl UserErrorCodes {
+    fn contains(&self, user_err: UserErrValue) -> bool {
+        match self {
+            UserErrorCodes::List(codes) => codes.contains(&*user_err),
+            UserErrorCodes::Range(range) => range.contains(&*user_err),
+        }
+    }
 }
 
+/// Translates a filtered error into another one, e.g. from subsystem specific codes into the program's error taxonomy
+pub type ErrorMapper = fn(HandlerErrors) -> HandlerErrors;
+
 /// Use [`ErrorFilter`] with bitwise-or (or .into()) to create a set of filters
 #[derive(Debug, Clone, Copy)]
 pub struct ErrorFilters(u64);
@@ -57,23 +123,30 @@ impl ErrorFilters {
 /// The `Catch` action ensures that errors are either handled or propagated further down the chain.
 ///
 /// # Key Features
-/// - Supports filtering specific error types using `ErrorFilter`.
+/// - Supports filtering specific error types using `ErrorFilter`, including internal errors so supervisory programs
+///   can react on them, e.g. by restarting a program.
 /// - Allows attaching recoverable and non-recoverable error handlers.
+/// - Translates filtered errors using optional [`ErrorMapper`] before they reach the handler or get propagated.
 /// - Propagates unhandled errors to the next action in the chain.
 ///
 pub struct Catch {
     base: ActionBaseMeta,
 
     filters: ErrorFilters,
+    user_error_codes: Option<UserErrorCodes>,
     action: Box<dyn ActionTrait>,
+    action_tag: Tag,
     handler: HandlerType,
+    error_mapper: Option<ErrorMapper>,
 }
 
 /// `CatchBuilder` is a builder for creating a `Catch` action.
 pub struct CatchBuilder {
     filters: ErrorFilters,
+    user_error_codes: Option<UserErrorCodes>,
     action: Option<Box<dyn ActionTrait>>,
     handler: HandlerType,
+    error_mapper: Option<ErrorMapper>,
 }
 
 impl CatchBuilder {
@@ -89,8 +162,10 @@ impl CatchBuilder {
     pub fn new(filters: ErrorFilters, action: Box<dyn ActionTrait>) -> Self {
         Self {
             filters,
+            user_error_codes: None,
             action: Some(action),
             handler: HandlerType::None,
+            error_mapper: None,
         }
     }
 
@@ -148,37 +223,120 @@ impl CatchBuilder {
         self
     }
 
+    /// Attaches a recoverable error handler, which can recover at most `max_recoveries` times during the lifetime of
+    /// the `Catch` action, e.g. one program run. Once the limit is reached, filtered errors are escalated to
+    /// `ActionExecError::NonRecoverableFailure` without calling the handler.
+    ///
+    /// # Parameters
+    /// - `max_recoveries`: Number of recoveries allowed. Errors the handler decided to propagate are not counted.
+    /// - `handler`: A closure that takes a `HandlerErrors` parameter and returns a `bool`, like in [`CatchBuilder::catch_recoverable`].
+    ///
+    /// # Returns
+    /// The `CatchBuilder` instance.
+    ///
+    /// # Panics
+    /// Panics if a handler is already attached.
+    ///
+    pub fn catch_recoverable_with_limit<H>(mut self, max_recoveries: usize, handler: H) -> Self
+    where
+        H: FnMut(HandlerErrors) -> bool + Send + 'static,
+    {
+        assert!(
+            self.handler.is_none(),
+            "Catch: Cannot set handler multiple times, this will cause an error in execution."
+        );
+
+        self.handler = HandlerType::BoundedRecoverable(Arc::new(Mutex::new(BoundedHandler {
+            handler: Box::new(handler),
+            remaining: max_recoveries,
+        })));
+        self
+    }
+
+    /// Attaches an error mapper to the `CatchBuilder`. Each filtered error is translated by the mapper before it is
+    /// passed to the handler or propagated down the chain. Without a handler, `Catch` only translates the filtered errors.
+    ///
+    /// # Parameters
+    /// - `mapper`: A function translating `HandlerErrors` into the error taxonomy of the outer program.
+    ///
+    /// # Returns
+    /// The `CatchBuilder` instance.
+    ///
+    /// # Panics
+    /// Panics if a mapper is already attached.
+    ///
+    pub fn map_error(mut self, mapper: ErrorMapper) -> Self {
+        assert!(
+            self.error_mapper.is_none(),
+            "Catch: Cannot set error mapper multiple times, this will cause an error in execution."
+        );
+
+        self.error_mapper = Some(mapper);
+        self
+    }
+
+    /// Narrows handled user errors to given `codes`, so distinct handlers can be attached for distinct code sets by
+    /// nesting `Catch` actions. Other user errors are propagated down the chain.
+    ///
+    /// # Parameters
+    /// - `codes`: The list or range of user error codes to handle.
+    ///
+    /// # Returns
+    /// The `CatchBuilder` instance.
+    ///
+    /// # Panics
+    /// Panics if codes are already set or if filters do not contain `ErrorFilter::UserErrors`.
+    ///
+    pub fn with_user_error_codes(mut self, codes: UserErrorCodes) -> Self {
+        assert!(
+            self.user_error_codes.is_none(),
+            "Catch: Cannot set user error codes multiple times, this will cause an error in execution."
+        );
+        assert!(
+            self.filters.is_filter_enabled(ErrorFilter::UserErrors),
+            "Catch: User error codes require ErrorFilter::UserErrors filter."
+        );
+
+        self.user_error_codes = Some(codes);
+        self
+    }
+
     /// Builds the `Catch` action.
     ///
     /// # Returns
     /// A `Box<Catch>` instance that wraps the specified action and handles errors based on the configured filters and handlers.
     ///
     /// # Panics
-    /// Panics if no handler is attached.
+    /// Panics if neither handler nor error mapper is attached.
     ///
     pub fn build(mut self, design: &Design) -> Box<Catch> {
         assert!(
-            !self.handler.is_none(),
+            !self.handler.is_none() || self.error_mapper.is_some(),
             "Catch: No handler provided, this will cause an error in execution."
         );
 
         let mut lp = ReusableBoxFuturePool::for_value(1, async move { Ok(()) });
         let action = lp.next(async { Ok(()) }).unwrap();
+        let guarded = self
+            .action
+            .take()
+            .expect("CatchBuilder: Action must be set before building");
+        let action_tag = guarded.tag();
 
         Box::new(Catch {
             base: ActionBaseMeta {
                 tag: "orch::internal::catch_action".into(),
                 reusable_future_pool: ReusableBoxFuturePool::for_value(
                     design.config.max_concurrent_action_executions,
-                    Catch::execute_impl(action, HandlerType::None, self.filters),
+                    Catch::execute_impl(Some(action), action_tag, HandlerType::None, None, self.filters, None),
                 ),
             },
             filters: self.filters,
-            action: self
-                .action
-                .take()
-                .expect("CatchBuilder: Action must be set before building"),
+            user_error_codes: self.user_error_codes.take(),
+            action: guarded,
+            action_tag,
             handler: self.handler.clone(),
+            error_mapper: self.error_mapper,
         })
     }
 }
@@ -188,6 +346,13 @@ enum HandlerType {
     None,
     Recoverable(Arc<Mutex<dyn FnMut(HandlerErrors) -> bool + Send>>),
     NonRecoverable(Arc<Mutex<dyn FnMut(HandlerErrors) -> bool + Send>>), // Consider sth else than mutex
+    BoundedRecoverable(Arc<Mutex<BoundedHandler>>),
+}
+
+/// Recoverable handler with the number of recoveries left, see [`CatchBuilder::catch_recoverable_with_limit`].
+struct BoundedHandler {
+    handler: Box<dyn FnMut(HandlerErrors) -> bool + Send>,
+    remaining: usize,
 }
 
 unsafe impl Send for HandlerType {} // underlying type is send so this can also be send
@@ -202,6 +367,9 @@ impl From<HandlerErrors> for ActionExecError {
         match e {
             HandlerErrors::UserErr(user_err) => ActionExecError::UserError(user_err),
             HandlerErrors::Timeout => ActionExecError::Timeout,
+            HandlerErrors::Internal(_) => ActionExecError::Internal,
+            HandlerErrors::UserPanic(code) => ActionExecError::UserPanic(code),
+            HandlerErrors::Multiple(errors) => ActionExecError::Multiple(errors),
         }
     }
 }
@@ -223,9 +391,12 @@ impl Into<ErrorFilters> for ErrorFilter {
 
 impl Catch {
     async fn execute_impl(
-        action: ReusableBoxFuture<ActionResult>,
+        action: Option<ReusableBoxFuture<ActionResult>>,
+        action_tag: Tag,
         handler: HandlerType,
+        error_mapper: Option<ErrorMapper>,
         filters: ErrorFilters,
+        user_error_codes: Option<UserErrorCodes>,
     ) -> ActionResult {
         // How does it work:
         // There are two cases for error source: Return error from user Invoke or Timeout from `Timeout` action..
@@ -237,17 +408,35 @@ impl Catch {
         // 2. Timeout from `Timeout` action: Timeout actions is always spawning a new task for connected action to be sure it cannot be blocked by any `synchronous` action (like Sequence with Invoke).
         // When timeout is detected, task in which `Timeout` was created will be bring back into safety worker, return error as Timeout and then `Catch` action will be executed eventually (as above)
         //
+        // Internal errors are handled only with `ErrorFilter::Internals`, which also covers the case when no future of the guarded action was available.
+
+        let internal = |code| HandlerErrors::Internal(InternalDiagnostic { code, tag: action_tag });
+        let Some(action) = action else {
+            return Self::handle_filtered_error(handler, error_mapper, internal(InternalErrorCode::FutureUnavailable));
+        };
 
         let res = action.into_pin().await;
 
         // Checks errors from actions, this action acts as error filter to call reaction
         match res {
             Ok(_) => Ok(()),
-            Err(ActionExecError::UserError(user_error)) if filters.is_filter_enabled(ErrorFilter::UserErrors) => {
-                Self::handle_user_action(handler, HandlerErrors::UserErr(user_error))
+            Err(ActionExecError::UserError(user_error))
+                if filters.is_filter_enabled(ErrorFilter::UserErrors)
+                    && user_error_codes.as_ref().is_none_or(|codes| codes.contains(user_error)) =>
+            {
+                Self::handle_filtered_error(handler, error_mapper, HandlerErrors::UserErr(user_error))
             },
             Err(ActionExecError::Timeout) if filters.is_filter_enabled(ErrorFilter::Timeouts) => {
-                Self::handle_user_action(handler, HandlerErrors::Timeout)
+                Self::handle_filtered_error(handler, error_mapper, HandlerErrors::Timeout)
+            },
+            Err(ActionExecError::Internal) if filters.is_filter_enabled(ErrorFilter::Internals) => {
+                Self::handle_filtered_error(handler, error_mapper, internal(InternalErrorCode::Execution))
+            },
+            Err(ActionExecError::UserPanic(code)) if filters.is_filter_enabled(ErrorFilter::UserPanics) => {
+                Self::handle_filtered_error(handler, error_mapper, HandlerErrors::UserPanic(code))
+            },
+            Err(ActionExecError::Multiple(errors)) if filters.is_filter_enabled(ErrorFilter::MultipleErrors) => {
+                Self::handle_filtered_error(handler, error_mapper, HandlerErrors::Multiple(errors))
             },
             Err(e) => {
                 error!("Catch: Not filtered error in action execution: {:?}, propagating.", e);
@@ -256,12 +445,27 @@ impl Catch {
         }
     }
 
+    fn handle_filtered_error(
+        handler: HandlerType,
+        error_mapper: Option<ErrorMapper>,
+        e: HandlerErrors,
+    ) -> ActionResult {
+        let e = error_mapper.map_or(e, |mapper| mapper(e));
+
+        if handler.is_none() {
+            // Only translation of the error was requested
+            return Err(ActionExecError::from(e));
+        }
+
+        Self::handle_user_action(handler, e)
+    }
+
     fn handle_user_action(mut handler: HandlerType, e: HandlerErrors) -> ActionResult {
         match handler {
             HandlerType::None => not_recoverable_error!("Catch: Cannot be here, we assured this during builder phase."),
             HandlerType::Recoverable(ref mut user_handler) => {
                 let mut handler = user_handler.lock().unwrap();
-                if handler(e) {
+                if handler(e.clone()) {
                     Ok(())
                 } else {
                     Err(ActionExecError::from(e)) // Keep  the error as is, maybe someone below can handle it
@@ -272,25 +476,67 @@ impl Catch {
                 handler(e);
                 Err(ActionExecError::NonRecoverableFailure)
             },
+            HandlerType::BoundedRecoverable(ref mut user_handler) => {
+                let mut bounded = user_handler.lock().unwrap();
+                if bounded.remaining == 0 {
+                    error!("Catch: Recovery limit reached, escalating {:?} as non-recoverable.", e);
+                    return Err(ActionExecError::NonRecoverableFailure);
+                }
+
+                if (bounded.handler)(e.clone()) {
+                    bounded.remaining -= 1;
+                    Ok(())
+                } else {
+                    Err(ActionExecError::from(e))
+                }
+            },
         }
     }
 }
 
 impl ActionTrait for Catch {
     fn try_execute(&mut self) -> ReusableBoxFutureResult {
-        let action = self.action.try_execute()?;
+        let action = match self.action.try_execute() {
+            Ok(action) => Some(action),
+            Err(e) if self.filters.is_filter_enabled(ErrorFilter::Internals) => {
+                error!(
+                    "Catch: Future of guarded action not available: {:?}, handling as internal error.",
+                    e
+                );
+                None
+            },
+            Err(e) => return Err(e),
+        };
 
-        self.base
-            .reusable_future_pool
-            .next(Self::execute_impl(action, self.handler.clone(), self.filters))
+        self.base.reusable_future_pool.next(Self::execute_impl(
+            action,
+            self.action_tag,
+            self.handler.clone(),
+            self.error_mapper,
+            self.filters,
+            self.user_error_codes.clone(),
+        ))
     }
 
     fn name(&self) -> &'static str {
         "Catch"
     }
 
-    fn dbg_fmt(&self, _nest: usize, _f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        todo!()
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(
+            f,
+            "{}|-{} (filters: {:?}, user error codes: {:?}) - {:?}",
+            " ".repeat(nest),
+            self.name(),
+            self.filters,
+            self.user_error_codes,
+            self.base
+        )?;
+        self.action.dbg_fmt(nest + 1, f)
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.action.attach_trace_switch(switch);
     }
 }
 
@@ -305,9 +551,11 @@ mod tests {
     };
 
     use crate::{
+        actions::{concurrency::ConcurrencyBuilder, invoke::Invoke},
         common::DesignConfig,
         testing::{MockAction, MockActionBuilder, OrchTestingPoller},
     };
+    use orchestration_macros::OrchUserError;
 
     #[test]
     fn non_recoverable_handler_not_called_before_execution() {
@@ -414,6 +662,93 @@ mod tests {
         assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::NonRecoverableFailure)));
     }
 
+    #[test]
+    fn catch_handles_only_configured_user_error_codes() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(UserErrValue::from(7).into()))
+                .will_once_return(Err(UserErrValue::from(64).into()))
+                .build(),
+        );
+
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors.into(), action)
+            .with_user_error_codes(UserErrorCodes::List(&[3, 7]))
+            .catch_recoverable(|_err| true)
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(UserErrValue::from(64))))
+        );
+    }
+
+    #[test]
+    fn catch_handles_user_error_codes_in_range() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(UserErrValue::from(0x100).into()))
+                .will_once_return(Err(UserErrValue::from(0x200).into()))
+                .build(),
+        );
+
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors.into(), action)
+            .with_user_error_codes(UserErrorCodes::Range(0x100..=0x1ff))
+            .catch_recoverable(|_err| true)
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(UserErrValue::from(0x200))))
+        );
+    }
+
+    #[test]
+    fn bounded_recoverable_handler_escalates_after_limit() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_repeatedly_return(Err(UserErrValue::from(5).into()))
+                .build(),
+        );
+
+        let mut handler_mock = kyron_testing::mock_fn::MockFnBuilder::<(), bool>::new()
+            .times(2)
+            .build();
+
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors.into(), action)
+            .catch_recoverable_with_limit(2, move |_err| {
+                handler_mock.call(());
+                true
+            })
+            .build(&design);
+
+        for _ in 0..2 {
+            let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+            assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        }
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::NonRecoverableFailure)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Catch: User error codes require ErrorFilter::UserErrors filter.")]
+    fn user_error_codes_without_user_errors_filter_panic() {
+        let action = Box::new(MockAction::<()>::default());
+        let _ =
+            CatchBuilder::new(ErrorFilter::Timeouts.into(), action).with_user_error_codes(UserErrorCodes::List(&[1]));
+    }
+
     #[test]
     fn when_user_action_finished_with_not_filtered_error_catch_does_not_call_handler() {
         {
@@ -557,4 +892,267 @@ mod tests {
             Poll::Ready(Err(ActionExecError::UserError(UserErrValue::from(64))))
         );
     }
+
+    #[test]
+    fn error_mapper_translates_error_for_handler_and_propagation() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(UserErrValue::from(64).into()))
+                .build(),
+        );
+
+        let handled_error = Arc::new(Mutex::new(None));
+        let handled_error_clone = Arc::clone(&handled_error);
+
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors.into(), action)
+            .map_error(|e| match e {
+                HandlerErrors::UserErr(code) => HandlerErrors::UserErr(UserErrValue::from(*code + 1000)),
+                other => other,
+            })
+            .catch_recoverable(move |err| {
+                *handled_error_clone.lock().unwrap() = Some(err);
+                false
+            })
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(UserErrValue::from(1064))))
+        );
+        assert_eq!(
+            *handled_error.lock().unwrap(),
+            Some(HandlerErrors::UserErr(UserErrValue::from(1064)))
+        );
+    }
+
+    #[test]
+    fn error_mapper_without_handler_only_translates_filtered_errors() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Timeout))
+                .will_once_return(Err(ActionExecError::Internal))
+                .build(),
+        );
+
+        let mut catch = CatchBuilder::new(ErrorFilter::Timeouts.into(), action)
+            .map_error(|_| HandlerErrors::UserErr(UserErrValue::from(7)))
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(UserErrValue::from(7))))
+        );
+
+        // Not filtered errors are propagated as they are
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    fn user_panics_are_handled_only_with_user_panics_filter() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::UserPanic(UserErrValue::from(0xdead))))
+                .build(),
+        );
+
+        let handled_error = Arc::new(Mutex::new(None));
+        let handled_error_clone = Arc::clone(&handled_error);
+
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors | ErrorFilter::UserPanics, action)
+            .catch_recoverable(move |err| {
+                *handled_error_clone.lock().unwrap() = Some(err);
+                true
+            })
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(
+            *handled_error.lock().unwrap(),
+            Some(HandlerErrors::UserPanic(UserErrValue::from(0xdead)))
+        );
+
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::UserPanic(UserErrValue::from(0xdead))))
+                .build(),
+        );
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors.into(), action)
+            .catch_recoverable(|_| true)
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserPanic(UserErrValue::from(0xdead))))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Catch: Cannot set error mapper multiple times, this will cause an error in execution.")]
+    fn multiple_error_mappers_panic() {
+        let action = Box::new(MockAction::<()>::default());
+        let _ = CatchBuilder::new(ErrorFilter::UserErrors.into(), action)
+            .map_error(|e| e)
+            .map_error(|e| e);
+    }
+
+    #[derive(Debug, PartialEq, OrchUserError)]
+    enum TestError {
+        NotReady = 10,
+        Broken = 20,
+    }
+
+    // Derived code shall not depend on the names in scope at the derive site.
+    #[allow(dead_code)]
+    mod shadowed_prelude {
+        use orchestration_macros::OrchUserError;
+
+        struct Option;
+        struct Some;
+        struct None;
+        trait From {}
+
+        #[derive(OrchUserError)]
+        pub(super) enum ShadowedError {
+            Failed = 30,
+        }
+    }
+
+    #[test]
+    fn derived_user_error_maps_variants_to_codes_and_back() {
+        assert_eq!(*UserErrValue::from(TestError::NotReady), 10);
+        assert_eq!(*TestError::Broken.to_user_err_value(), 20);
+        assert_eq!(
+            TestError::from_user_err_value(UserErrValue::from(10)),
+            Some(TestError::NotReady)
+        );
+        assert_eq!(TestError::from_user_err_value(UserErrValue::from(15)), None);
+
+        let code: UserErrValue = shadowed_prelude::ShadowedError::Failed.into();
+        assert_eq!(*code, 30);
+    }
+
+    #[test]
+    fn handler_error_downcasts_to_typed_user_error() {
+        assert_eq!(
+            HandlerErrors::UserErr(TestError::Broken.to_user_err_value()).downcast::<TestError>(),
+            Some(TestError::Broken)
+        );
+        assert_eq!(
+            HandlerErrors::UserErr(UserErrValue::from(15)).downcast::<TestError>(),
+            None
+        );
+        assert_eq!(HandlerErrors::Timeout.downcast::<TestError>(), None);
+    }
+
+    #[test]
+    fn catch_handles_internal_errors_only_with_internals_filter() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Internal))
+                .build(),
+        );
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors.into(), action)
+            .catch_recoverable(|_err| true)
+            .build(&design);
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Internal))
+                .build(),
+        );
+        let handled = Arc::new(Mutex::new(None));
+        let handled_clone = Arc::clone(&handled);
+        let mut catch = CatchBuilder::new(ErrorFilter::UserErrors | ErrorFilter::Internals, action)
+            .catch_recoverable(move |err| {
+                *handled_clone.lock().unwrap() = Some(err);
+                true
+            })
+            .build(&design);
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(
+            *handled.lock().unwrap(),
+            Some(HandlerErrors::Internal(InternalDiagnostic {
+                code: InternalErrorCode::Execution,
+                tag: Tag::from_str_static("MockAction"),
+            }))
+        );
+    }
+
+    #[test]
+    fn catch_handles_unavailable_future_of_guarded_action_as_internal_error() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let guarded = design.register_invoke_fn("Guarded".into(), || Ok(())).unwrap();
+        let single_execution = DesignConfig {
+            max_concurrent_action_executions: 1,
+            ..DesignConfig::default()
+        };
+        let action = Invoke::from_tag(&guarded, &single_execution);
+        let handled = Arc::new(Mutex::new(None));
+        let handled_clone = Arc::clone(&handled);
+        let mut catch = CatchBuilder::new(ErrorFilter::Internals.into(), action)
+            .catch(move |err| {
+                *handled_clone.lock().unwrap() = Some(err);
+            })
+            .build(&design);
+
+        // The invoke provides a single future at a time, so the second execution has none
+        let mut first = OrchTestingPoller::new(catch.try_execute().unwrap());
+        let mut second = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(second.poll(), Poll::Ready(Err(ActionExecError::NonRecoverableFailure)));
+        assert_eq!(
+            *handled.lock().unwrap(),
+            Some(HandlerErrors::Internal(InternalDiagnostic {
+                code: InternalErrorCode::FutureUnavailable,
+                tag: *guarded.tag(),
+            }))
+        );
+        assert_eq!(first.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn catch_handles_collected_errors_of_concurrency_as_multiple() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let failing = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::Timeout))
+            .build();
+        let passing = MockActionBuilder::<()>::new().will_once_return(Ok(())).build();
+        let action = ConcurrencyBuilder::new()
+            .with_branch(Box::new(failing))
+            .with_branch(Box::new(passing))
+            .with_execution_strategy(ExecutionStrategy::Inline)
+            .collect_errors(true)
+            .build(&design);
+        let handled = Arc::new(Mutex::new(None));
+        let handled_clone = Arc::clone(&handled);
+        let mut catch = CatchBuilder::new(ErrorFilter::MultipleErrors.into(), action)
+            .catch_recoverable(move |err| {
+                *handled_clone.lock().unwrap() = Some(err);
+                true
+            })
+            .build(&design);
+
+        // Collected errors are recovered as a whole
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(
+            *handled.lock().unwrap(),
+            Some(HandlerErrors::Multiple(vec![(
+                Tag::from_str_static("MockAction"),
+                ActionExecError::Timeout
+            )]))
+        );
+    }
 }
@@ -11,50 +11,80 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use super::action::{ActionBaseMeta, ActionMeta, ActionResult, ActionTrait, ReusableBoxFutureResult};
-use crate::actions::action::ActionExecError;
+use super::action::{
+    ActionBaseMeta, ActionExecError, ActionMeta, ActionResult, ActionTrait, ExecutionStrategy, ReusableBoxFutureResult,
+    TraceSwitch,
+};
 use crate::api::design::Design;
 use crate::common::tag::Tag;
+use ::core::cell::UnsafeCell;
 use ::core::future::Future;
 use ::core::pin::Pin;
-use ::core::task::{Context, Poll};
+use ::core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use ::core::task::{Context, Poll, Waker};
 use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
 use kyron::futures::{FutureInternalReturn, FutureState};
-#[cfg(any(test, feature = "runtime-api-mock"))]
-use kyron::testing::mock::*;
-#[cfg(not(any(test, feature = "runtime-api-mock")))]
-use kyron::*;
 use kyron_foundation::containers::growable_vec::GrowableVec;
-use kyron_foundation::containers::reusable_objects::ReusableObject;
+use kyron_foundation::containers::reusable_objects::{ReusableObject, ReusableObjectTrait, ReusableObjects};
 use kyron_foundation::containers::reusable_vec_pool::ReusableVecPool;
 use kyron_foundation::not_recoverable_error;
 use kyron_foundation::prelude::vector_extension::VectorExtension;
 use kyron_foundation::prelude::*;
+use std::sync::Arc;
+use std::task::Wake;
 
 /// Builder for constructing a concurrency group of actions to be executed concurrently.
 /// Allows adding multiple branches (actions) and finalizing into a [`Concurrency`] object.
 /// Requires at least one branch to be added before building.
 pub struct ConcurrencyBuilder {
     actions: Option<GrowableVec<Box<dyn ActionTrait>>>,
+    strategy: ExecutionStrategy,
+    max_parallel: usize,
+    abort_on_error: bool,
+    collect_errors: bool,
 }
 
 /// Final concurrency object, ready for execution.
 /// The concurrency object is reusable and can be executed multiple times.
 /// Holds the actions to be executed concurrently and manages their execution and result collection.
-/// All actions are spawned as tasks and their results are awaited concurrently.
+/// By default all actions are spawned as tasks and their results are awaited concurrently,
+/// see [`ExecutionStrategy`] for polling them inline instead.
 /// The result of the concurrency execution is either `Ok(())` if all branches succeed,
 /// or an `ActionExecError` if any branch fails. The error returned is the last failing branch's error in the registration order of concurrency.
-/// If any branch fails, the other branches are still awaited to completion (without aborting them).
+/// If any branch fails, the other branches are still awaited to completion (without aborting them), unless
+/// [`ConcurrencyBuilder::abort_on_error`] is set. Errors of all failed branches are returned together with
+/// [`ConcurrencyBuilder::collect_errors`].
+/// With [`ConcurrencyBuilder::with_max_parallel`] only a limited number of branches run at a time,
+/// the remaining ones are started in registration order as running branches finish.
 pub struct Concurrency {
     base: ActionBaseMeta,
     actions: Vec<Box<dyn ActionTrait>>,
     futures_vec_pool: ReusableVecPool<ActionMeta>,
+    branch_wakers_pool: ReusableObjects<BranchWakers>,
+    policy: JoinPolicy,
+    branch_tags: Arc<[Tag]>,
+    trace: TraceSwitch,
+}
+
+/// How the branches of a single concurrency execution are started and joined.
+#[derive(Clone, Copy)]
+struct JoinPolicy {
+    strategy: ExecutionStrategy,
+    max_parallel: usize,
+    abort_on_error: bool,
+    collect_errors: bool,
 }
 
 impl ConcurrencyBuilder {
     /// Create a new concurrency builder.
     pub fn new() -> Self {
-        Self { actions: None }
+        Self {
+            actions: None,
+            strategy: ExecutionStrategy::default(),
+            max_parallel: usize::MAX,
+            abort_on_error: false,
+            collect_errors: false,
+        }
     }
 
     /// Add a new branch (concurrent action).
@@ -64,6 +94,42 @@ impl ConcurrencyBuilder {
         self
     }
 
+    /// Set how the branches are executed, [`ExecutionStrategy::Spawn`] by default.
+    /// Returns a mutable reference to self for chaining.
+    pub fn with_execution_strategy(&mut self, strategy: ExecutionStrategy) -> &mut Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Limit the number of branches running at the same time, all branches run at once by default.
+    /// Queued branches are started in registration order as running branches finish.
+    /// Returns a mutable reference to self for chaining.
+    ///
+    /// # Panics
+    /// Panics if `max_parallel` is zero.
+    pub fn with_max_parallel(&mut self, max_parallel: usize) -> &mut Self {
+        assert!(max_parallel > 0, "Concurrency requires at least one parallel branch.");
+        self.max_parallel = max_parallel;
+        self
+    }
+
+    /// Finish the concurrency with the error of the first failing branch, without awaiting the other branches.
+    /// Remaining branches are cancelled: spawned branches are aborted, inline and queued branches are dropped.
+    /// Disabled by default.
+    /// Returns a mutable reference to self for chaining.
+    pub fn abort_on_error(&mut self, abort: bool) -> &mut Self {
+        self.abort_on_error = abort;
+        self
+    }
+
+    /// Finish a failed concurrency with `ActionExecError::Multiple` holding the errors of all failed branches, with
+    /// the tags of their actions, instead of the error of the last failing branch. Disabled by default.
+    /// Returns a mutable reference to self for chaining.
+    pub fn collect_errors(&mut self, collect: bool) -> &mut Self {
+        self.collect_errors = collect;
+        self
+    }
+
     /// Finalize and return the concurrency object ready for execution.
     ///
     /// # Panics
@@ -72,6 +138,7 @@ impl ConcurrencyBuilder {
         let mut actions = self.actions.take().expect("Concurrency requires at least one branch.");
         actions.lock();
         let length = actions.len();
+        let branch_tags = actions.iter().map(|action| action.tag()).collect();
 
         Box::new(Concurrency {
             base: ActionBaseMeta {
@@ -85,6 +152,18 @@ impl ConcurrencyBuilder {
                 design.config.max_concurrent_action_executions,
                 |_| Vec::new_in_global(length),
             ),
+            branch_wakers_pool: ReusableObjects::<BranchWakers>::new(
+                design.config.max_concurrent_action_executions,
+                |_| BranchWakers::new(length),
+            ),
+            policy: JoinPolicy {
+                strategy: self.strategy,
+                max_parallel: self.max_parallel,
+                abort_on_error: self.abort_on_error,
+                collect_errors: self.collect_errors,
+            },
+            branch_tags,
+            trace: TraceSwitch::default(),
         })
     }
 }
@@ -98,20 +177,29 @@ impl Default for ConcurrencyBuilder {
 impl Concurrency {
     /// Internal async execution logic for concurrent actions.
     ///
-    /// Spawns all actions as tasks, waits for all to complete.
-    async fn execute_impl(meta: Tag, mut futures_vec: ReusableObject<Vec<ActionMeta>>) -> ActionResult {
-        for fut in futures_vec.iter_mut() {
-            if let Some(future) = fut.take_future() {
-                fut.assign_handle(safety::spawn_from_reusable(future));
-            }
+    /// Starts up to `max_parallel` actions (spawned as tasks or polled inline), waits for all to complete.
+    async fn execute_impl(
+        meta: Tag,
+        mut futures_vec: ReusableObject<Vec<ActionMeta>>,
+        branch_wakers: ReusableObject<BranchWakers>,
+        policy: JoinPolicy,
+        branch_tags: Arc<[Tag]>,
+        trace: TraceSwitch,
+    ) -> ActionResult {
+        for fut in futures_vec.iter_mut().take(policy.max_parallel) {
+            fut.start(policy.strategy);
         }
 
-        tracing_adapter!(concurrent = ?meta, "Before joining branches");
+        if trace.is_on() {
+            tracing_adapter!(concurrent = ?meta, "Before joining branches");
+        }
 
-        let joined = ConcurrencyJoin::new(futures_vec);
+        let joined = ConcurrencyJoin::new(futures_vec, branch_wakers, policy, branch_tags);
         let res = joined.await;
 
-        tracing_adapter!(concurrent = ?meta, ?res, "After joining branches");
+        if trace.is_on() {
+            tracing_adapter!(concurrent = ?meta, ?res, "After joining branches");
+        }
         res
     }
 
@@ -119,7 +207,24 @@ impl Concurrency {
     fn create_reusable_future_pool(pool_size: usize) -> ReusableBoxFuturePool<ActionResult> {
         let mut vec_pool = ReusableVecPool::<ActionMeta>::new(pool_size, |_| Vec::new_in_global(1));
         let vec = vec_pool.next_object().unwrap();
-        ReusableBoxFuturePool::<ActionResult>::for_value(pool_size, Self::execute_impl("dummy".into(), vec))
+        let mut wakers_pool = ReusableObjects::<BranchWakers>::new(pool_size, |_| BranchWakers::new(1));
+        let wakers = wakers_pool.next_object().unwrap();
+        ReusableBoxFuturePool::<ActionResult>::for_value(
+            pool_size,
+            Self::execute_impl(
+                "dummy".into(),
+                vec,
+                wakers,
+                JoinPolicy {
+                    strategy: ExecutionStrategy::Spawn,
+                    max_parallel: usize::MAX,
+                    abort_on_error: false,
+                    collect_errors: false,
+                },
+                Arc::from([]),
+                TraceSwitch::default(),
+            ),
+        )
     }
 }
 
@@ -127,15 +232,21 @@ impl ActionTrait for Concurrency {
     /// Attempts to execute all branches concurrently, returning a reusable boxed future.
     fn try_execute(&mut self) -> ReusableBoxFutureResult {
         let mut futures_vec = self.futures_vec_pool.next_object()?;
+        let branch_wakers = self.branch_wakers_pool.next_object()?;
 
         for action in self.actions.iter_mut() {
             // Each action is executed and its future is collected for concurrent execution.
             futures_vec.push(ActionMeta::new(action.try_execute()?));
         }
 
-        self.base
-            .reusable_future_pool
-            .next(Self::execute_impl(self.base.tag, futures_vec))
+        self.base.reusable_future_pool.next(Self::execute_impl(
+            self.base.tag,
+            futures_vec,
+            branch_wakers,
+            self.policy,
+            Arc::clone(&self.branch_tags),
+            self.trace.clone(),
+        ))
     }
 
     fn name(&self) -> &'static str {
@@ -150,23 +261,228 @@ impl ActionTrait for Concurrency {
             x.dbg_fmt(nest + 1, f)
         })
     }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.trace = switch.clone();
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.attach_trace_switch(switch));
+    }
+}
+
+/// Waker of the task polling the concurrency, registered and woken without locking in the manner of `AtomicWaker`.
+/// The `state` bits grant exclusive access to `waker`, a wake during registration is handed over to the registering task.
+struct ParentWaker {
+    state: AtomicUsize,
+    waker: UnsafeCell<Option<Waker>>,
+}
+
+const PARENT_WAITING: usize = 0;
+const PARENT_REGISTERING: usize = 0b01;
+const PARENT_WAKING: usize = 0b10;
+
+// Safety: `waker` is accessed only by the holder of `PARENT_REGISTERING` or `PARENT_WAKING`, which are exclusive.
+unsafe impl Send for ParentWaker {}
+unsafe impl Sync for ParentWaker {}
+
+impl ParentWaker {
+    fn new() -> Self {
+        Self {
+            state: AtomicUsize::new(PARENT_WAITING),
+            waker: UnsafeCell::new(None),
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        match self
+            .state
+            .compare_exchange(PARENT_WAITING, PARENT_REGISTERING, Ordering::Acquire, Ordering::Acquire)
+            .unwrap_or_else(|state| state)
+        {
+            PARENT_WAITING => {
+                // Safety: holding `PARENT_REGISTERING`, wakes do not access the waker until it is released.
+                let parent = unsafe { &mut *self.waker.get() };
+                if !parent.as_ref().is_some_and(|parent| parent.will_wake(waker)) {
+                    *parent = Some(waker.clone());
+                }
+
+                if self
+                    .state
+                    .compare_exchange(PARENT_REGISTERING, PARENT_WAITING, Ordering::AcqRel, Ordering::Acquire)
+                    .is_err()
+                {
+                    // A branch was woken during registration and left the wake to us.
+                    self.state.swap(PARENT_WAITING, Ordering::AcqRel);
+                    waker.wake_by_ref();
+                }
+            },
+            PARENT_WAKING => waker.wake_by_ref(), // Waker is in use by a branch, make sure the task polls again.
+            _ => {}, // Concurrent registration, the concurrency is polled by a single task.
+        }
+    }
+
+    fn wake(&self) {
+        if self.state.fetch_or(PARENT_WAKING, Ordering::AcqRel) == PARENT_WAITING {
+            // Safety: holding `PARENT_WAKING`, registration does not access the waker until it is released.
+            if let Some(parent) = unsafe { &*self.waker.get() } {
+                parent.wake_by_ref();
+            }
+            self.state.fetch_and(!PARENT_WAKING, Ordering::Release);
+        }
+    }
+
+    /// Drops the registered waker, unless a stale branch waker is just using it.
+    fn clear(&self) {
+        if self
+            .state
+            .compare_exchange(PARENT_WAITING, PARENT_REGISTERING, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+        {
+            // Safety: holding `PARENT_REGISTERING`, wakes do not access the waker until it is released.
+            unsafe { *self.waker.get() = None };
+            self.state.store(PARENT_WAITING, Ordering::Release);
+        }
+    }
+}
+
+/// Wake state shared by the branch wakers of a single concurrency execution.
+struct BranchWakeState {
+    woken: Box<[AtomicBool]>,
+    parent: ParentWaker,
+}
+
+/// Waker of a single branch. Marks the branch as woken and wakes the task polling the concurrency.
+struct BranchWaker {
+    index: usize,
+    state: Arc<BranchWakeState>,
+}
+
+impl Wake for BranchWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.state.woken[self.index].store(true, Ordering::Release);
+        self.state.parent.wake();
+    }
+}
+
+/// Per branch wakers, created once at build time, so only woken branches need to be polled again.
+struct BranchWakers {
+    state: Arc<BranchWakeState>,
+    wakers: Box<[Waker]>,
+}
+
+impl BranchWakers {
+    fn new(count: usize) -> Self {
+        let state = Arc::new(BranchWakeState {
+            woken: (0..count).map(|_| AtomicBool::new(false)).collect(),
+            parent: ParentWaker::new(),
+        });
+        let wakers = (0..count)
+            .map(|index| {
+                Waker::from(Arc::new(BranchWaker {
+                    index,
+                    state: Arc::clone(&state),
+                }))
+            })
+            .collect();
+
+        Self { state, wakers }
+    }
+
+    /// Registers the waker of the task polling the concurrency.
+    fn register(&self, waker: &Waker) {
+        self.state.parent.register(waker);
+    }
+
+    /// Returns `true` if any branch was woken since it was last polled.
+    fn any_woken(&self) -> bool {
+        self.state.woken.iter().any(|woken| woken.load(Ordering::Acquire))
+    }
+
+    /// Returns `true` if the branch was woken since it was last polled, resetting the flag.
+    fn take_woken(&self, index: usize) -> bool {
+        self.state.woken[index].swap(false, Ordering::AcqRel)
+    }
 }
 
-/// Future that waits for multiple [`JoinHandle`]s to complete.
+impl ReusableObjectTrait for BranchWakers {
+    fn reusable_clear(&mut self) {
+        self.state
+            .woken
+            .iter()
+            .for_each(|woken| woken.store(false, Ordering::Relaxed));
+        self.state.parent.clear();
+    }
+}
+
+/// Future that waits for multiple [`JoinHandle`](kyron::JoinHandle)s or inline futures to complete.
+/// Each branch is polled with its own waker, so after the first poll only woken branches are polled again.
+/// Branches which are not started yet are started once running branches finish, see `max_parallel`.
 /// Returns `Ready` once all are done. Uses FutureState to track polling state.
 struct ConcurrencyJoin {
     handles: ReusableObject<Vec<ActionMeta>>,
+    wakers: ReusableObject<BranchWakers>,
+    policy: JoinPolicy,
+    branch_tags: Arc<[Tag]>,
     state: FutureState,
     action_execution_result: (usize, ActionResult),
+    collected_errors: std::vec::Vec<(Tag, ActionExecError)>,
 }
 
 impl ConcurrencyJoin {
     /// Create a new `ConcurrencyJoin` for the given handles.
-    fn new(handles: ReusableObject<Vec<ActionMeta>>) -> Self {
+    fn new(
+        handles: ReusableObject<Vec<ActionMeta>>,
+        wakers: ReusableObject<BranchWakers>,
+        policy: JoinPolicy,
+        branch_tags: Arc<[Tag]>,
+    ) -> Self {
         Self {
             handles,
+            wakers,
+            policy,
+            branch_tags,
             state: FutureState::New,
             action_execution_result: (0, ActionResult::Ok(())),
+            collected_errors: std::vec::Vec::new(),
+        }
+    }
+
+    /// Takes the result of the joined branches, `ActionExecError::Multiple` if errors are collected.
+    fn take_result(&mut self) -> ActionResult {
+        let result = ::core::mem::replace(&mut self.action_execution_result.1, Ok(()));
+        if self.policy.collect_errors && !self.collected_errors.is_empty() {
+            Err(ActionExecError::Multiple(::core::mem::take(&mut self.collected_errors)))
+        } else {
+            result
+        }
+    }
+
+    /// Starts queued branches until `max_parallel` branches are running.
+    /// Started branches are marked as woken, so they are polled on the next poll of the join.
+    fn start_queued(&mut self) {
+        if self.policy.max_parallel >= self.handles.len() {
+            return; // All branches were started up front.
+        }
+
+        let mut running = self
+            .handles
+            .iter()
+            .filter(|branch| matches!(branch, ActionMeta::Handle(_) | ActionMeta::Inline(_)))
+            .count();
+
+        for (index, branch) in self.handles.iter_mut().enumerate() {
+            if running >= self.policy.max_parallel {
+                break;
+            }
+
+            if branch.start(self.policy.strategy) {
+                self.wakers.wakers[index].wake_by_ref();
+                running += 1;
+            }
         }
     }
 
@@ -176,54 +492,75 @@ impl ConcurrencyJoin {
     fn join_result(&mut self, cx: &mut Context<'_>) -> Poll<ActionResult> {
         let result = match self.state {
             FutureState::New | FutureState::Polled => {
-                // Poll all handles and collect results.
-                let mut is_done = true;
+                self.wakers.register(cx.waker());
 
-                for hnd in self.handles.iter_mut().enumerate() {
-                    match hnd.1 {
-                        ActionMeta::Handle(handle) => {
-                            let res = Pin::new(handle).poll(cx);
-                            match res {
-                                Poll::Ready(action_result) => {
-                                    hnd.1.clear(); // Clear the handle after polling
-                                    let execution_result = match action_result {
-                                        Ok(Ok(_)) => continue,
-                                        Ok(Err(err)) => Err(err),
-
-                                        // This a JoinResult error, not the future error
-                                        Err(_) => Err(ActionExecError::Internal),
-                                    };
-
-                                    // Store the error of the last failed branch in the registration order of concurrency.
-                                    if execution_result.is_err() && hnd.0 >= self.action_execution_result.0 {
-                                        self.action_execution_result = (hnd.0, execution_result);
-                                    }
-                                },
-                                Poll::Pending => {
-                                    is_done = false; // At least one handle is still pending
-                                    if self.state == FutureState::Polled {
-                                        // Exit loop, no need to poll others now since aborting is not required
-                                        break;
-                                    }
-                                },
-                            }
+                // Poll all branches on the first poll, or if the wake-up did not come from any branch waker
+                let poll_all = self.state == FutureState::New || !self.wakers.any_woken();
+                let is_bounded = self.policy.max_parallel < self.handles.len();
+                let mut is_done = true;
+                let mut is_aborted = false;
+
+                for (index, branch) in self.handles.iter_mut().enumerate() {
+                    match branch {
+                        ActionMeta::Handle(_) | ActionMeta::Inline(_) => {},
+                        ActionMeta::Empty if self.state == FutureState::Polled => continue, // Already finished.
+                        ActionMeta::Future(_) if is_bounded => {
+                            is_done = false; // Queued, not started yet
+                            continue;
                         },
-                        ActionMeta::Future(_) => {
+                        _ => {
                             not_recoverable_error!("Join handle not available for the spawned future!");
                         },
-                        ActionMeta::Empty => {
-                            if self.state == FutureState::Polled {
-                                continue; // Already polled.
+                    }
+
+                    let woken = self.wakers.take_woken(index);
+                    if !poll_all && !woken {
+                        is_done = false; // Not woken, so still pending
+                        continue;
+                    }
+
+                    let mut branch_cx = Context::from_waker(&self.wakers.wakers[index]);
+                    match branch.poll_started(&mut branch_cx) {
+                        Some(Poll::Ready(execution_result)) => {
+                            branch.clear(); // Clear the handle after polling
+
+                            let Err(err) = execution_result else {
+                                continue;
+                            };
+
+                            if self.policy.collect_errors {
+                                self.collected_errors.push((self.branch_tags[index], err.clone()));
+                            }
+
+                            if self.policy.abort_on_error {
+                                self.action_execution_result = (index, Err(err));
+                                is_aborted = true;
+                                break;
+                            }
+
+                            // Store the error of the last failed branch in the registration order of concurrency.
+                            if index >= self.action_execution_result.0 {
+                                self.action_execution_result = (index, Err(err));
                             }
-                            not_recoverable_error!("Join handle not available for the spawned future!");
+                        },
+                        Some(Poll::Pending) | None => {
+                            is_done = false; // At least one handle is still pending
                         },
                     }
                 }
 
-                if is_done {
-                    FutureInternalReturn::ready(self.action_execution_result.1)
+                if is_aborted {
+                    // Cancel the remaining branches, so none keeps running detached with its pooled future.
+                    self.handles.iter_mut().for_each(ActionMeta::abort);
+                    FutureInternalReturn::ready(self.take_result())
                 } else {
-                    FutureInternalReturn::polled()
+                    self.start_queued();
+
+                    if is_done {
+                        FutureInternalReturn::ready(self.take_result())
+                    } else {
+                        FutureInternalReturn::polled()
+                    }
                 }
             },
             FutureState::Finished => {
@@ -248,11 +585,16 @@ impl Future for ConcurrencyJoin {
 #[cfg(not(loom))]
 mod tests {
     use super::*;
+    use crate::actions::action::UserErrValue;
+    use crate::actions::invoke::Invoke;
     use crate::common::DesignConfig;
     use crate::testing::MockActionBuilder;
     use crate::testing::OrchTestingPoller;
+    use crate::testing::TestAsyncAction;
+    use ::core::future;
     use ::core::task::Poll;
     use kyron::testing::mock;
+    use kyron_testing::poller::TestingFuturePoller;
     use kyron_testing_macros::ensure_clear_mock_runtime;
 
     #[test]
@@ -518,4 +860,330 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_inline_strategy_executes_without_spawning() {
+        let mock1 = MockActionBuilder::<()>::new().will_once_return(Ok(())).build();
+        let mock2 = MockActionBuilder::<()>::new().will_once_return(Ok(())).build();
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Box::new(mock1))
+            .with_branch(Box::new(mock2))
+            .with_execution_strategy(ExecutionStrategy::Inline);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        let result = poller.poll();
+
+        // Branches are polled inline, nothing is spawned
+        assert_eq!(mock::runtime::remaining_tasks(), 0);
+        assert_eq!(result, Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_inline_strategy_returns_last_error() {
+        let mock1 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::Internal))
+            .build();
+        let mock2 = MockActionBuilder::<()>::new().will_once_return(Ok(())).build();
+        let mock3 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::NonRecoverableFailure))
+            .build();
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Box::new(mock1))
+            .with_branch(Box::new(mock2))
+            .with_branch(Box::new(mock3))
+            .with_execution_strategy(ExecutionStrategy::Inline);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::NonRecoverableFailure)));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_collects_errors_of_all_failed_branches() {
+        let mock1 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::Internal))
+            .will_once_return(Ok(()))
+            .build();
+        let mock2 = MockActionBuilder::<()>::new().times(2).build();
+        let mock3 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::Timeout))
+            .will_once_return(Err(ActionExecError::NonRecoverableFailure))
+            .build();
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Box::new(mock1))
+            .with_branch(Box::new(mock2))
+            .with_branch(Box::new(mock3))
+            .with_execution_strategy(ExecutionStrategy::Inline)
+            .collect_errors(true);
+        let mut concurrency = concurrency_builder.build(&design);
+        let tag = Tag::from_str_static("MockAction");
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::Multiple(vec![
+                (tag, ActionExecError::Internal),
+                (tag, ActionExecError::Timeout)
+            ])))
+        );
+
+        // Each execution reports its own errors
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::Multiple(vec![(
+                tag,
+                ActionExecError::NonRecoverableFailure
+            )])))
+        );
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_collected_errors_are_keyed_by_tag_of_branch() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let failing = design
+            .register_invoke_fn("Failing".into(), || Err(UserErrValue::from(7)))
+            .unwrap();
+        let passing = design.register_invoke_fn("Passing".into(), || Ok(())).unwrap();
+
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Invoke::from_tag(&passing, design.config()))
+            .with_branch(Invoke::from_tag(&failing, design.config()))
+            .with_execution_strategy(ExecutionStrategy::Inline)
+            .collect_errors(true);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::Multiple(vec![(
+                "Failing".into(),
+                ActionExecError::UserError(UserErrValue::from(7))
+            )])))
+        );
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_max_parallel_starts_queued_branches_in_order() {
+        let mock1 = MockActionBuilder::<()>::new().will_once_return(Ok(())).build();
+        let mock2 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::Internal))
+            .build();
+        let mock3 = MockActionBuilder::<()>::new().will_once_return(Ok(())).build();
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Box::new(mock1))
+            .with_branch(Box::new(mock2))
+            .with_branch(Box::new(mock3))
+            .with_execution_strategy(ExecutionStrategy::Inline)
+            .with_max_parallel(1);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        // Only one branch runs at a time, so each poll finishes one branch and starts the next one.
+        assert_eq!(poller.poll(), Poll::Pending);
+        assert_eq!(poller.poll(), Poll::Pending);
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_abort_on_error_does_not_await_other_branches() {
+        let mock1 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::UserError(0xcafe_u64.into())))
+            .build();
+        let mock2 = TestAsyncAction::new(future::pending);
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Box::new(mock1))
+            .with_branch(Box::new(mock2))
+            .with_execution_strategy(ExecutionStrategy::Inline)
+            .abort_on_error(true);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(0xcafe_u64.into())))
+        );
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_abort_on_error_aborts_spawned_branches() {
+        let mock1 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::Internal))
+            .build();
+        let mock2 = TestAsyncAction::new(future::pending);
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Box::new(mock1))
+            .with_branch(Box::new(mock2))
+            .abort_on_error(true);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Pending);
+        mock::runtime::step();
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+
+        // The pending branch was aborted, so it does not stay in the runtime
+        mock::runtime::step();
+        assert_eq!(mock::runtime::remaining_tasks(), 0);
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn concurrency_abort_on_error_cancels_queued_branches() {
+        let mock1 = MockActionBuilder::<()>::new()
+            .will_once_return(Err(ActionExecError::Internal))
+            .build();
+        let mock2 = MockActionBuilder::<()>::new().times(0).build();
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder
+            .with_branch(Box::new(mock1))
+            .with_branch(Box::new(mock2))
+            .with_execution_strategy(ExecutionStrategy::Inline)
+            .with_max_parallel(1)
+            .abort_on_error(true);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let mut poller = OrchTestingPoller::new(concurrency.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Concurrency requires at least one parallel branch.")]
+    fn concurrency_max_parallel_zero_panics() {
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        concurrency_builder.with_max_parallel(0);
+    }
+
+    struct CountingWaker(::core::sync::atomic::AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn branch_wakers_mark_only_woken_branch_and_wake_parent() {
+        let parent = Arc::new(CountingWaker(::core::sync::atomic::AtomicUsize::new(0)));
+        let wakers = BranchWakers::new(3);
+        wakers.register(&Waker::from(Arc::clone(&parent)));
+        assert!(!wakers.any_woken());
+
+        wakers.wakers[1].wake_by_ref();
+        assert!(wakers.any_woken());
+        assert_eq!(parent.0.load(Ordering::Relaxed), 1);
+
+        assert!(!wakers.take_woken(0));
+        assert!(wakers.take_woken(1));
+        assert!(!wakers.take_woken(1));
+        assert!(!wakers.any_woken());
+    }
+
+    /// Branch counting its polls, pending until released and woken through the waker of its last poll.
+    struct CountingBranch {
+        polls: Arc<::core::sync::atomic::AtomicUsize>,
+        released: Arc<AtomicBool>,
+        waker: Arc<std::sync::Mutex<Option<Waker>>>,
+    }
+
+    impl CountingBranch {
+        fn new() -> Self {
+            Self {
+                polls: Arc::new(::core::sync::atomic::AtomicUsize::new(0)),
+                released: Arc::new(AtomicBool::new(false)),
+                waker: Arc::new(std::sync::Mutex::new(None)),
+            }
+        }
+
+        fn action(&self) -> Box<dyn ActionTrait> {
+            let (polls, released, waker) = (
+                Arc::clone(&self.polls),
+                Arc::clone(&self.released),
+                Arc::clone(&self.waker),
+            );
+            Box::new(TestAsyncAction::new(move || {
+                let (polls, released, waker) = (Arc::clone(&polls), Arc::clone(&released), Arc::clone(&waker));
+                future::poll_fn(move |cx| {
+                    polls.fetch_add(1, Ordering::Relaxed);
+                    if released.load(Ordering::Relaxed) {
+                        Poll::Ready(Ok(()))
+                    } else {
+                        *waker.lock().unwrap() = Some(cx.waker().clone());
+                        Poll::Pending
+                    }
+                })
+            }))
+        }
+
+        fn polls(&self) -> usize {
+            self.polls.load(Ordering::Relaxed)
+        }
+
+        fn release_and_wake(&self) {
+            self.released.store(true, Ordering::Relaxed);
+            self.waker.lock().unwrap().as_ref().unwrap().wake_by_ref();
+        }
+    }
+
+    #[test]
+    fn concurrency_polls_only_woken_branches() {
+        let branches = [CountingBranch::new(), CountingBranch::new(), CountingBranch::new()];
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut concurrency_builder = ConcurrencyBuilder::new();
+        for branch in &branches {
+            concurrency_builder.with_branch(branch.action());
+        }
+        concurrency_builder.with_execution_strategy(ExecutionStrategy::Inline);
+        let mut concurrency = concurrency_builder.build(&design);
+
+        let parent = Arc::new(CountingWaker(::core::sync::atomic::AtomicUsize::new(0)));
+        let parent_waker = Waker::from(Arc::clone(&parent));
+        let mut poller = TestingFuturePoller::new(concurrency.try_execute().unwrap().into_pin());
+        let polls = |branches: &[CountingBranch; 3]| branches.each_ref().map(CountingBranch::polls);
+
+        // All branches are polled on the first poll
+        assert_eq!(poller.poll_with_waker(&parent_waker), Poll::Pending);
+        assert_eq!(polls(&branches), [1, 1, 1]);
+
+        // Wake-up of a branch re-polls only that branch
+        branches[1].release_and_wake();
+        assert_eq!(parent.0.load(Ordering::Relaxed), 1);
+        assert_eq!(poller.poll_with_waker(&parent_waker), Poll::Pending);
+        assert_eq!(polls(&branches), [1, 2, 1]);
+
+        branches[0].release_and_wake();
+        branches[2].release_and_wake();
+        assert_eq!(parent.0.load(Ordering::Relaxed), 3);
+        assert_eq!(poller.poll_with_waker(&parent_waker), Poll::Ready(Ok(())));
+        assert_eq!(polls(&branches), [2, 2, 2]);
+    }
 }
@@ -0,0 +1,163 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionResult, ActionTrait, ReusableBoxFutureResult};
+use crate::{api::design::Design, common::tag::Tag};
+use ::core::future::Future;
+use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
+
+///
+/// Builder for [`CustomAction`], a user defined action made of an async closure. It is the supported way to extend
+/// programs with own actions, instead of implementing [`ActionTrait`] by hand.
+///
+pub struct CustomActionBuilder<A, F>
+where
+    A: Fn() -> F + Send + 'static,
+    F: Future<Output = ActionResult> + Send + 'static,
+{
+    name: &'static str,
+    action: A,
+    max_concurrent_executions: Option<usize>,
+}
+
+impl<A, F> CustomActionBuilder<A, F>
+where
+    A: Fn() -> F + Send + 'static,
+    F: Future<Output = ActionResult> + Send + 'static,
+{
+    /// Creates the builder for action named `name`, which runs the future returned by `action` on each execution.
+    pub fn new(name: &'static str, action: A) -> Self {
+        Self {
+            name,
+            action,
+            max_concurrent_executions: None,
+        }
+    }
+
+    /// Sets how many executions of the action can run concurrently, which sizes the pool of its futures.
+    /// `DesignConfig::max_concurrent_action_executions` is used by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrent_executions` is zero.
+    pub fn with_max_concurrent_executions(mut self, max_concurrent_executions: usize) -> Self {
+        assert!(
+            max_concurrent_executions > 0,
+            "CustomAction requires at least one concurrent execution."
+        );
+        self.max_concurrent_executions = Some(max_concurrent_executions);
+        self
+    }
+
+    /// Builds the `CustomAction`. The closure is called once here to size the pool of futures, the returned future
+    /// is dropped without being polled.
+    pub fn build(self, design: &Design) -> Box<CustomAction<A, F>> {
+        let pool_size = self
+            .max_concurrent_executions
+            .unwrap_or(design.config.max_concurrent_action_executions);
+        let future = (self.action)();
+
+        Box::new(CustomAction {
+            base: ActionBaseMeta {
+                tag: Tag::from_str_static(self.name),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(pool_size, future),
+            },
+            name: self.name,
+            action: self.action,
+        })
+    }
+}
+
+///
+/// User defined action running the future returned by its closure on each execution, see [`CustomActionBuilder`].
+///
+pub struct CustomAction<A, F>
+where
+    A: Fn() -> F + Send + 'static,
+    F: Future<Output = ActionResult> + Send + 'static,
+{
+    base: ActionBaseMeta,
+    name: &'static str,
+    action: A,
+}
+
+impl<A, F> ActionTrait for CustomAction<A, F>
+where
+    A: Fn() -> F + Send + 'static,
+    F: Future<Output = ActionResult> + Send + 'static,
+{
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base.reusable_future_pool.next((self.action)())
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{actions::action::ActionExecError, common::DesignConfig, testing::OrchTestingPoller};
+    use ::core::{
+        sync::atomic::{AtomicUsize, Ordering},
+        task::Poll,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn custom_action_runs_closure_future_on_each_execution() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let executions = Arc::new(AtomicUsize::new(0));
+        let executions_clone = Arc::clone(&executions);
+
+        let mut action = CustomActionBuilder::new("counter", move || {
+            let executions = Arc::clone(&executions_clone);
+            async move {
+                executions.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .build(&design);
+        assert_eq!(action.name(), "counter");
+
+        for _ in 0..3 {
+            let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+            assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        }
+        assert_eq!(executions.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn custom_action_pool_limits_concurrent_executions() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut action = CustomActionBuilder::new("failing", || async { Err(ActionExecError::Internal) })
+            .with_max_concurrent_executions(1)
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert!(action.try_execute().is_err());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[should_panic(expected = "CustomAction requires at least one concurrent execution.")]
+    fn custom_action_panics_for_zero_concurrent_executions() {
+        let _ = CustomActionBuilder::new("noop", || async { Ok(()) }).with_max_concurrent_executions(0);
+    }
+}
@@ -0,0 +1,337 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{
+    ActionBaseMeta, ActionExecError, ActionMeta, ActionResult, ActionTrait, ExecutionStrategy, ReusableBoxFutureResult,
+    TraceSwitch,
+};
+use crate::{
+    api::design::Design,
+    common::{tag::Tag, DesignConfig},
+};
+use ::core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::Poll,
+    time::Duration,
+};
+use kyron::{
+    futures::{reusable_box_future::ReusableBoxFuturePool, sleep},
+    time::clock::Clock,
+};
+use kyron_foundation::prelude::*;
+use std::sync::Arc;
+
+/// Callback receiving the tag of an action that exceeded its deadline and the duration measured at that point,
+/// see `Deployment::set_deadline_handler`.
+pub(crate) type DeadlineHandler = Arc<dyn Fn(Tag, Duration) + Send + Sync>;
+
+///
+/// Builder for [`DeadlineMonitor`] action.
+///
+pub struct DeadlineBuilder {
+    tag: Tag,
+    deadline: Duration,
+    action: Box<dyn ActionTrait>,
+    strategy: ExecutionStrategy,
+}
+
+impl DeadlineBuilder {
+    /// Creates a builder for action reporting `action` under `tag` when it does not complete within `deadline`.
+    /// Lets any action, e.g. a `Sequence` or a local graph, be monitored like the actions registered in the design.
+    pub fn new(tag: Tag, deadline: Duration, action: Box<dyn ActionTrait>) -> Self {
+        Self {
+            tag,
+            deadline,
+            action,
+            strategy: ExecutionStrategy::Spawn,
+        }
+    }
+
+    /// Sets how the monitored action is executed. By default it is spawned, so a synchronous action cannot delay
+    /// the report. `ExecutionStrategy::Inline` avoids the extra task for actions which already spawn their work.
+    pub fn with_execution_strategy(mut self, strategy: ExecutionStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Builds the `DeadlineMonitor` action reporting to the handler set by `Deployment::set_deadline_handler`.
+    pub fn build(self, design: &Design) -> Box<DeadlineMonitor> {
+        Box::new(DeadlineMonitor::new(
+            self.tag,
+            self.action,
+            self.deadline,
+            self.strategy,
+            design.db.deadline_handler(),
+            &design.config,
+        ))
+    }
+}
+
+///
+/// Monitors execution time of the child action against a soft deadline, either given to [`DeadlineBuilder`] or
+/// configured with `DesignConfig::deadline` for the invokes registered in the design. When the deadline is exceeded,
+/// the handler is called once per execution while the child keeps running, and the result of the child is returned
+/// unchanged. Without a handler the violation is logged. A child which does not spawn its work by itself is spawned as
+/// a separate task, so a synchronous child cannot delay the report.
+///
+pub struct DeadlineMonitor {
+    base: ActionBaseMeta,
+    monitored: Tag,
+    deadline: Duration,
+    strategy: ExecutionStrategy,
+    handler: Option<DeadlineHandler>,
+    action: Box<dyn ActionTrait>,
+}
+
+impl DeadlineMonitor {
+    /// Wraps `action` registered under `monitored` tag into the monitor.
+    pub(crate) fn wrap(
+        monitored: Tag,
+        action: Box<dyn ActionTrait>,
+        deadline: Duration,
+        strategy: ExecutionStrategy,
+        handler: Option<DeadlineHandler>,
+        config: &DesignConfig,
+    ) -> Box<dyn ActionTrait> {
+        Box::new(DeadlineMonitor::new(
+            monitored, action, deadline, strategy, handler, config,
+        ))
+    }
+
+    fn new(
+        monitored: Tag,
+        action: Box<dyn ActionTrait>,
+        deadline: Duration,
+        strategy: ExecutionStrategy,
+        handler: Option<DeadlineHandler>,
+        config: &DesignConfig,
+    ) -> Self {
+        DeadlineMonitor {
+            base: ActionBaseMeta {
+                tag: "orch::internal::deadline".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    config.max_concurrent_action_executions,
+                    DeadlineMonitor::execute_impl(monitored, ActionMeta::Empty, deadline, strategy, None),
+                ),
+            },
+            monitored,
+            deadline,
+            strategy,
+            handler,
+            action,
+        }
+    }
+
+    async fn execute_impl(
+        monitored: Tag,
+        mut action: ActionMeta,
+        deadline: Duration,
+        strategy: ExecutionStrategy,
+        mut handler: Option<DeadlineHandler>,
+    ) -> ActionResult {
+        let start = Clock::now();
+        action.start(strategy);
+
+        // Timer is created lazily, only if the child did not complete on the first poll
+        let mut timer = pin!(None);
+        let mut is_reported = false;
+
+        poll_fn(|cx| {
+            match action.poll_started(cx) {
+                Some(Poll::Ready(result)) => return Poll::Ready(result),
+                Some(Poll::Pending) => {},
+                None => return Poll::Ready(Err(ActionExecError::Internal)),
+            }
+
+            if is_reported {
+                return Poll::Pending;
+            }
+
+            let elapsed = Clock::now().saturating_duration_since(start);
+            if elapsed < deadline {
+                if timer.is_none() {
+                    timer.set(Some(sleep::sleep(deadline - elapsed)));
+                }
+
+                let expired = timer
+                    .as_mut()
+                    .as_pin_mut()
+                    .is_some_and(|timer| timer.poll(cx).is_ready());
+                if !expired {
+                    return Poll::Pending;
+                }
+            }
+
+            // The child keeps running, its waker is already registered
+            is_reported = true;
+            let elapsed = Clock::now().saturating_duration_since(start);
+            match handler.take() {
+                Some(handler) => handler(monitored, elapsed),
+                None => warn!(
+                    "Action {:?} exceeded its deadline {:?}, running for {:?}.",
+                    monitored, deadline, elapsed
+                ),
+            }
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+impl ActionTrait for DeadlineMonitor {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        let action = ActionMeta::new(self.action.try_execute()?);
+
+        self.base.reusable_future_pool.next(DeadlineMonitor::execute_impl(
+            self.monitored,
+            action,
+            self.deadline,
+            self.strategy,
+            self.handler.clone(),
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "DeadlineMonitor"
+    }
+
+    fn tag(&self) -> Tag {
+        self.monitored
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(
+            f,
+            "{}|-{} ({:?}) - {:?}",
+            " ".repeat(nest),
+            self.name(),
+            self.deadline,
+            self.base
+        )?;
+        self.action.dbg_fmt(nest + 1, f)
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.action.attach_trace_switch(switch);
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(miri))]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::testing::{MockActionBuilder, OrchTestingPoller, TestAsyncAction};
+    use ::core::future;
+    use kyron::testing::mock;
+    use kyron_testing_macros::ensure_clear_mock_runtime;
+    use std::sync::Mutex;
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn deadline_violation_is_reported_once_while_child_keeps_running() {
+        let violations = Arc::new(Mutex::new(std::vec::Vec::new()));
+        let handler: DeadlineHandler = {
+            let violations = Arc::clone(&violations);
+            Arc::new(move |tag, _elapsed| violations.lock().unwrap().push(tag))
+        };
+        let child = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        let mut monitor = DeadlineMonitor::wrap(
+            "monitored".into(),
+            child,
+            Duration::ZERO,
+            ExecutionStrategy::Spawn,
+            Some(handler),
+            &DesignConfig::default(),
+        );
+        let mut poller = OrchTestingPoller::new(monitor.try_execute().unwrap());
+
+        assert_eq!(poller.poll(), Poll::Pending);
+        assert_eq!(poller.poll(), Poll::Pending);
+        assert_eq!(*violations.lock().unwrap(), [Tag::from_str_static("monitored")]);
+
+        mock::runtime::step();
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(violations.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn deadline_is_not_reported_before_expiry() {
+        let violations = Arc::new(Mutex::new(0));
+        let handler: DeadlineHandler = {
+            let violations = Arc::clone(&violations);
+            Arc::new(move |_tag, _elapsed| *violations.lock().unwrap() += 1)
+        };
+        let child = Box::new(TestAsyncAction::new(future::pending));
+
+        let mut monitor = DeadlineMonitor::wrap(
+            "monitored".into(),
+            child,
+            Duration::from_secs(3600),
+            ExecutionStrategy::Spawn,
+            Some(handler),
+            &DesignConfig::default(),
+        );
+        let mut poller = OrchTestingPoller::new(monitor.try_execute().unwrap());
+
+        assert_eq!(poller.poll(), Poll::Pending);
+        assert_eq!(*violations.lock().unwrap(), 0);
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn deadline_builder_monitors_any_action() {
+        let violations = Arc::new(Mutex::new(std::vec::Vec::new()));
+        let design = Design::new("Design".into(), DesignConfig::default());
+        design.db.set_deadline_handler({
+            let violations = Arc::clone(&violations);
+            Arc::new(move |tag, _elapsed| violations.lock().unwrap().push(tag))
+        });
+        let child = Box::new(TestAsyncAction::new(future::pending));
+
+        let mut monitor = DeadlineBuilder::new("sequence".into(), Duration::ZERO, child).build(&design);
+        assert_eq!(monitor.name(), "DeadlineMonitor");
+
+        let mut poller = OrchTestingPoller::new(monitor.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Pending);
+        assert_eq!(*violations.lock().unwrap(), [Tag::from_str_static("sequence")]);
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn inline_strategy_polls_child_without_spawning() {
+        let violations = Arc::new(Mutex::new(std::vec::Vec::new()));
+        let handler: DeadlineHandler = {
+            let violations = Arc::clone(&violations);
+            Arc::new(move |tag, _elapsed| violations.lock().unwrap().push(tag))
+        };
+        let child = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        let mut monitor = DeadlineMonitor::wrap(
+            "monitored".into(),
+            child,
+            Duration::from_secs(3600),
+            ExecutionStrategy::Inline,
+            Some(handler),
+            &DesignConfig::default(),
+        );
+        let mut poller = OrchTestingPoller::new(monitor.try_execute().unwrap());
+
+        // Completes on the first poll, no task was spawned for the child
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert!(violations.lock().unwrap().is_empty());
+    }
+}
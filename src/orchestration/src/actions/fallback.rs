@@ -0,0 +1,177 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult, TraceSwitch};
+use crate::api::design::Design;
+use kyron::futures::reusable_box_future::{ReusableBoxFuture, ReusableBoxFuturePool};
+use kyron_foundation::prelude::*;
+use std::sync::{Arc, Mutex};
+
+///
+/// Builder for [`Fallback`] action.
+///
+pub struct FallbackBuilder {
+    primary: Box<dyn ActionTrait>,
+    secondary: Box<dyn ActionTrait>,
+}
+
+impl FallbackBuilder {
+    /// Creates a builder for action executing `primary` and, if it fails with a recoverable error, `secondary`.
+    pub fn new(primary: Box<dyn ActionTrait>, secondary: Box<dyn ActionTrait>) -> Self {
+        Self { primary, secondary }
+    }
+
+    /// Builds the `Fallback` action.
+    pub fn build(self, design: &Design) -> Box<Fallback> {
+        let mut lp = ReusableBoxFuturePool::for_value(1, async move { Ok(()) });
+        let primary = lp.next(async { Ok(()) }).unwrap();
+        let secondary = Arc::new(Mutex::new(self.secondary));
+
+        Box::new(Fallback {
+            base: ActionBaseMeta {
+                tag: "orch::internal::fallback".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    Fallback::execute_impl(primary, Arc::clone(&secondary)),
+                ),
+            },
+            primary: self.primary,
+            secondary,
+        })
+    }
+}
+
+///
+/// Executes the primary action and, if it fails with `ActionExecError::UserError` or `ActionExecError::Timeout`,
+/// executes the secondary action and reports its result instead, e.g. to switch into a degraded mode. Other errors
+/// of the primary action are propagated without executing the secondary one.
+///
+pub struct Fallback {
+    base: ActionBaseMeta,
+    primary: Box<dyn ActionTrait>,
+    secondary: Arc<Mutex<Box<dyn ActionTrait>>>,
+}
+
+impl Fallback {
+    async fn execute_impl(
+        primary: ReusableBoxFuture<ActionResult>,
+        secondary: Arc<Mutex<Box<dyn ActionTrait>>>,
+    ) -> ActionResult {
+        let result = primary.into_pin().await;
+
+        match result {
+            Err(ActionExecError::UserError(_) | ActionExecError::Timeout) => {
+                debug!(
+                    "Fallback: Primary action failed with {:?}, executing secondary one",
+                    result
+                );
+
+                let future = secondary.lock().unwrap().try_execute();
+                match future {
+                    Ok(future) => future.into_pin().await,
+                    Err(_) => Err(ActionExecError::Internal),
+                }
+            },
+            _ => result,
+        }
+    }
+}
+
+impl ActionTrait for Fallback {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        let primary = self.primary.try_execute()?;
+
+        self.base
+            .reusable_future_pool
+            .next(Fallback::execute_impl(primary, Arc::clone(&self.secondary)))
+    }
+
+    fn name(&self) -> &'static str {
+        "Fallback"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)?;
+        self.primary.dbg_fmt(nest + 1, f)?;
+        self.secondary.lock().unwrap().dbg_fmt(nest + 1, f)
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.primary.attach_trace_switch(switch);
+        self.secondary.lock().unwrap().attach_trace_switch(switch);
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{
+        common::DesignConfig,
+        testing::{MockActionBuilder, OrchTestingPoller},
+    };
+    use ::core::task::Poll;
+
+    #[test]
+    fn fallback_does_not_execute_secondary_when_primary_succeeds() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let primary = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let secondary = Box::new(MockActionBuilder::<()>::new().times(0).build());
+
+        let mut fallback = FallbackBuilder::new(primary, secondary).build(&design);
+        let mut poller = OrchTestingPoller::new(fallback.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn fallback_reports_result_of_secondary_when_primary_fails() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let primary = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::UserError(1_u64.into())))
+                .will_once_return(Err(ActionExecError::Timeout))
+                .build(),
+        );
+        let secondary = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Ok(()))
+                .will_once_return(Err(ActionExecError::UserError(2_u64.into())))
+                .build(),
+        );
+
+        let mut fallback = FallbackBuilder::new(primary, secondary).build(&design);
+        let mut poller = OrchTestingPoller::new(fallback.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+
+        let mut poller = OrchTestingPoller::new(fallback.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(2_u64.into())))
+        );
+    }
+
+    #[test]
+    fn fallback_propagates_non_recoverable_failure_of_primary() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let primary = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::NonRecoverableFailure))
+                .build(),
+        );
+        let secondary = Box::new(MockActionBuilder::<()>::new().times(0).build());
+
+        let mut fallback = FallbackBuilder::new(primary, secondary).build(&design);
+        let mut poller = OrchTestingPoller::new(fallback.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::NonRecoverableFailure)));
+    }
+}
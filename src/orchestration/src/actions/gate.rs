@@ -0,0 +1,215 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult, TraceSwitch};
+use crate::{api::design::Design, common::tag::Tag};
+use ::core::sync::atomic::{AtomicBool, Ordering};
+use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
+use std::sync::{Arc, Mutex};
+
+/// Flag of the gate, either shared by the user or registered in the design.
+enum GateFlag {
+    Shared(Arc<AtomicBool>),
+    Registered(Tag),
+}
+
+///
+/// Builder for [`Gate`] action.
+///
+pub struct GateBuilder {
+    flag: GateFlag,
+    action: Box<dyn ActionTrait>,
+}
+
+impl GateBuilder {
+    /// Creates a builder for action executing `action` only while `flag` is `true`.
+    pub fn new(flag: Arc<AtomicBool>, action: Box<dyn ActionTrait>) -> Self {
+        Self {
+            flag: GateFlag::Shared(flag),
+            action,
+        }
+    }
+
+    /// Creates a builder for action executing `action` only while the gate registered under `tag` with
+    /// [`Design::register_gate`] is open.
+    pub fn from_tag(tag: Tag, action: Box<dyn ActionTrait>) -> Self {
+        Self {
+            flag: GateFlag::Registered(tag),
+            action,
+        }
+    }
+
+    /// Builds the `Gate` action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the gate is created from a tag and no gate is registered under it.
+    pub fn build(self, design: &Design) -> Box<Gate> {
+        let (tag, flag) = match self.flag {
+            GateFlag::Shared(flag) => ("orch::internal::gate".into(), flag),
+            GateFlag::Registered(tag) => {
+                let flag = design.gate(tag);
+                assert!(
+                    flag.is_some(),
+                    "Failed to create Gate with tag {:?}, gate is not registered in design {:?}.",
+                    tag,
+                    design
+                );
+                (tag, flag.unwrap())
+            },
+        };
+        let action = Arc::new(Mutex::new(self.action));
+
+        Box::new(Gate {
+            base: ActionBaseMeta {
+                tag,
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    Gate::execute_impl(Arc::clone(&flag), Arc::clone(&action)),
+                ),
+            },
+            flag,
+            action,
+        })
+    }
+}
+
+///
+/// Executes the child action only if the gate is open when the `Gate` is executed, otherwise finishes with `Ok(())`
+/// without executing it. Lets parts of a program be enabled or disabled at runtime without rebuilding the program.
+///
+pub struct Gate {
+    base: ActionBaseMeta,
+    flag: Arc<AtomicBool>,
+    action: Arc<Mutex<Box<dyn ActionTrait>>>,
+}
+
+impl Gate {
+    async fn execute_impl(flag: Arc<AtomicBool>, action: Arc<Mutex<Box<dyn ActionTrait>>>) -> ActionResult {
+        if !flag.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        let future = action.lock().unwrap().try_execute();
+        match future {
+            Ok(future) => future.into_pin().await,
+            Err(_) => Err(ActionExecError::Internal),
+        }
+    }
+}
+
+impl ActionTrait for Gate {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base
+            .reusable_future_pool
+            .next(Gate::execute_impl(Arc::clone(&self.flag), Arc::clone(&self.action)))
+    }
+
+    fn name(&self) -> &'static str {
+        "Gate"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)?;
+        self.action.lock().unwrap().dbg_fmt(nest + 1, f)
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.action.lock().unwrap().attach_trace_switch(switch);
+    }
+}
+
+///
+/// Handle opening and closing a gate registered in designs, see [`crate::api::deployment::Deployment::gate_control`].
+/// Gates with the same tag registered in multiple designs are controlled together.
+///
+#[derive(Clone)]
+pub struct GateControl {
+    flags: Vec<Arc<AtomicBool>>,
+}
+
+impl GateControl {
+    pub(crate) fn new(flags: Vec<Arc<AtomicBool>>) -> Self {
+        Self { flags }
+    }
+
+    /// Opens the gate, so its actions are executed from their next execution on.
+    pub fn open(&self) {
+        self.flags.iter().for_each(|flag| flag.store(true, Ordering::Release));
+    }
+
+    /// Closes the gate, so its actions are skipped from their next execution on. Running actions are not aborted.
+    pub fn close(&self) {
+        self.flags.iter().for_each(|flag| flag.store(false, Ordering::Release));
+    }
+
+    /// Returns `true` if the gate is open.
+    pub fn is_open(&self) -> bool {
+        self.flags.iter().all(|flag| flag.load(Ordering::Acquire))
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{
+        common::DesignConfig,
+        testing::{MockActionBuilder, OrchTestingPoller},
+    };
+    use ::core::task::Poll;
+
+    #[test]
+    fn gate_executes_action_only_while_open() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let flag = Arc::new(AtomicBool::new(false));
+        let action = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        let mut gate = GateBuilder::new(Arc::clone(&flag), action).build(&design);
+        let mut poller = OrchTestingPoller::new(gate.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+
+        flag.store(true, Ordering::Release);
+        let mut poller = OrchTestingPoller::new(gate.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn gate_from_tag_is_controlled_by_registered_gate() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design.register_gate("diagnostics".into(), true).unwrap();
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Timeout))
+                .build(),
+        );
+
+        let mut gate = GateBuilder::from_tag("diagnostics".into(), action).build(&design);
+        let mut poller = OrchTestingPoller::new(gate.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Timeout)));
+
+        let control = GateControl::new(vec![design.gate("diagnostics".into()).unwrap()]);
+        control.close();
+        assert!(!control.is_open());
+        let mut poller = OrchTestingPoller::new(gate.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    #[should_panic(expected = "gate is not registered")]
+    fn gate_panics_for_unknown_gate() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let _ = GateBuilder::from_tag("diagnostics".into(), action).build(&design);
+    }
+}
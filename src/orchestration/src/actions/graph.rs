@@ -11,19 +11,19 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use super::action::{ActionBaseMeta, ActionMeta, ActionResult, ActionTrait, ReusableBoxFutureResult};
-use crate::actions::action::ActionExecError;
+use super::action::{
+    ActionBaseMeta, ActionExecError, ActionMeta, ActionResult, ActionTrait, ExecutionStrategy, ReusableBoxFutureResult,
+    TraceSwitch,
+};
+use super::ifelse::ConditionFn;
 use crate::api::design::Design;
 use crate::common::tag::Tag;
 use ::core::future::Future;
 use ::core::pin::Pin;
 use ::core::task::{Context, Poll};
+use kyron::core::types::UniqueWorkerId;
 use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
 use kyron::futures::{FutureInternalReturn, FutureState};
-#[cfg(any(test, feature = "runtime-api-mock"))]
-use kyron::testing::mock::*;
-#[cfg(not(any(test, feature = "runtime-api-mock")))]
-use kyron::*;
 use kyron_foundation::containers::growable_vec::GrowableVec;
 use kyron_foundation::containers::reusable_objects::ReusableObject;
 use kyron_foundation::containers::reusable_vec_pool::ReusableVecPool;
@@ -33,16 +33,50 @@ use kyron_foundation::prelude::*;
 
 pub type NodeId = usize;
 
+/// Defines when a node of the graph is started with respect to the nodes it depends on.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DependencyMode {
+    /// The node starts once all nodes it depends on finished successfully.
+    #[default]
+    All,
+    /// The node starts once any one of the nodes it depends on finished successfully. Results of the remaining
+    /// ones do not start the node again, which allows redundant producers of the same input.
+    Any,
+}
+
+/// Defines on which worker a node of the graph is spawned, see [`LocalGraphActionBuilder::with_node_placement`].
+#[derive(Clone, Copy, Default)]
+pub enum NodePlacement {
+    /// The node is spawned on any worker of the engine.
+    #[default]
+    Any,
+    /// The node is spawned on the dedicated worker of the parent that started it, so data produced by the parent is
+    /// consumed on the same core. Behaves as `Any` if the parent was not spawned on a dedicated worker.
+    SameAsParent,
+    /// The node is spawned on the dedicated worker with given id.
+    Worker(UniqueWorkerId),
+}
+
 use std::sync::Arc;
 
 /// A node in the graph representing an action and its dependencies.
 struct Node {
+    /// Tag reported with the error of the node, its name or the tag of its action.
+    tag: Tag,
     /// The action to be executed at this node.
     action: Box<dyn ActionTrait>,
+    /// Optional name used in error messages and debug output instead of the NodeId.
+    name: Option<&'static str>,
     /// Number of dependencies this node has.
     indegree: usize,
+    /// How many of the dependencies must finish before the node starts.
+    mode: DependencyMode,
+    /// Worker the node is spawned on.
+    placement: NodePlacement,
     /// Nodes that depend on this node.
     edges: Option<Vec<NodeId>>, // Option: to move edges into array when building the graph action
+    /// Conditions of the conditional edges, by target node.
+    conditions: Option<std::vec::Vec<(NodeId, Tag)>>,
 }
 
 /// Builder for creating a LocalGraphAction.
@@ -51,6 +85,8 @@ struct Node {
 pub struct LocalGraphActionBuilder {
     next_node_id: NodeId,             // Next node ID (index)
     nodes: GrowableVec<Option<Node>>, // Option: to move nodes during sorting
+    strategy: ExecutionStrategy,
+    collect_errors: bool,
 }
 
 impl LocalGraphActionBuilder {
@@ -59,22 +95,84 @@ impl LocalGraphActionBuilder {
         Self {
             next_node_id: 0,
             nodes: GrowableVec::new(2),
+            strategy: ExecutionStrategy::default(),
+            collect_errors: false,
         }
     }
 
+    /// Sets how the node actions are executed, [`ExecutionStrategy::Spawn`] by default.
+    /// Returns a mutable reference to self.
+    pub fn with_execution_strategy(&mut self, strategy: ExecutionStrategy) -> &mut Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Finishes a failed graph with `ActionExecError::Multiple` holding the errors of all failed nodes, with the
+    /// names of the nodes or the tags of their actions, instead of the error of the last failed node.
+    /// Disabled by default.
+    /// Returns a mutable reference to self.
+    pub fn collect_errors(&mut self, collect: bool) -> &mut Self {
+        self.collect_errors = collect;
+        self
+    }
+
     /// Adds a node with the given action to the graph, returning its NodeId.
     pub fn add_node(&mut self, action: Box<dyn ActionTrait>) -> NodeId {
+        self.push_node(None, action, DependencyMode::All)
+    }
+
+    /// Adds a node with the given action and dependency `mode` to the graph, returning its NodeId.
+    pub fn add_node_with_mode(&mut self, action: Box<dyn ActionTrait>, mode: DependencyMode) -> NodeId {
+        self.push_node(None, action, mode)
+    }
+
+    /// Adds a named node with the given action to the graph, returning its NodeId.
+    /// The name is used instead of the NodeId in validation errors and debug output.
+    pub fn add_node_named(&mut self, name: &'static str, action: Box<dyn ActionTrait>) -> NodeId {
+        self.push_node(Some(name), action, DependencyMode::All)
+    }
+
+    fn push_node(&mut self, name: Option<&'static str>, action: Box<dyn ActionTrait>, mode: DependencyMode) -> NodeId {
         let id = self.next_node_id;
         let node = Node {
+            tag: name.map_or_else(|| action.tag(), Tag::from_str_static),
             action,
+            name,
             indegree: 0,
+            mode,
+            placement: NodePlacement::Any,
             edges: None,
+            conditions: None,
         };
         self.nodes.push(Some(node));
         self.next_node_id += 1;
         id
     }
 
+    /// Returns name of the node, or its NodeId if the node is not named.
+    fn node_label(&self, node_id: NodeId) -> String {
+        let name = if node_id < self.nodes.len() {
+            self.nodes[node_id].as_ref().and_then(|node| node.name)
+        } else {
+            None
+        };
+
+        match name {
+            Some(name) => format!("'{}'", name),
+            None => format!("{}", node_id),
+        }
+    }
+
+    /// Sets on which worker the node with `node_id` is spawned, [`NodePlacement::Any`] by default.
+    /// Placement is honored with [`ExecutionStrategy::Spawn`] only, inline nodes are polled by the task of the graph.
+    /// Returns a mutable reference to self.
+    /// Panics if `node_id` is invalid.
+    pub fn with_node_placement(&mut self, node_id: NodeId, placement: NodePlacement) -> &mut Self {
+        assert!(node_id < self.nodes.len(), "Invalid node ID.");
+        self.nodes[node_id].as_mut().unwrap().placement = placement;
+        self
+    }
+
     /// Adds directed edges from the node with `node_id` to each node in `edges`.
     /// Returns a mutable reference to self.
     /// Panics if `node_id` or any edge in `edges` is invalid, if there are duplicate edges,
@@ -86,36 +184,105 @@ impl LocalGraphActionBuilder {
         assert!(node_id < node_len, "Invalid node ID.");
 
         // Find invalid edge IDs, self-loop edges, and duplicated edges
+        let existing = self.nodes[node_id].as_ref().unwrap().edges.as_ref();
         for i in 0..edges.len() {
-            assert!(edges[i] < node_len, "Invalid edge ID.");
-            assert!(edges[i] != node_id, "Self-loop edges are not allowed.");
+            assert!(
+                edges[i] < node_len,
+                "Invalid edge ID. Edge {} of node {}.",
+                edges[i],
+                self.node_label(node_id)
+            );
+            assert!(
+                edges[i] != node_id,
+                "Self-loop edges are not allowed. Node {}.",
+                self.node_label(node_id)
+            );
             // Number of edges would be less, so O(n^2) is acceptable here
-            for j in (i + 1)..edges.len() {
-                assert!(edges[i] != edges[j], "Duplicate edges are not allowed.");
-            }
+            let is_duplicate = edges[(i + 1)..].contains(&edges[i])
+                || existing.is_some_and(|existing| existing.iter().any(|&edge| edge == edges[i]));
+            assert!(
+                !is_duplicate,
+                "Duplicate edges are not allowed. Edge to node {} from node {}.",
+                self.node_label(edges[i]),
+                self.node_label(node_id)
+            );
         }
 
-        // Add edges
-        let mut temp = Vec::new_in_global(edges.len());
+        // Add edges, after the ones added before
+        self.append_edges(node_id, edges);
+
+        self
+    }
+
+    /// Adds a directed edge from the node `from` to the node `to`, which is followed only if the IfElse condition
+    /// registered in the design under `condition` returns `true` once `from` finishes successfully. Otherwise `to` and
+    /// the nodes depending on it are not executed. The condition is looked up when the graph is built.
+    /// Returns a mutable reference to self.
+    /// Panics if `from` or `to` is invalid, if the edge already exists or if it is a self-loop edge.
+    pub fn add_conditional_edge(&mut self, from: NodeId, to: NodeId, condition: Tag) -> &mut Self {
+        let node_len = self.nodes.len();
+        assert!(from < node_len && to < node_len, "Invalid node ID.");
+        assert!(
+            from != to,
+            "Self-loop edges are not allowed. Node {}.",
+            self.node_label(from)
+        );
+
+        let is_duplicate = self.nodes[from]
+            .as_ref()
+            .unwrap()
+            .edges
+            .as_ref()
+            .is_some_and(|edges| edges.iter().any(|&edge| edge == to));
+        assert!(
+            !is_duplicate,
+            "Duplicate edges are not allowed. Edge to node {} from node {}.",
+            self.node_label(to),
+            self.node_label(from)
+        );
+
+        self.append_edges(from, &[to]);
+        self.nodes[from]
+            .as_mut()
+            .unwrap()
+            .conditions
+            .get_or_insert_with(std::vec::Vec::new)
+            .push((to, condition));
+        self
+    }
+
+    /// Appends `edges` to the edges of the node and updates indegrees of edge nodes.
+    fn append_edges(&mut self, node_id: NodeId, edges: &[NodeId]) {
+        let node = self.nodes[node_id].as_mut().unwrap();
+        let existing = node.edges.take();
+        let existing = existing.as_ref().map_or(&[][..], |existing| existing.as_slice());
+
+        let mut temp = Vec::new_in_global(existing.len() + edges.len());
+        temp.extend_from_slice(existing).unwrap();
         temp.extend_from_slice(edges).unwrap();
-        self.nodes[node_id].as_mut().unwrap().edges = Some(temp);
+        node.edges = Some(temp);
 
         // Update indegrees (number of dependencies) of edge nodes
         for &edge in edges {
             self.nodes[edge].as_mut().unwrap().indegree += 1;
         }
-
-        self
     }
 
     /// Builds the LocalGraphAction from the added nodes and edges.
-    /// Panics if there are no nodes or if the graph contains a cycle.
+    /// Panics if there are no nodes, if the graph contains a cycle or if a condition of conditional edge is not
+    /// registered in the design.
     pub fn build(&mut self, design: &Design) -> Box<LocalGraphAction> {
         assert!(!self.nodes.is_empty(), "No nodes in the graph.");
-        let mut sorted_nodes =
-            LocalGraphActionBuilder::sort(&mut self.nodes).expect("Graph contains a cycle, which is not allowed.");
+        let mut sorted_nodes = LocalGraphActionBuilder::sort(&mut self.nodes).unwrap_or_else(|unresolved| {
+            let labels: std::vec::Vec<String> = unresolved.iter().map(|&id| self.node_label(id)).collect();
+            panic!(
+                "Graph contains a cycle, which is not allowed. Unresolved nodes: [{}].",
+                labels.join(", ")
+            )
+        });
         let num_of_nodes = sorted_nodes.len();
         let nodes_edges = LocalGraphActionBuilder::build_edges(&mut sorted_nodes);
+        let nodes_conditions = LocalGraphActionBuilder::build_conditions(&mut sorted_nodes, design);
         // Create and return the LocalGraphAction
         Box::new(LocalGraphAction {
             base: ActionBaseMeta {
@@ -126,16 +293,21 @@ impl LocalGraphActionBuilder {
             },
             nodes: sorted_nodes,
             nodes_edges,
+            nodes_conditions,
             futures_vec_pool: ReusableVecPool::<NodeFuture>::new(
                 design.config.max_concurrent_action_executions,
                 |_| Vec::new_in_global(num_of_nodes),
             ),
+            strategy: self.strategy,
+            collect_errors: self.collect_errors,
+            trace: TraceSwitch::default(),
         })
     }
 
     /// Checks if the graph has a cycle using Kahn's algorithm and sorts the nodes topologically if acyclic.
-    /// Returns Some(sorted_nodes) if the graph is acyclic, None if it contains a cycle.
-    fn sort(nodes: &mut GrowableVec<Option<Node>>) -> Option<Vec<Node>> {
+    /// Returns Ok(sorted_nodes) if the graph is acyclic, Err(unresolved_nodes) if it contains a cycle.
+    /// Unresolved nodes are the ones on the cycle and the ones depending on it.
+    fn sort(nodes: &mut GrowableVec<Option<Node>>) -> Result<Vec<Node>, Vec<NodeId>> {
         let length = nodes.len();
         // Find cycle in the graph using Kahn's algorithm
         // 1. Collect indegree (number of dependencies) for each node and
@@ -174,7 +346,13 @@ impl LocalGraphActionBuilder {
 
         // 3. If not all nodes are visited, there is a cycle
         if visited != length {
-            return None;
+            let mut unresolved = Vec::new_in_global(length - visited);
+            for (i, &deg) in indegree.iter().enumerate() {
+                if deg > 0 {
+                    unresolved.push(i).unwrap();
+                }
+            }
+            return Err(unresolved);
         }
 
         // 4. Return nodes in sorted order
@@ -195,9 +373,14 @@ impl LocalGraphActionBuilder {
                     *e = new_index[*e];
                 }
             }
+            if let Some(conditions) = &mut new_nodes.last_mut().unwrap().conditions {
+                for (to, _) in conditions.iter_mut() {
+                    *to = new_index[*to];
+                }
+            }
         }
 
-        Some(new_nodes)
+        Ok(new_nodes)
     }
 
     /// Builds the edges into an Arc of boxed slices to share across threads.
@@ -218,6 +401,31 @@ impl LocalGraphActionBuilder {
         // Convert Vec<Box<[usize]>> to Arc<[Box<[usize]>]>
         Arc::from(vec_of_boxed_arr.as_slice())
     }
+
+    /// Resolves the conditions of conditional edges in the design, shared across threads like the edges.
+    fn build_conditions(nodes: &mut Vec<Node>, design: &Design) -> Arc<[Box<[(NodeId, ConditionFn)]>]> {
+        let mut vec_of_boxed_arr = Vec::new_in_global(nodes.len());
+
+        for node in nodes.iter_mut() {
+            let conditions = node.conditions.take().unwrap_or_default();
+            let boxed_conditions_arr: Box<[(NodeId, ConditionFn)]> = conditions
+                .into_iter()
+                .map(|(to, tag)| {
+                    let Ok(orch_tag) = design.get_orchestration_tag(tag) else {
+                        panic!("Failed to find condition {:?} of conditional edge.", tag);
+                    };
+                    let condition = orch_tag.action_provider().borrow_mut().provide_condition(tag);
+                    match condition {
+                        Some(condition) => (to, condition),
+                        None => panic!("Failed to find condition {:?} of conditional edge.", tag),
+                    }
+                })
+                .collect();
+            vec_of_boxed_arr.push(boxed_conditions_arr).unwrap();
+        }
+
+        Arc::from(vec_of_boxed_arr.as_slice())
+    }
 }
 
 /// Default implementation for LocalGraphActionBuilder.
@@ -235,12 +443,30 @@ pub struct LocalGraphAction {
     base: ActionBaseMeta,
     nodes: Vec<Node>,
     nodes_edges: Arc<[Box<[NodeId]>]>,
+    nodes_conditions: Arc<[Box<[(NodeId, ConditionFn)]>]>,
     futures_vec_pool: ReusableVecPool<NodeFuture>,
+    strategy: ExecutionStrategy,
+    collect_errors: bool,
+    trace: TraceSwitch,
 }
 
 struct NodeFuture {
+    tag: Tag, // Reported with the error of the node
     future: ActionMeta,
     indegree: usize,
+    placement: NodePlacement,
+    worker: Option<UniqueWorkerId>, // Dedicated worker the node is spawned on, resolved from placement
+}
+
+impl NodeFuture {
+    /// Starts the node according to the strategy, on its dedicated worker if it has one.
+    /// Returns `false` if there is no future to start.
+    fn start(&mut self, strategy: ExecutionStrategy) -> bool {
+        match (strategy, self.worker) {
+            (ExecutionStrategy::Spawn, Some(worker_id)) => self.future.start_on_dedicated(worker_id),
+            _ => self.future.start(strategy),
+        }
+    }
 }
 
 impl LocalGraphAction {
@@ -248,13 +474,21 @@ impl LocalGraphAction {
         meta: Tag,
         futures_vec: ReusableObject<Vec<NodeFuture>>,
         edges_arr: Arc<[Box<[NodeId]>]>,
+        conditions_arr: Arc<[Box<[(NodeId, ConditionFn)]>]>,
+        strategy: ExecutionStrategy,
+        collect_errors: bool,
+        trace: TraceSwitch,
     ) -> ActionResult {
-        tracing_adapter!(graph = ?meta, "Before executing nodes");
+        if trace.is_on() {
+            tracing_adapter!(graph = ?meta, "Before executing nodes");
+        }
 
-        let executor = DagExecutor::spawn_graph(futures_vec, edges_arr);
+        let executor = DagExecutor::spawn_graph(futures_vec, edges_arr, conditions_arr, strategy, collect_errors);
         let res = executor.await;
 
-        tracing_adapter!(graph = ?meta, ?res, "After executing nodes");
+        if trace.is_on() {
+            tracing_adapter!(graph = ?meta, ?res, "After executing nodes");
+        }
         res
     }
 
@@ -262,9 +496,18 @@ impl LocalGraphAction {
         let mut futures_vec_pool = ReusableVecPool::<NodeFuture>::new(pool_size, |_| Vec::new_in_global(1));
         let futures_vec = futures_vec_pool.next_object().unwrap();
         let edges_arr = Arc::new([]);
+        let conditions_arr = Arc::new([]);
         ReusableBoxFuturePool::<ActionResult>::for_value(
             pool_size,
-            Self::execute_impl("dummy".into(), futures_vec, edges_arr),
+            Self::execute_impl(
+                "dummy".into(),
+                futures_vec,
+                edges_arr,
+                conditions_arr,
+                ExecutionStrategy::Spawn,
+                false,
+                TraceSwitch::default(),
+            ),
         )
     }
 }
@@ -274,16 +517,33 @@ impl ActionTrait for LocalGraphAction {
         let mut futures_vec = self.futures_vec_pool.next_object()?;
 
         for node in self.nodes.iter_mut() {
-            // Collect futures and indegrees for each node
+            // Collect futures and indegrees for each node, a node waiting for any dependency needs only one
+            let indegree = match node.mode {
+                DependencyMode::All => node.indegree,
+                DependencyMode::Any => node.indegree.min(1),
+            };
+            let worker = match node.placement {
+                NodePlacement::Worker(worker_id) => Some(worker_id),
+                NodePlacement::Any | NodePlacement::SameAsParent => None,
+            };
             futures_vec.push(NodeFuture {
+                tag: node.tag,
                 future: ActionMeta::new(node.action.try_execute()?),
-                indegree: node.indegree,
+                indegree,
+                placement: node.placement,
+                worker,
             });
         }
 
-        self.base
-            .reusable_future_pool
-            .next(Self::execute_impl(self.base.tag, futures_vec, self.nodes_edges.clone()))
+        self.base.reusable_future_pool.next(Self::execute_impl(
+            self.base.tag,
+            futures_vec,
+            self.nodes_edges.clone(),
+            self.nodes_conditions.clone(),
+            self.strategy,
+            self.collect_errors,
+            self.trace.clone(),
+        ))
     }
 
     fn name(&self) -> &'static str {
@@ -295,7 +555,10 @@ impl ActionTrait for LocalGraphAction {
         writeln!(f, "{}|-{} - {:?}", indent, self.name(), self.base)?;
         for (i, node) in self.nodes.iter().enumerate() {
             // Print node info
-            write!(f, "{} |node {} {{ indegree: {}, ", indent, i, node.indegree)?;
+            match node.name {
+                Some(name) => write!(f, "{} |node {} '{}' {{ indegree: {}, ", indent, i, name, node.indegree)?,
+                None => write!(f, "{} |node {} {{ indegree: {}, ", indent, i, node.indegree)?,
+            }
             // Print edges for this node
             if let Some(edges_arr) = self.nodes_edges.get(i) {
                 write!(f, "edges: [",)?;
@@ -314,6 +577,13 @@ impl ActionTrait for LocalGraphAction {
         }
         Ok(())
     }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.trace = switch.clone();
+        self.nodes
+            .iter_mut()
+            .for_each(|node| node.action.attach_trace_switch(switch));
+    }
 }
 
 /// Executor for the DAG that manages the execution of actions based on their dependencies.
@@ -323,16 +593,24 @@ struct DagExecutor {
     state: FutureState,
     action_execution_result: (usize, ActionResult),
     edges_arr: Arc<[Box<[NodeId]>]>,
+    conditions_arr: Arc<[Box<[(NodeId, ConditionFn)]>]>,
+    strategy: ExecutionStrategy,
+    collect_errors: bool,
+    collected_errors: std::vec::Vec<(Tag, ActionExecError)>,
 }
 
 impl DagExecutor {
-    /// Spawns the actions of all root nodes (nodes with zero indegree) and returns a DagExecutor.
-    fn spawn_graph(mut futures_vec: ReusableObject<Vec<NodeFuture>>, edges_arr: Arc<[Box<[NodeId]>]>) -> DagExecutor {
+    /// Starts the actions of all root nodes (nodes with zero indegree) and returns a DagExecutor.
+    fn spawn_graph(
+        mut futures_vec: ReusableObject<Vec<NodeFuture>>,
+        edges_arr: Arc<[Box<[NodeId]>]>,
+        conditions_arr: Arc<[Box<[(NodeId, ConditionFn)]>]>,
+        strategy: ExecutionStrategy,
+        collect_errors: bool,
+    ) -> DagExecutor {
         for node_fut in futures_vec.iter_mut() {
             if node_fut.indegree == 0 {
-                if let Some(future) = node_fut.future.take_future() {
-                    node_fut.future.assign_handle(safety::spawn_from_reusable(future));
-                } else {
+                if !node_fut.start(strategy) {
                     not_recoverable_error!("Future not available for root node!");
                 }
             } else {
@@ -346,34 +624,63 @@ impl DagExecutor {
             state: FutureState::New,
             action_execution_result: (0, ActionResult::Ok(())),
             edges_arr,
+            conditions_arr,
+            strategy,
+            collect_errors,
+            collected_errors: std::vec::Vec::new(),
         }
     }
 
     /// Spawns the actions of the nodes that are dependent on the given node index,
-    /// if their indegree reaches zero.
+    /// if their indegree reaches zero. Conditional edges whose condition returns `false` are not followed.
     fn spawn_edge_nodes(&mut self, node_index: usize) {
         let edges = &self.edges_arr[node_index];
+        let conditions = &self.conditions_arr[node_index];
+        let parent_worker = self.handles[node_index].worker;
         for &to_node in edges.iter() {
+            let is_followed = conditions
+                .iter()
+                .find(|(to, _)| *to == to_node)
+                .is_none_or(|(_, condition)| condition());
+            if !is_followed {
+                continue; // Node is never started, so nodes depending on it neither
+            }
+
             let node_handle = &mut self.handles[to_node];
+            if node_handle.indegree == 0 {
+                continue; // Node waiting for any dependency was already started by another one
+            }
             // Decrease indegree of dependent nodes
             node_handle.indegree -= 1;
-            // If indegree reaches zero, spawn the action
+            // If indegree reaches zero, start the action
             if node_handle.indegree == 0 {
-                if let Some(future) = node_handle.future.take_future() {
-                    node_handle.future.assign_handle(safety::spawn_from_reusable(future));
-                } else {
+                if let NodePlacement::SameAsParent = node_handle.placement {
+                    node_handle.worker = parent_worker;
+                }
+                if !node_handle.start(self.strategy) {
                     not_recoverable_error!("Future not available for edge node!");
                 }
             }
         }
     }
 
+    /// Takes the result of the executed nodes, `ActionExecError::Multiple` if errors are collected.
+    fn take_result(&mut self) -> ActionResult {
+        let result = ::core::mem::replace(&mut self.action_execution_result.1, Ok(()));
+        if self.collect_errors && !self.collected_errors.is_empty() {
+            Err(ActionExecError::Multiple(::core::mem::take(&mut self.collected_errors)))
+        } else {
+            result
+        }
+    }
+
     /// Polls the join handles of the spawned actions and manages the execution flow.
     /// Spawns edge nodes only after the current node's action completes successfully.
     /// In case of an action failure, edge nodes are not spawned.
     /// Returns Poll::Ready when all spawned actions are completed, or Poll::Pending if there are still actions running.
     /// If any action fails, it captures the error and continues to poll other actions.
-    /// The final result will be the error of the last failed action in the sorted order of nodes.
+    /// The final result will be the error of the last failed action in the sorted order of nodes,
+    /// or `ActionExecError::Multiple` with errors of all failed actions if they are collected.
     fn poll_node_handles(&mut self, cx: &mut Context<'_>) -> Poll<ActionResult> {
         let result = match self.state {
             // Poll all handles and spawn edge nodes as their dependencies are resolved
@@ -381,51 +688,48 @@ impl DagExecutor {
                 // Assume all are done, if any one is pending, we will set it to false
                 let mut is_done = true;
 
+                // Nodes are in topological order, so inline nodes started here are polled later in the same loop
                 for index in self.finished_node_index..self.handles.len() {
-                    match &mut self.handles[index].future {
-                        ActionMeta::Handle(handle) => {
-                            let res = Pin::new(handle).poll(cx);
-                            match res {
-                                Poll::Ready(action_result) => {
-                                    self.handles[index].future.clear(); // Clear the handle after polling
-                                    if self.finished_node_index == index {
-                                        self.finished_node_index += 1; // Move finished node index forward for next iteration
-                                    }
-                                    let execution_result = match action_result {
-                                        Ok(Ok(_)) => {
-                                            self.spawn_edge_nodes(index);
-                                            continue; // No error, continue to next handle
-                                        },
-                                        // In case of error, edge nodes are not spawned
-                                        Ok(Err(err)) => Err(err),
-
-                                        // This a JoinResult error, not the future error
-                                        Err(_) => Err(ActionExecError::Internal),
-                                    };
-
-                                    // Store the error of the last failed node in the registration order of nodes.
-                                    if execution_result.is_err() && index >= self.action_execution_result.0 {
-                                        self.action_execution_result = (index, execution_result);
-                                    }
-                                },
-                                Poll::Pending => {
-                                    is_done = false; // At least one handle is still pending
-                                },
+                    match self.handles[index].future.poll_started(cx) {
+                        Some(Poll::Ready(execution_result)) => {
+                            self.handles[index].future.clear(); // Clear the handle after polling
+                            if self.finished_node_index == index {
+                                self.finished_node_index += 1; // Move finished node index forward for next iteration
+                            }
+
+                            // In case of error, edge nodes are not spawned.
+                            let Err(err) = execution_result else {
+                                self.spawn_edge_nodes(index);
+                                continue; // No error, continue to next handle
+                            };
+
+                            if self.collect_errors {
+                                self.collected_errors.push((self.handles[index].tag, err.clone()));
+                            }
+
+                            // Store the error of the last failed node in the registration order of nodes.
+                            if index >= self.action_execution_result.0 {
+                                self.action_execution_result = (index, Err(err));
                             }
                         },
-                        ActionMeta::Future(_) => {
-                            // Future not yet spawned
+                        Some(Poll::Pending) => {
+                            is_done = false; // At least one handle is still pending
                         },
-                        ActionMeta::Empty => {
-                            if self.state != FutureState::Polled {
-                                not_recoverable_error!("Join handle not available for the spawned future!");
-                            }
+                        None => match self.handles[index].future {
+                            ActionMeta::Future(_) => {
+                                // Future not yet spawned
+                            },
+                            _ => {
+                                if self.state != FutureState::Polled {
+                                    not_recoverable_error!("Join handle not available for the spawned future!");
+                                }
+                            },
                         },
                     }
                 }
 
                 if is_done {
-                    FutureInternalReturn::ready(self.action_execution_result.1)
+                    FutureInternalReturn::ready(self.take_result())
                 } else {
                     FutureInternalReturn::polled()
                 }
@@ -453,6 +757,7 @@ impl Future for DagExecutor {
 #[cfg(not(loom))]
 mod tests {
     use super::*;
+    use crate::actions::action::ActionExecError;
     use crate::common::DesignConfig;
     use crate::testing::MockActionBuilder;
 
@@ -572,6 +877,53 @@ mod tests {
         builder.build(&design);
     }
 
+    #[test]
+    #[should_panic(expected = "Unresolved nodes: ['post_processing', 'fusion'].")]
+    fn graph_builder_reports_named_nodes_of_cycle() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let pre = builder.add_node_named("pre_processing", Box::new(MockActionBuilder::<()>::new().build()));
+        let post = builder.add_node_named("post_processing", Box::new(MockActionBuilder::<()>::new().build()));
+        let fusion = builder.add_node_named("fusion", Box::new(MockActionBuilder::<()>::new().build()));
+
+        builder.add_edges(pre, &[post]);
+        builder.add_edges(post, &[fusion]);
+        builder.add_edges(fusion, &[post]);
+
+        builder.build(&design);
+    }
+
+    #[test]
+    #[should_panic(expected = "Self-loop edges are not allowed. Node 'pre_processing'.")]
+    fn graph_builder_reports_named_node_of_self_loop() {
+        let mut builder = LocalGraphActionBuilder::new();
+        let pre = builder.add_node_named("pre_processing", Box::new(MockActionBuilder::<()>::new().build()));
+        builder.add_node(Box::new(MockActionBuilder::<()>::new().build()));
+
+        builder.add_edges(pre, &[pre]);
+    }
+
+    #[test]
+    fn graph_dbg_fmt_contains_node_names() {
+        struct GraphDbg<'a>(&'a LocalGraphAction);
+        impl ::core::fmt::Debug for GraphDbg<'_> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                self.0.dbg_fmt(0, f)
+            }
+        }
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let pre = builder.add_node_named("pre_processing", Box::new(MockActionBuilder::<()>::new().build()));
+        let other = builder.add_node(Box::new(MockActionBuilder::<()>::new().build()));
+        builder.add_edges(pre, &[other]);
+        let graph = builder.build(&design);
+
+        let output = format!("{:?}", GraphDbg(&graph));
+        assert!(output.contains("|node 0 'pre_processing' { indegree: 0, edges: [1] }"));
+        assert!(output.contains("|node 1 { indegree: 1, edges: [] }"));
+    }
+
     #[test]
     #[cfg(not(miri))]
     #[kyron_testing_macros::ensure_clear_mock_runtime]
@@ -1155,4 +1507,282 @@ mod tests {
         };
         assert_eq!(result, Poll::Ready(Ok(())));
     }
+
+    #[test]
+    #[cfg(not(miri))]
+    #[kyron_testing_macros::ensure_clear_mock_runtime]
+    fn graph_action_inline_strategy_executes_without_spawning() {
+        use crate::testing::OrchTestingPoller;
+        use ::core::task::Poll;
+        use kyron::testing::mock;
+        use kyron_testing::prelude::Sequence;
+        let seq = Sequence::new();
+        let action_1 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Ok(()))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let action_2 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Ok(()))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let action_3 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Internal))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let action_4 = Box::new(MockActionBuilder::<()>::new().times(0).build());
+
+        // Graph structure from left to right: 1 -> 2 -> 3 -> 4
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_1 = builder.add_node(action_1);
+        let node_2 = builder.add_node(action_2);
+        let node_3 = builder.add_node(action_3);
+        let node_4 = builder.add_node(action_4);
+        builder.add_edges(node_1, &[node_2]);
+        builder.add_edges(node_2, &[node_3]);
+        builder.add_edges(node_3, &[node_4]);
+        builder.with_execution_strategy(ExecutionStrategy::Inline);
+
+        let mut graph_action = builder.build(&design);
+        let mut poller = OrchTestingPoller::new(graph_action.try_execute().unwrap());
+
+        // All nodes are polled inline within a single poll, node 4 is skipped due to error of node 3
+        let result = poller.poll();
+        assert_eq!(mock::runtime::remaining_tasks(), 0);
+        assert_eq!(result, Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    #[kyron_testing_macros::ensure_clear_mock_runtime]
+    fn graph_action_follows_conditional_edges_by_condition() {
+        use crate::actions::ifelse::IfElseCondition;
+        use crate::testing::OrchTestingPoller;
+        use ::core::task::Poll;
+
+        struct Flag(bool);
+
+        impl IfElseCondition for Flag {
+            fn compute(&self) -> bool {
+                self.0
+            }
+        }
+
+        let action_1 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_2 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Internal))
+                .build(),
+        );
+        let action_3 = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let action_4 = Box::new(MockActionBuilder::<()>::new().times(0).build());
+
+        // Graph structure from left to right: 1 -(enabled)-> 2, 1 -(disabled)-> 3 -> 4
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design.register_if_else_condition("enabled".into(), Flag(true)).unwrap();
+        design
+            .register_if_else_condition("disabled".into(), Flag(false))
+            .unwrap();
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_1 = builder.add_node(action_1);
+        let node_2 = builder.add_node(action_2);
+        let node_3 = builder.add_node(action_3);
+        let node_4 = builder.add_node(action_4);
+        builder
+            .add_conditional_edge(node_1, node_2, "enabled".into())
+            .add_conditional_edge(node_1, node_3, "disabled".into())
+            .add_edges(node_3, &[node_4])
+            .with_execution_strategy(ExecutionStrategy::Inline);
+
+        let mut graph_action = builder.build(&design);
+        let mut poller = OrchTestingPoller::new(graph_action.try_execute().unwrap());
+
+        // Nodes 3 and 4 are not executed, the error of node 2 shows that the enabled edge was followed
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    #[kyron_testing_macros::ensure_clear_mock_runtime]
+    fn graph_action_starts_any_mode_node_once() {
+        use crate::testing::OrchTestingPoller;
+        use ::core::task::Poll;
+
+        let action_1 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_2 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_3 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Internal))
+                .build(),
+        );
+        let action_4 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_5 = Box::new(MockActionBuilder::<()>::new().times(0).build());
+
+        // Graph structure from left to right: 1 -> 4 (any), 2 -> 4 (any), 3 -> 4 (any), 2 -> 5 (all), 3 -> 5 (all)
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_1 = builder.add_node(action_1);
+        let node_2 = builder.add_node(action_2);
+        let node_3 = builder.add_node(action_3);
+        let node_4 = builder.add_node_with_mode(action_4, DependencyMode::Any);
+        let node_5 = builder.add_node_with_mode(action_5, DependencyMode::All);
+        builder
+            .add_edges(node_1, &[node_4])
+            .add_edges(node_2, &[node_4, node_5])
+            .add_edges(node_3, &[node_4, node_5])
+            .with_execution_strategy(ExecutionStrategy::Inline);
+
+        let mut graph_action = builder.build(&design);
+        let mut poller = OrchTestingPoller::new(graph_action.try_execute().unwrap());
+
+        // Node 4 runs once although two of its dependencies succeed, node 5 is skipped due to error of node 3
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    #[kyron_testing_macros::ensure_clear_mock_runtime]
+    fn graph_action_collects_errors_of_all_failed_nodes() {
+        use crate::testing::OrchTestingPoller;
+        use ::core::task::Poll;
+
+        let action_0 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Timeout))
+                .build(),
+        );
+        let action_1 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_2 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Internal))
+                .build(),
+        );
+
+        // Graph structure from left to right: 1 -> 0, 2
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_0 = builder.add_node_named("node_0", action_0);
+        let node_1 = builder.add_node(action_1);
+        builder.add_node(action_2);
+        builder
+            .add_edges(node_1, &[node_0])
+            .with_execution_strategy(ExecutionStrategy::Inline)
+            .collect_errors(true);
+
+        let mut graph_action = builder.build(&design);
+        let mut poller = OrchTestingPoller::new(graph_action.try_execute().unwrap());
+
+        // Named node is reported with its name, other nodes with the tag of their action
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::Multiple(vec![
+                ("MockAction".into(), ActionExecError::Internal),
+                ("node_0".into(), ActionExecError::Timeout)
+            ])))
+        );
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    #[kyron_testing_macros::ensure_clear_mock_runtime]
+    fn graph_action_starts_any_mode_node_after_first_dependency() {
+        use crate::testing::OrchTestingPoller;
+        use ::core::task::Poll;
+
+        let action_1 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::Internal))
+                .build(),
+        );
+        let action_2 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_3 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_4 = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let action_5 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        // Graph structure from left to right: 1 -> 3 (any), 2 -> 3 (any), 1 -> 4 (all), 2 -> 4 (all), 2 -> 5 (any)
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_1 = builder.add_node(action_1);
+        let node_2 = builder.add_node(action_2);
+        let node_3 = builder.add_node_with_mode(action_3, DependencyMode::Any);
+        let node_4 = builder.add_node_with_mode(action_4, DependencyMode::All);
+        let node_5 = builder.add_node_with_mode(action_5, DependencyMode::Any);
+        builder
+            .add_edges(node_1, &[node_3, node_4])
+            .add_edges(node_2, &[node_3, node_4, node_5])
+            .with_execution_strategy(ExecutionStrategy::Inline);
+
+        let mut graph_action = builder.build(&design);
+        let mut poller = OrchTestingPoller::new(graph_action.try_execute().unwrap());
+
+        // Nodes 3 and 5 run once thanks to node 2, node 4 is skipped due to error of node 1
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[cfg(not(miri))]
+    #[kyron_testing_macros::ensure_clear_mock_runtime]
+    fn graph_action_spawns_placed_nodes_on_dedicated_workers() {
+        use crate::testing::OrchTestingPoller;
+        use ::core::task::Poll;
+        use kyron::testing::mock;
+
+        let action_1 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_2 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let action_3 = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        // Graph structure from left to right: 1 -> 2 (dedicated worker) -> 3 (same worker as 2)
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_1 = builder.add_node(action_1);
+        let node_2 = builder.add_node(action_2);
+        let node_3 = builder.add_node(action_3);
+        builder
+            .add_edges(node_1, &[node_2])
+            .add_edges(node_2, &[node_3])
+            .with_node_placement(node_2, NodePlacement::Worker("dedicated_worker1".into()))
+            .with_node_placement(node_3, NodePlacement::SameAsParent);
+
+        let mut graph_action = builder.build(&design);
+        let mut poller = OrchTestingPoller::new(graph_action.try_execute().unwrap());
+
+        let result = loop {
+            let result = poller.poll();
+            if result.is_ready() {
+                break result;
+            }
+            mock::runtime::step();
+        };
+        assert_eq!(result, Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate edges are not allowed.")]
+    fn graph_builder_panics_for_conditional_edge_duplicating_edge() {
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_a = builder.add_node(Box::new(MockActionBuilder::<()>::new().build()));
+        let node_b = builder.add_node(Box::new(MockActionBuilder::<()>::new().build()));
+
+        builder
+            .add_edges(node_a, &[node_b])
+            .add_conditional_edge(node_a, node_b, "condition".into());
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to find condition")]
+    fn graph_builder_panics_for_unknown_edge_condition() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut builder = LocalGraphActionBuilder::new();
+        let node_a = builder.add_node(Box::new(MockActionBuilder::<()>::new().build()));
+        let node_b = builder.add_node(Box::new(MockActionBuilder::<()>::new().build()));
+
+        builder.add_conditional_edge(node_a, node_b, "missing".into());
+        let _ = builder.build(&design);
+    }
 }
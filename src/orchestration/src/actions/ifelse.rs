@@ -14,7 +14,7 @@
 use crate::{
     api::design::Design,
     common::{orch_tag::OrchestrationTag, DesignConfig},
-    prelude::{ActionBaseMeta, ActionResult, ActionTrait, ReusableBoxFutureResult},
+    prelude::{ActionBaseMeta, ActionResult, ActionTrait, ReusableBoxFutureResult, TraceSwitch},
 };
 use core::future::Future;
 use kyron::futures::reusable_box_future::{ReusableBoxFuture, ReusableBoxFuturePool};
@@ -26,6 +26,9 @@ pub trait IfElseCondition {
     fn compute(&self) -> bool;
 }
 
+/// Evaluates a registered IfElse condition, used by actions that need the condition without branches (e.g. `Loop`).
+pub(crate) type ConditionFn = Arc<dyn Fn() -> bool + Send + Sync>;
+
 /// An orchestration action that executes either branch action depending on the result of the user-provided condition object.
 pub struct IfElse {}
 
@@ -53,7 +56,12 @@ impl IfElse {
         let tag = design.get_orchestration_tag(name.into());
         assert!(tag.is_ok(), "Failed to create ifelse with name \"{}\"", name);
 
-        Self::from_tag(&tag.unwrap(), true_branch, false_branch, design.config())
+        Self::from_tag(
+            &tag.unwrap(),
+            true_branch,
+            false_branch,
+            &design.action_config(name.into()),
+        )
     }
 
     pub(crate) fn from_arc_condition<C>(
@@ -157,6 +165,11 @@ impl<C: IfElseCondition + Send + Sync + 'static> ActionTrait for IfElseArc<C> {
             self.false_branch.name()
         )
     }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.true_branch.attach_trace_switch(switch);
+        self.false_branch.attach_trace_switch(switch);
+    }
 }
 
 struct IfElseArcMutex<C: IfElseCondition + Send + 'static> {
@@ -211,6 +224,11 @@ impl<C: IfElseCondition + Send + 'static> ActionTrait for IfElseArcMutex<C> {
             self.false_branch.name()
         )
     }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.true_branch.attach_trace_switch(switch);
+        self.false_branch.attach_trace_switch(switch);
+    }
 }
 
 #[cfg(test)]
@@ -17,15 +17,21 @@ use super::action::{
 use crate::{
     api::design::Design,
     common::{orch_tag::OrchestrationTag, tag::Tag, DesignConfig},
+    core::cooperative::{budgeted, CancelFlag},
+};
+use ::core::{
+    future::poll_fn, future::Future, panic::AssertUnwindSafe, pin::pin, pin::Pin, sync::atomic::AtomicBool,
+    sync::atomic::Ordering, time::Duration,
 };
-use ::core::future::Future;
 
 use kyron::{
     core::types::UniqueWorkerId, futures::reusable_box_future::ReusableBoxFuture,
-    futures::reusable_box_future::ReusableBoxFuturePool,
+    futures::reusable_box_future::ReusableBoxFuturePool, futures::sleep, JoinHandle,
 };
+use kyron_foundation::containers::reusable_objects::{ReusableObject, ReusableObjectTrait, ReusableObjects};
 use kyron_foundation::prelude::CommonErrors;
-use std::sync::{Arc, Mutex};
+use std::panic::catch_unwind;
+use std::sync::{Arc, Mutex, PoisonError};
 
 #[cfg(not(any(test, feature = "runtime-api-mock")))]
 use kyron::safety::spawn_from_reusable_on_dedicated;
@@ -36,6 +42,41 @@ use kyron::testing::mock::spawn_from_reusable_on_dedicated;
 pub type InvokeResult = Result<(), UserErrValue>;
 pub(crate) type InvokeFunctionType = fn() -> InvokeResult;
 
+/// Boxed future returned by [`AsyncInvoke::execute`].
+pub type InvokeFuture = Pin<Box<dyn Future<Output = InvokeResult> + Send>>;
+
+/// Object safe async invoke. Allows registering invokes of components selected at runtime (e.g. by variant coding)
+/// without generics at the call site, see [`Design::register_invoke_dyn`].
+/// Note that each execution allocates the returned future.
+pub trait AsyncInvoke: Send + Sync {
+    fn execute(&self) -> InvokeFuture;
+}
+
+/// Defines how a panic raised inside an invoked callable is handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum InvokePanicPolicy {
+    /// Panic is not caught and unwinds (or aborts) as usual.
+    #[default]
+    Propagate,
+    /// Panic is caught and reported as `ActionExecError::UserPanic` with given code, so a single faulty callable
+    /// does not take down the whole process. Takes effect only in builds with `panic = "unwind"`.
+    ConvertToError(UserErrValue),
+}
+
+/// Replaces all invokes of a design with stubs, so the structure of programs (sequences, concurrency, graphs, events)
+/// executes for real while user code does not run. Useful to validate scheduling of a deployment on target before the
+/// real algorithms are ready.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum InvokeDryRun {
+    /// Invokes call user code.
+    #[default]
+    Disabled,
+    /// Invokes complete immediately with success.
+    NoOp,
+    /// Invokes sleep for given time and complete with success.
+    Delay(Duration),
+}
+
 pub struct Invoke {}
 
 impl Invoke {
@@ -56,7 +97,21 @@ impl Invoke {
             tag
         );
 
-        Self::from_tag(&tag.unwrap(), design.config())
+        Self::from_tag(&tag.unwrap(), &design.action_config(name.into()))
+    }
+
+    /// Creates a stub used in place of the invoke when [`InvokeDryRun`] is enabled.
+    pub(crate) fn stub(tag: Tag, delay: Duration, config: &DesignConfig) -> Box<dyn ActionTrait> {
+        Box::new(InvokeStub {
+            delay,
+            base: ActionBaseMeta {
+                tag,
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    config.max_concurrent_action_executions,
+                    InvokeStub::execute_impl(delay),
+                ),
+            },
+        })
     }
 
     pub(crate) fn from_fn(
@@ -69,14 +124,15 @@ impl Invoke {
             action,
             action_future_pool: ReusableBoxFuturePool::for_value(
                 config.max_concurrent_action_executions,
-                InvokeFn::action_future(action),
+                InvokeFn::action_future(action, InvokePanicPolicy::default()),
             ),
             worker_id,
+            panic_policy: config.invoke_panic_policy,
             base: ActionBaseMeta {
                 tag,
                 reusable_future_pool: ReusableBoxFuturePool::for_value(
                     config.max_concurrent_action_executions,
-                    InvokeFn::spawn_action(InstantOrSpawn::None),
+                    InvokeFn::spawn_action(InstantOrSpawn::None, InvokePanicPolicy::default()),
                 ),
             },
         })
@@ -98,14 +154,21 @@ impl Invoke {
             action,
             action_future_pool: ReusableBoxFuturePool::for_value(
                 config.max_concurrent_action_executions,
-                InvokeAsync::<A, F>::action_future(future),
+                InvokeAsync::<A, F>::action_future(future, InvokePanicPolicy::default(), Duration::ZERO, None),
             ),
+            cancel_flags: ReusableObjects::new(config.max_concurrent_action_executions, |_| PooledCancelFlag::new()),
             worker_id,
+            panic_policy: config.invoke_panic_policy,
+            cooperative_budget: config.cooperative_budget,
             base: ActionBaseMeta {
                 tag,
                 reusable_future_pool: ReusableBoxFuturePool::for_value(
                     config.max_concurrent_action_executions,
-                    InvokeAsync::<A, F>::spawn_action(InstantOrSpawn::None),
+                    InvokeAsync::<A, F>::spawn_action(
+                        InstantOrSpawn::None,
+                        InvokePanicPolicy::default(),
+                        Duration::ZERO,
+                    ),
                 ),
             },
         })
@@ -123,14 +186,15 @@ impl Invoke {
             method,
             action_future_pool: ReusableBoxFuturePool::for_value(
                 config.max_concurrent_action_executions,
-                InvokeMethod::<T>::action_future(Arc::clone(&object), method),
+                InvokeMethod::<T>::action_future(Arc::clone(&object), method, InvokePanicPolicy::default()),
             ),
             worker_id,
+            panic_policy: config.invoke_panic_policy,
             base: ActionBaseMeta {
                 tag,
                 reusable_future_pool: ReusableBoxFuturePool::for_value(
                     config.max_concurrent_action_executions,
-                    InvokeMethod::<T>::spawn_action(InstantOrSpawn::None),
+                    InvokeMethod::<T>::spawn_action(InstantOrSpawn::None, InvokePanicPolicy::default()),
                 ),
             },
         })
@@ -155,14 +219,21 @@ impl Invoke {
             method,
             action_future_pool: ReusableBoxFuturePool::for_value(
                 config.max_concurrent_action_executions,
-                InvokeMethodAsync::<T, M, F>::action_future(future),
+                InvokeMethodAsync::<T, M, F>::action_future(future, InvokePanicPolicy::default(), Duration::ZERO, None),
             ),
+            cancel_flags: ReusableObjects::new(config.max_concurrent_action_executions, |_| PooledCancelFlag::new()),
             worker_id,
+            panic_policy: config.invoke_panic_policy,
+            cooperative_budget: config.cooperative_budget,
             base: ActionBaseMeta {
                 tag,
                 reusable_future_pool: ReusableBoxFuturePool::for_value(
                     config.max_concurrent_action_executions,
-                    InvokeMethodAsync::<T, M, F>::spawn_action(InstantOrSpawn::None),
+                    InvokeMethodAsync::<T, M, F>::spawn_action(
+                        InstantOrSpawn::None,
+                        InvokePanicPolicy::default(),
+                        Duration::ZERO,
+                    ),
                 ),
             },
         })
@@ -173,32 +244,166 @@ fn invoke_result_into_action_result(result: InvokeResult) -> ActionResult {
     result.map_err(|err| err.into())
 }
 
+/// Calls the callable, converting its panic into an error if the policy requests it.
+fn call_guarded<C: FnOnce() -> InvokeResult>(policy: InvokePanicPolicy, call: C) -> ActionResult {
+    match policy {
+        InvokePanicPolicy::Propagate => invoke_result_into_action_result(call()),
+        InvokePanicPolicy::ConvertToError(code) => match catch_unwind(AssertUnwindSafe(call)) {
+            Ok(result) => invoke_result_into_action_result(result),
+            Err(_) => Err(ActionExecError::UserPanic(code)),
+        },
+    }
+}
+
+/// Awaits the future, converting a panic raised while polling it into an error if the policy requests it.
+async fn await_guarded<F: Future<Output = InvokeResult>>(policy: InvokePanicPolicy, future: F) -> ActionResult {
+    match policy {
+        InvokePanicPolicy::Propagate => invoke_result_into_action_result(future.await),
+        InvokePanicPolicy::ConvertToError(code) => {
+            let mut future = pin!(future);
+            poll_fn(|cx| match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+                Ok(poll) => poll.map(invoke_result_into_action_result),
+                Err(_) => ::core::task::Poll::Ready(Err(ActionExecError::UserPanic(code))),
+            })
+            .await
+        },
+    }
+}
+
+/// Cancel flag of an async invoke execution, pooled by the invoke so executions on a dedicated worker do not allocate
+/// it. The flag is shared with the task on the dedicated worker.
+struct PooledCancelFlag(CancelFlag);
+
+impl PooledCancelFlag {
+    fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+}
+
+impl ReusableObjectTrait for PooledCancelFlag {
+    fn reusable_clear(&mut self) {
+        if Arc::strong_count(&self.0) == 1 {
+            self.0.store(false, Ordering::Release);
+        } else {
+            // The cancelled task still runs and observes the flag, so the next execution gets a new one
+            *self = Self::new();
+        }
+    }
+}
+
+/// Task of an invoke spawned on a dedicated worker. Dropping it before the task finished aborts the task and cancels
+/// the cooperative points of the invoke, so it does not keep running detached.
+struct DedicatedTask {
+    handle: Option<JoinHandle<ActionResult>>,
+    cancelled: Option<ReusableObject<PooledCancelFlag>>,
+}
+
+impl DedicatedTask {
+    async fn join(
+        future: ReusableBoxFuture<ActionResult>,
+        worker_id: UniqueWorkerId,
+        cancelled: Option<ReusableObject<PooledCancelFlag>>,
+    ) -> ActionResult {
+        let mut task = DedicatedTask {
+            handle: Some(spawn_from_reusable_on_dedicated(future, worker_id)),
+            cancelled,
+        };
+
+        poll_fn(|cx| {
+            let Some(handle) = task.handle.as_mut() else {
+                return ::core::task::Poll::Ready(Err(ActionExecError::Internal));
+            };
+
+            // The JoinResult error is not the future error, so map it to internal one
+            let poll = Pin::new(handle)
+                .poll(cx)
+                .map(|res| res.unwrap_or(Err(ActionExecError::Internal)));
+            if poll.is_ready() {
+                task.handle = None;
+            }
+            poll
+        })
+        .await
+    }
+}
+
+impl Drop for DedicatedTask {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            if let Some(cancelled) = &self.cancelled {
+                cancelled.0.store(true, Ordering::Release);
+            }
+            handle.abort();
+        }
+    }
+}
+
+struct InvokeStub {
+    delay: Duration,
+    base: ActionBaseMeta,
+}
+
+impl InvokeStub {
+    async fn execute_impl(delay: Duration) -> ActionResult {
+        if !delay.is_zero() {
+            sleep::sleep(delay).await;
+        }
+        Ok(())
+    }
+}
+
+impl ActionTrait for InvokeStub {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base
+            .reusable_future_pool
+            .next(InvokeStub::execute_impl(self.delay))
+    }
+
+    fn name(&self) -> &'static str {
+        "InvokeStub"
+    }
+
+    fn tag(&self) -> Tag {
+        self.base.tag
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)
+    }
+}
+
 enum InstantOrSpawn<I> {
     None,
     Instant(I),
-    Spawn(ReusableBoxFuture<ActionResult>, UniqueWorkerId),
+    Spawn(
+        ReusableBoxFuture<ActionResult>,
+        UniqueWorkerId,
+        Option<ReusableObject<PooledCancelFlag>>,
+    ),
 }
 
 struct InvokeFn {
     action: InvokeFunctionType,
     action_future_pool: ReusableBoxFuturePool<ActionResult>,
     worker_id: Option<UniqueWorkerId>,
+    panic_policy: InvokePanicPolicy,
     base: ActionBaseMeta,
 }
 
 impl InvokeFn {
-    async fn action_future(action: InvokeFunctionType) -> ActionResult {
-        invoke_result_into_action_result(action())
+    async fn action_future(action: InvokeFunctionType, panic_policy: InvokePanicPolicy) -> ActionResult {
+        call_guarded(panic_policy, action)
     }
 
-    async fn spawn_action(instant_or_spawn: InstantOrSpawn<InvokeFunctionType>) -> ActionResult {
+    async fn spawn_action(
+        instant_or_spawn: InstantOrSpawn<InvokeFunctionType>,
+        panic_policy: InvokePanicPolicy,
+    ) -> ActionResult {
         match instant_or_spawn {
             InstantOrSpawn::None => Ok(()),
-            InstantOrSpawn::Instant(action) => invoke_result_into_action_result(action()),
-            InstantOrSpawn::Spawn(future, worker_id) => match spawn_from_reusable_on_dedicated(future, worker_id).await
-            {
-                Ok(result) => result,
-                Err(_) => Err(ActionExecError::Internal),
+            InstantOrSpawn::Instant(action) => call_guarded(panic_policy, action),
+            InstantOrSpawn::Spawn(future, worker_id, cancelled) => {
+                DedicatedTask::join(future, worker_id, cancelled).await
             },
         }
     }
@@ -207,17 +412,21 @@ impl InvokeFn {
 impl ActionTrait for InvokeFn {
     fn try_execute(&mut self) -> ReusableBoxFutureResult {
         if let Some(worker_id) = self.worker_id {
-            match self.action_future_pool.next(InvokeFn::action_future(self.action)) {
-                Ok(future) => self
-                    .base
-                    .reusable_future_pool
-                    .next(InvokeFn::spawn_action(InstantOrSpawn::Spawn(future, worker_id))),
+            match self
+                .action_future_pool
+                .next(InvokeFn::action_future(self.action, self.panic_policy))
+            {
+                Ok(future) => self.base.reusable_future_pool.next(InvokeFn::spawn_action(
+                    InstantOrSpawn::Spawn(future, worker_id, None),
+                    self.panic_policy,
+                )),
                 Err(_) => Err(CommonErrors::GenericError),
             }
         } else {
-            self.base
-                .reusable_future_pool
-                .next(InvokeFn::spawn_action(InstantOrSpawn::Instant(self.action)))
+            self.base.reusable_future_pool.next(InvokeFn::spawn_action(
+                InstantOrSpawn::Instant(self.action),
+                self.panic_policy,
+            ))
         }
     }
 
@@ -225,6 +434,10 @@ impl ActionTrait for InvokeFn {
         "Invoke"
     }
 
+    fn tag(&self) -> Tag {
+        self.base.tag
+    }
+
     fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         writeln!(f, "{}|-{}", " ".repeat(nest), self.name())
     }
@@ -237,7 +450,10 @@ where
 {
     action: A,
     action_future_pool: ReusableBoxFuturePool<ActionResult>,
+    cancel_flags: ReusableObjects<PooledCancelFlag>,
     worker_id: Option<UniqueWorkerId>,
+    panic_policy: InvokePanicPolicy,
+    cooperative_budget: Duration,
     base: ActionBaseMeta,
 }
 
@@ -246,18 +462,25 @@ where
     A: Fn() -> F + 'static + Send,
     F: Future<Output = InvokeResult> + 'static + Send,
 {
-    async fn action_future(future: F) -> ActionResult {
-        invoke_result_into_action_result(future.await)
+    async fn action_future(
+        future: F,
+        panic_policy: InvokePanicPolicy,
+        budget: Duration,
+        cancelled: Option<CancelFlag>,
+    ) -> ActionResult {
+        await_guarded(panic_policy, budgeted(budget, cancelled, future)).await
     }
 
-    async fn spawn_action(instant_or_spawn: InstantOrSpawn<F>) -> ActionResult {
+    async fn spawn_action(
+        instant_or_spawn: InstantOrSpawn<F>,
+        panic_policy: InvokePanicPolicy,
+        budget: Duration,
+    ) -> ActionResult {
         match instant_or_spawn {
             InstantOrSpawn::None => Ok(()),
-            InstantOrSpawn::Instant(action) => invoke_result_into_action_result(action.await),
-            InstantOrSpawn::Spawn(future, worker_id) => match spawn_from_reusable_on_dedicated(future, worker_id).await
-            {
-                Ok(result) => result,
-                Err(_) => Err(ActionExecError::Internal),
+            InstantOrSpawn::Instant(action) => await_guarded(panic_policy, budgeted(budget, None, action)).await,
+            InstantOrSpawn::Spawn(future, worker_id, cancelled) => {
+                DedicatedTask::join(future, worker_id, cancelled).await
             },
         }
     }
@@ -270,25 +493,26 @@ where
 {
     fn try_execute(&mut self) -> ReusableBoxFutureResult {
         if let Some(worker_id) = self.worker_id {
-            match self
-                .action_future_pool
-                .next(InvokeAsync::<A, F>::action_future((self.action)()))
-            {
-                Ok(future) => {
-                    self.base
-                        .reusable_future_pool
-                        .next(InvokeAsync::<A, F>::spawn_action(InstantOrSpawn::Spawn(
-                            future, worker_id,
-                        )))
-                },
+            let cancelled = self.cancel_flags.next_object()?;
+            match self.action_future_pool.next(InvokeAsync::<A, F>::action_future(
+                (self.action)(),
+                self.panic_policy,
+                self.cooperative_budget,
+                Some(Arc::clone(&cancelled.0)),
+            )) {
+                Ok(future) => self.base.reusable_future_pool.next(InvokeAsync::<A, F>::spawn_action(
+                    InstantOrSpawn::Spawn(future, worker_id, Some(cancelled)),
+                    self.panic_policy,
+                    self.cooperative_budget,
+                )),
                 Err(_) => Err(CommonErrors::GenericError),
             }
         } else {
-            self.base
-                .reusable_future_pool
-                .next(InvokeAsync::<A, F>::spawn_action(InstantOrSpawn::Instant((self
-                    .action)(
-                ))))
+            self.base.reusable_future_pool.next(InvokeAsync::<A, F>::spawn_action(
+                InstantOrSpawn::Instant((self.action)()),
+                self.panic_policy,
+                self.cooperative_budget,
+            ))
         }
     }
 
@@ -296,6 +520,10 @@ where
         "InvokeAsync"
     }
 
+    fn tag(&self) -> Tag {
+        self.base.tag
+    }
+
     fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         writeln!(f, "{}|-{}", " ".repeat(nest), self.name())
     }
@@ -308,26 +536,35 @@ struct InvokeMethod<T: 'static + Send> {
     method: InvokeMethodType<T>,
     action_future_pool: ReusableBoxFuturePool<ActionResult>,
     worker_id: Option<UniqueWorkerId>,
+    panic_policy: InvokePanicPolicy,
     base: ActionBaseMeta,
 }
 
 impl<T: 'static + Send> InvokeMethod<T> {
-    async fn action_future(object: Arc<Mutex<T>>, method: InvokeMethodType<T>) -> ActionResult {
-        let mut object = object.lock().unwrap();
-        invoke_result_into_action_result(method(&mut object))
+    async fn action_future(
+        object: Arc<Mutex<T>>,
+        method: InvokeMethodType<T>,
+        panic_policy: InvokePanicPolicy,
+    ) -> ActionResult {
+        call_guarded(panic_policy, || {
+            // A panic converted by the policy poisons the mutex, the object is still handed to the next execution
+            let mut object = object.lock().unwrap_or_else(PoisonError::into_inner);
+            method(&mut object)
+        })
     }
 
-    async fn spawn_action(instant_or_spawn: InstantOrSpawn<(Arc<Mutex<T>>, InvokeMethodType<T>)>) -> ActionResult {
+    async fn spawn_action(
+        instant_or_spawn: InstantOrSpawn<(Arc<Mutex<T>>, InvokeMethodType<T>)>,
+        panic_policy: InvokePanicPolicy,
+    ) -> ActionResult {
         match instant_or_spawn {
             InstantOrSpawn::None => Ok(()),
-            InstantOrSpawn::Instant((object, method)) => {
-                let mut object = object.lock().unwrap();
-                invoke_result_into_action_result(method(&mut object))
-            },
-            InstantOrSpawn::Spawn(future, worker_id) => match spawn_from_reusable_on_dedicated(future, worker_id).await
-            {
-                Ok(result) => result,
-                Err(_) => Err(ActionExecError::Internal),
+            InstantOrSpawn::Instant((object, method)) => call_guarded(panic_policy, || {
+                let mut object = object.lock().unwrap_or_else(PoisonError::into_inner);
+                method(&mut object)
+            }),
+            InstantOrSpawn::Spawn(future, worker_id, cancelled) => {
+                DedicatedTask::join(future, worker_id, cancelled).await
             },
         }
     }
@@ -336,26 +573,22 @@ impl<T: 'static + Send> InvokeMethod<T> {
 impl<T: 'static + Send> ActionTrait for InvokeMethod<T> {
     fn try_execute(&mut self) -> ReusableBoxFutureResult {
         if let Some(worker_id) = self.worker_id {
-            match self
-                .action_future_pool
-                .next(InvokeMethod::<T>::action_future(Arc::clone(&self.object), self.method))
-            {
-                Ok(future) => {
-                    self.base
-                        .reusable_future_pool
-                        .next(InvokeMethod::<T>::spawn_action(InstantOrSpawn::Spawn(
-                            future, worker_id,
-                        )))
-                },
+            match self.action_future_pool.next(InvokeMethod::<T>::action_future(
+                Arc::clone(&self.object),
+                self.method,
+                self.panic_policy,
+            )) {
+                Ok(future) => self.base.reusable_future_pool.next(InvokeMethod::<T>::spawn_action(
+                    InstantOrSpawn::Spawn(future, worker_id, None),
+                    self.panic_policy,
+                )),
                 Err(_) => Err(CommonErrors::GenericError),
             }
         } else {
-            self.base
-                .reusable_future_pool
-                .next(InvokeMethod::<T>::spawn_action(InstantOrSpawn::Instant((
-                    Arc::clone(&self.object),
-                    self.method,
-                ))))
+            self.base.reusable_future_pool.next(InvokeMethod::<T>::spawn_action(
+                InstantOrSpawn::Instant((Arc::clone(&self.object), self.method)),
+                self.panic_policy,
+            ))
         }
     }
 
@@ -363,6 +596,10 @@ impl<T: 'static + Send> ActionTrait for InvokeMethod<T> {
         "InvokeAsync"
     }
 
+    fn tag(&self) -> Tag {
+        self.base.tag
+    }
+
     fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         writeln!(f, "{}|-{}", " ".repeat(nest), self.name())
     }
@@ -377,7 +614,10 @@ where
     object: Arc<Mutex<T>>,
     method: M,
     action_future_pool: ReusableBoxFuturePool<ActionResult>,
+    cancel_flags: ReusableObjects<PooledCancelFlag>,
     worker_id: Option<UniqueWorkerId>,
+    panic_policy: InvokePanicPolicy,
+    cooperative_budget: Duration,
     base: ActionBaseMeta,
 }
 
@@ -387,18 +627,25 @@ where
     M: FnMut(Arc<Mutex<T>>) -> F + 'static + Send,
     F: Future<Output = InvokeResult> + 'static + Send,
 {
-    async fn action_future(future: F) -> ActionResult {
-        invoke_result_into_action_result(future.await)
+    async fn action_future(
+        future: F,
+        panic_policy: InvokePanicPolicy,
+        budget: Duration,
+        cancelled: Option<CancelFlag>,
+    ) -> ActionResult {
+        await_guarded(panic_policy, budgeted(budget, cancelled, future)).await
     }
 
-    async fn spawn_action(instant_or_spawn: InstantOrSpawn<F>) -> ActionResult {
+    async fn spawn_action(
+        instant_or_spawn: InstantOrSpawn<F>,
+        panic_policy: InvokePanicPolicy,
+        budget: Duration,
+    ) -> ActionResult {
         match instant_or_spawn {
             InstantOrSpawn::None => Ok(()),
-            InstantOrSpawn::Instant(future) => invoke_result_into_action_result(future.await),
-            InstantOrSpawn::Spawn(future, worker_id) => match spawn_from_reusable_on_dedicated(future, worker_id).await
-            {
-                Ok(result) => result,
-                Err(_) => Err(ActionExecError::Internal),
+            InstantOrSpawn::Instant(future) => await_guarded(panic_policy, budgeted(budget, None, future)).await,
+            InstantOrSpawn::Spawn(future, worker_id, cancelled) => {
+                DedicatedTask::join(future, worker_id, cancelled).await
             },
         }
     }
@@ -412,31 +659,43 @@ where
 {
     fn try_execute(&mut self) -> ReusableBoxFutureResult {
         if let Some(worker_id) = self.worker_id {
+            let cancelled = self.cancel_flags.next_object()?;
             match self
                 .action_future_pool
-                .next(InvokeMethodAsync::<T, M, F>::action_future((self.method)(Arc::clone(
-                    &self.object,
-                )))) {
+                .next(InvokeMethodAsync::<T, M, F>::action_future(
+                    (self.method)(Arc::clone(&self.object)),
+                    self.panic_policy,
+                    self.cooperative_budget,
+                    Some(Arc::clone(&cancelled.0)),
+                )) {
                 Ok(future) => self
                     .base
                     .reusable_future_pool
-                    .next(InvokeMethodAsync::<T, M, F>::spawn_action(InstantOrSpawn::Spawn(
-                        future, worker_id,
-                    ))),
+                    .next(InvokeMethodAsync::<T, M, F>::spawn_action(
+                        InstantOrSpawn::Spawn(future, worker_id, Some(cancelled)),
+                        self.panic_policy,
+                        self.cooperative_budget,
+                    )),
                 Err(_) => Err(CommonErrors::GenericError),
             }
         } else {
             self.base
                 .reusable_future_pool
-                .next(InvokeMethodAsync::<T, M, F>::spawn_action(InstantOrSpawn::Instant(
-                    (self.method)(Arc::clone(&self.object)),
-                )))
+                .next(InvokeMethodAsync::<T, M, F>::spawn_action(
+                    InstantOrSpawn::Instant((self.method)(Arc::clone(&self.object))),
+                    self.panic_policy,
+                    self.cooperative_budget,
+                ))
         }
     }
     fn name(&self) -> &'static str {
         "InvokeAsync"
     }
 
+    fn tag(&self) -> Tag {
+        self.base.tag
+    }
+
     fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         writeln!(f, "{}|-{}", " ".repeat(nest), self.name())
     }
@@ -445,8 +704,10 @@ where
 #[cfg(test)]
 #[cfg(not(loom))]
 mod tests {
-    use crate::common::DesignConfig;
-    use std::sync::{Arc, Mutex};
+    use super::{ActionExecError, InvokeDryRun, InvokePanicPolicy, PooledCancelFlag, ReusableObjectTrait};
+    use crate::{common::DesignConfig, testing::OrchTestingPoller};
+    use ::core::{sync::atomic::Ordering, task::Poll};
+    use std::sync::{Arc, Mutex, PoisonError};
 
     #[test]
     fn test_fn() {
@@ -545,4 +806,147 @@ mod tests {
         assert!(action2.try_execute().is_ok());
         assert!(action2.try_execute().is_ok());
     }
+
+    #[test]
+    fn panic_in_fn_is_converted_to_error_when_configured() {
+        let config = DesignConfig {
+            invoke_panic_policy: InvokePanicPolicy::ConvertToError(0xdead_u64.into()),
+            ..Default::default()
+        };
+
+        fn test() -> super::InvokeResult {
+            panic!("Faulty callable");
+        }
+
+        let mut action = super::Invoke::from_fn("tag".into(), test, None, &config);
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserPanic(0xdead_u64.into())))
+        );
+
+        // The action stays usable after the panic
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserPanic(0xdead_u64.into())))
+        );
+    }
+
+    #[test]
+    fn panic_in_method_is_converted_to_error_and_object_stays_usable() {
+        let config = DesignConfig {
+            invoke_panic_policy: InvokePanicPolicy::ConvertToError(0xdead_u64.into()),
+            ..Default::default()
+        };
+
+        struct TestObject {
+            calls: usize,
+        }
+
+        impl TestObject {
+            fn test_method(&mut self) -> super::InvokeResult {
+                self.calls += 1;
+                if self.calls == 1 {
+                    panic!("Faulty method");
+                }
+                Ok(())
+            }
+        }
+
+        let object = Arc::new(Mutex::new(TestObject { calls: 0 }));
+        let mut action = super::Invoke::from_method(
+            "tag".into(),
+            Arc::clone(&object),
+            TestObject::test_method,
+            None,
+            &config,
+        );
+
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserPanic(0xdead_u64.into())))
+        );
+
+        // The poisoned object is recovered for the next execution
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(object.lock().unwrap_or_else(PoisonError::into_inner).calls, 2);
+    }
+
+    #[test]
+    fn panic_in_async_is_converted_to_error_when_configured() {
+        let config = DesignConfig {
+            invoke_panic_policy: InvokePanicPolicy::ConvertToError(0xbeef_u64.into()),
+            ..Default::default()
+        };
+
+        async fn test() -> super::InvokeResult {
+            panic!("Faulty async callable");
+        }
+
+        let mut action = super::Invoke::from_async("tag".into(), test, None, &config);
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserPanic(0xbeef_u64.into())))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Faulty callable")]
+    fn panic_in_fn_is_propagated_by_default() {
+        let config = DesignConfig::default();
+
+        fn test() -> super::InvokeResult {
+            panic!("Faulty callable");
+        }
+
+        let mut action = super::Invoke::from_fn("tag".into(), test, None, &config);
+        let _ = OrchTestingPoller::new(action.try_execute().unwrap()).poll();
+    }
+
+    #[test]
+    fn dry_run_replaces_invoke_with_stub() {
+        let config = DesignConfig {
+            invoke_dry_run: InvokeDryRun::NoOp,
+            ..Default::default()
+        };
+        let design = crate::api::design::Design::new("Design".into(), config);
+
+        fn test() -> super::InvokeResult {
+            panic!("User code shall not run in dry run");
+        }
+
+        design.register_invoke_fn("tag".into(), test).unwrap();
+
+        let mut action = super::Invoke::from_design("tag", &design);
+        assert_eq!(action.name(), "InvokeStub");
+        let mut poller = OrchTestingPoller::new(action.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn pooled_cancel_flag_is_reset_for_next_execution() {
+        let mut flag = PooledCancelFlag::new();
+        let pooled = Arc::as_ptr(&flag.0);
+        flag.0.store(true, Ordering::Release);
+
+        flag.reusable_clear();
+        assert!(!flag.0.load(Ordering::Acquire));
+        assert_eq!(Arc::as_ptr(&flag.0), pooled);
+    }
+
+    #[test]
+    fn pooled_cancel_flag_still_observed_by_cancelled_task_is_replaced() {
+        let mut flag = PooledCancelFlag::new();
+        let observed = Arc::clone(&flag.0);
+        flag.0.store(true, Ordering::Release);
+
+        flag.reusable_clear();
+        assert!(observed.load(Ordering::Acquire));
+        assert!(!flag.0.load(Ordering::Acquire));
+        assert!(!Arc::ptr_eq(&flag.0, &observed));
+    }
 }
@@ -12,12 +12,24 @@
 // *******************************************************************************
 
 pub mod action;
+pub mod budget;
 pub mod catch;
 pub mod concurrency;
+pub mod custom;
+pub mod deadline;
+pub mod fallback;
+pub mod gate;
 pub mod graph;
 pub mod ifelse;
 pub mod invoke;
+pub mod repeat;
+pub mod retry;
 pub mod select;
 pub mod sequence;
+pub mod serve;
+pub mod switch;
 pub mod sync;
+pub mod throttle;
+pub mod timeout;
 pub mod trigger;
+pub mod watchdog;
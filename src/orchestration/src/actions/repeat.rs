@@ -0,0 +1,436 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult, TraceSwitch};
+use super::ifelse::ConditionFn;
+use crate::api::design::Design;
+use ::core::future::{poll_fn, Future};
+use ::core::task::Poll;
+use kyron::futures::reusable_box_future::{ReusableBoxFuture, ReusableBoxFuturePool};
+use kyron_foundation::prelude::*;
+use std::sync::{Arc, Mutex};
+
+///
+/// Builder for [`Loop`] action. At least one way to end the loop has to be configured: number of iterations,
+/// condition or stop action.
+///
+pub struct LoopBuilder {
+    action: Box<dyn ActionTrait>,
+    iterations: Option<u32>,
+    condition: Option<ConditionFn>,
+    stop_action: Option<Box<dyn ActionTrait>>,
+}
+
+impl LoopBuilder {
+    /// Creates a builder for action executing `action` repeatedly.
+    pub fn new(action: Box<dyn ActionTrait>) -> Self {
+        Self {
+            action,
+            iterations: None,
+            condition: None,
+            stop_action: None,
+        }
+    }
+
+    /// Ends the loop after `iterations` executions of the child action.
+    pub fn with_iterations(mut self, iterations: u32) -> Self {
+        self.iterations = Some(iterations);
+        self
+    }
+
+    /// Ends the loop once the IfElse condition registered in `design` under `name` returns `false`. The condition is
+    /// evaluated before each iteration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no IfElse condition registered under `name`.
+    pub fn with_condition(mut self, name: &str, design: &Design) -> Self {
+        self.condition = Some(find_condition(name, design));
+        self
+    }
+
+    /// Ends the loop after the current iteration once `action` completes, e.g. a `Sync` on a shutdown event.
+    /// The stop action is started together with the first iteration and its error, if any, is returned by the loop.
+    pub fn with_stop_action(mut self, action: Box<dyn ActionTrait>) -> Self {
+        self.stop_action = Some(action);
+        self
+    }
+
+    /// Builds the `Loop` action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if neither iterations, condition nor stop action is configured.
+    pub fn build(self, design: &Design) -> Box<Loop> {
+        assert!(
+            self.iterations.is_some() || self.condition.is_some() || self.stop_action.is_some(),
+            "Loop: Iterations, condition or stop action shall be configured."
+        );
+
+        let action = Arc::new(Mutex::new(self.action));
+
+        Box::new(Loop {
+            base: ActionBaseMeta {
+                tag: "orch::internal::loop".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    Loop::execute_impl(Arc::clone(&action), None, None, None, None),
+                ),
+            },
+            action,
+            iterations: self.iterations,
+            condition: self.condition,
+            max_iterations: None,
+            stop_action: self.stop_action,
+        })
+    }
+}
+
+///
+/// Builder for `while` flavour of [`Loop`] action, executing the body while a registered condition holds, e.g. to
+/// read a sensor until a valid frame arrives.
+///
+pub struct WhileBuilder {
+    action: Box<dyn ActionTrait>,
+    condition: ConditionFn,
+    max_iterations: Option<u32>,
+}
+
+impl WhileBuilder {
+    /// Creates a builder for action executing `action` while the IfElse condition registered in `design` under `name`
+    /// returns `true`. The condition is evaluated before each iteration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no IfElse condition registered under `name`.
+    pub fn new(name: &str, design: &Design, action: Box<dyn ActionTrait>) -> Self {
+        Self {
+            action,
+            condition: find_condition(name, design),
+            max_iterations: None,
+        }
+    }
+
+    /// Guards against infinite loops: if the condition still holds after `max_iterations` executions of the body, the
+    /// loop fails with `ActionExecError::Timeout`, which can be handled by `Catch` with `ErrorFilter::Timeouts`.
+    pub fn with_max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Builds the `Loop` action.
+    pub fn build(self, design: &Design) -> Box<Loop> {
+        let action = Arc::new(Mutex::new(self.action));
+
+        Box::new(Loop {
+            base: ActionBaseMeta {
+                tag: "orch::internal::while".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    Loop::execute_impl(Arc::clone(&action), None, None, None, None),
+                ),
+            },
+            action,
+            iterations: None,
+            condition: Some(self.condition),
+            max_iterations: self.max_iterations,
+            stop_action: None,
+        })
+    }
+}
+
+/// Finds the IfElse condition registered in `design` under `name`, panics if there is none.
+fn find_condition(name: &str, design: &Design) -> ConditionFn {
+    let tag = design.get_orchestration_tag(name.into());
+    assert!(tag.is_ok(), "Failed to create loop with condition \"{}\"", name);
+
+    let tag = tag.unwrap();
+    let condition = tag.action_provider().borrow_mut().provide_condition(*tag.tag());
+    assert!(condition.is_some(), "Failed to create loop with condition \"{}\"", name);
+
+    condition.unwrap()
+}
+
+///
+/// Executes the child action repeatedly, one iteration after another, until the configured number of iterations is
+/// reached, the condition returns `false` or the stop action completes, whichever comes first. Error of the child
+/// action ends the loop and is propagated. Unlike `Program::run_n`, only the child sub-tree is repeated.
+///
+pub struct Loop {
+    base: ActionBaseMeta,
+    action: Arc<Mutex<Box<dyn ActionTrait>>>,
+    iterations: Option<u32>,
+    condition: Option<ConditionFn>,
+    max_iterations: Option<u32>,
+    stop_action: Option<Box<dyn ActionTrait>>,
+}
+
+impl Loop {
+    async fn execute_impl(
+        action: Arc<Mutex<Box<dyn ActionTrait>>>,
+        iterations: Option<u32>,
+        condition: Option<ConditionFn>,
+        max_iterations: Option<u32>,
+        stop_future: Option<ReusableBoxFuture<ActionResult>>,
+    ) -> ActionResult {
+        let mut stop_future = stop_future.map(ReusableBoxFuture::into_pin);
+        let mut stop_result = None;
+        let mut iteration = 0;
+
+        loop {
+            if let Some(result) = stop_result {
+                debug!("Loop: Stopped after {} iterations", iteration);
+                return result;
+            }
+
+            if iterations.is_some_and(|iterations| iteration >= iterations)
+                || condition.as_ref().is_some_and(|condition| !condition())
+            {
+                return Ok(());
+            }
+
+            if max_iterations.is_some_and(|max_iterations| iteration >= max_iterations) {
+                warn!("Loop: Condition still holds after {} iterations", iteration);
+                return Err(ActionExecError::Timeout);
+            }
+
+            let future = action.lock().unwrap().try_execute();
+            let mut future = match future {
+                Ok(future) => future.into_pin(),
+                Err(_) => return Err(ActionExecError::Internal),
+            };
+
+            // Keep polling the stop action while the iteration runs, so its completion is not missed.
+            poll_fn(|cx| {
+                if stop_result.is_none() {
+                    if let Some(Poll::Ready(result)) = stop_future.as_mut().map(|stop| stop.as_mut().poll(cx)) {
+                        stop_result = Some(result);
+                    }
+                }
+
+                future.as_mut().poll(cx)
+            })
+            .await?;
+
+            iteration += 1;
+        }
+    }
+}
+
+impl ActionTrait for Loop {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        let stop_future = match self.stop_action.as_mut() {
+            Some(stop_action) => Some(stop_action.try_execute()?),
+            None => None,
+        };
+
+        self.base.reusable_future_pool.next(Loop::execute_impl(
+            Arc::clone(&self.action),
+            self.iterations,
+            self.condition.clone(),
+            self.max_iterations,
+            stop_future,
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "Loop"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(
+            f,
+            "{}|-{} (iterations: {:?}, condition: {}) - {:?}",
+            " ".repeat(nest),
+            self.name(),
+            self.iterations,
+            self.condition.is_some(),
+            self.base
+        )?;
+        self.action.lock().unwrap().dbg_fmt(nest + 1, f)?;
+        if let Some(stop_action) = self.stop_action.as_ref() {
+            writeln!(f, "{} |stop", " ".repeat(nest))?;
+            stop_action.dbg_fmt(nest + 1, f)?;
+        }
+        Ok(())
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.action.lock().unwrap().attach_trace_switch(switch);
+        if let Some(stop_action) = self.stop_action.as_mut() {
+            stop_action.attach_trace_switch(switch);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::ifelse::IfElseCondition,
+        common::DesignConfig,
+        testing::{MockActionBuilder, OrchTestingPoller, TestAsyncAction},
+    };
+    use ::core::future;
+    use ::core::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn loop_executes_child_given_number_of_times() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .times(3)
+                .will_repeatedly_return(Ok(()))
+                .build(),
+        );
+
+        let mut repeat = LoopBuilder::new(action).with_iterations(3).build(&design);
+        let mut poller = OrchTestingPoller::new(repeat.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn loop_propagates_error_of_child() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Ok(()))
+                .will_once_return(Err(ActionExecError::UserError(0xcafe_u64.into())))
+                .build(),
+        );
+
+        let mut repeat = LoopBuilder::new(action).with_iterations(5).build(&design);
+        let mut poller = OrchTestingPoller::new(repeat.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(0xcafe_u64.into())))
+        );
+    }
+
+    struct CountDown(AtomicU32);
+
+    impl IfElseCondition for CountDown {
+        fn compute(&self) -> bool {
+            self.0
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |left| left.checked_sub(1))
+                .is_ok()
+        }
+    }
+
+    #[test]
+    fn loop_ends_when_condition_returns_false() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design
+            .register_if_else_condition("count_down".into(), CountDown(AtomicU32::new(2)))
+            .unwrap();
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .times(2)
+                .will_repeatedly_return(Ok(()))
+                .build(),
+        );
+
+        let mut repeat = LoopBuilder::new(action)
+            .with_condition("count_down", &design)
+            .build(&design);
+        let mut poller = OrchTestingPoller::new(repeat.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn loop_ends_after_iteration_in_which_stop_action_completes() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let stop_action = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        let mut repeat = LoopBuilder::new(action).with_stop_action(stop_action).build(&design);
+        let mut poller = OrchTestingPoller::new(repeat.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn loop_keeps_running_while_stop_action_is_pending() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .times(4)
+                .will_repeatedly_return(Ok(()))
+                .build(),
+        );
+        let stop_action = Box::new(TestAsyncAction::new(future::pending));
+
+        let mut repeat = LoopBuilder::new(action)
+            .with_iterations(4)
+            .with_stop_action(stop_action)
+            .build(&design);
+        let mut poller = OrchTestingPoller::new(repeat.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn while_ends_when_condition_returns_false_within_guard() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design
+            .register_if_else_condition("count_down".into(), CountDown(AtomicU32::new(2)))
+            .unwrap();
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .times(2)
+                .will_repeatedly_return(Ok(()))
+                .build(),
+        );
+
+        let mut repeat = WhileBuilder::new("count_down", &design, action)
+            .with_max_iterations(2)
+            .build(&design);
+        let mut poller = OrchTestingPoller::new(repeat.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn while_fails_with_timeout_when_guard_is_reached() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design
+            .register_if_else_condition("count_down".into(), CountDown(AtomicU32::new(5)))
+            .unwrap();
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .times(3)
+                .will_repeatedly_return(Ok(()))
+                .build(),
+        );
+
+        let mut repeat = WhileBuilder::new("count_down", &design, action)
+            .with_max_iterations(3)
+            .build(&design);
+        let mut poller = OrchTestingPoller::new(repeat.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Timeout)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Loop: Iterations, condition or stop action shall be configured.")]
+    fn loop_without_end_panics() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(MockActionBuilder::<()>::new().build());
+        let _ = LoopBuilder::new(action).build(&design);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to create loop with condition \"missing\"")]
+    fn loop_with_unknown_condition_panics() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(MockActionBuilder::<()>::new().build());
+        let _ = LoopBuilder::new(action).with_condition("missing", &design);
+    }
+}
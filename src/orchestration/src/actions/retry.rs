@@ -0,0 +1,255 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult, TraceSwitch};
+use crate::{api::design::Design, common::DesignConfig};
+use ::core::time::Duration;
+use kyron::futures::{reusable_box_future::ReusableBoxFuturePool, sleep};
+use kyron_foundation::prelude::*;
+use std::sync::{Arc, Mutex};
+
+/// Delay between attempts of the [`Retry`] action.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RetryBackoff {
+    /// Next attempt is started immediately.
+    #[default]
+    None,
+    /// Next attempt is started after a fixed delay.
+    Fixed(Duration),
+    /// Delay starts with `initial` and is doubled after each attempt, up to `max`.
+    Exponential { initial: Duration, max: Duration },
+}
+
+impl RetryBackoff {
+    /// Delay before the retry with given index (0 for the first retry).
+    fn delay(&self, retry: u32) -> Duration {
+        match *self {
+            RetryBackoff::None => Duration::ZERO,
+            RetryBackoff::Fixed(delay) => delay,
+            RetryBackoff::Exponential { initial, max } => 2_u32
+                .checked_pow(retry)
+                .and_then(|factor| initial.checked_mul(factor))
+                .map_or(max, |delay| delay.min(max)),
+        }
+    }
+}
+
+///
+/// Builder for [`Retry`] action.
+///
+pub struct RetryBuilder {
+    attempts: u32,
+    backoff: RetryBackoff,
+    action: Box<dyn ActionTrait>,
+}
+
+impl RetryBuilder {
+    /// Creates a builder for action executing `action` up to `attempts` times until it succeeds.
+    pub fn new(attempts: u32, action: Box<dyn ActionTrait>) -> Self {
+        Self {
+            attempts,
+            backoff: RetryBackoff::None,
+            action,
+        }
+    }
+
+    /// Sets delay between attempts, no delay by default.
+    pub fn with_backoff(mut self, backoff: RetryBackoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Builds the `Retry` action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if number of attempts is zero.
+    pub fn build(self, design: &Design) -> Box<Retry> {
+        self.build_with_config(&design.config)
+    }
+
+    /// Builds the `Retry` action for an invoke wrapped by the deployment, see `Deployment::set_invoke_retry`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if number of attempts is zero.
+    pub(crate) fn build_with_config(self, config: &DesignConfig) -> Box<Retry> {
+        assert!(
+            self.attempts > 0,
+            "Retry: Number of attempts shall be greater than zero."
+        );
+
+        let action = Arc::new(Mutex::new(self.action));
+
+        Box::new(Retry {
+            base: ActionBaseMeta {
+                tag: "orch::internal::retry".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    config.max_concurrent_action_executions,
+                    Retry::execute_impl(Arc::clone(&action), self.attempts, self.backoff),
+                ),
+            },
+            attempts: self.attempts,
+            backoff: self.backoff,
+            action,
+        })
+    }
+}
+
+///
+/// Executes the child action again when it fails with `ActionExecError::UserError` or `ActionExecError::Internal`,
+/// up to configured number of attempts, with optional backoff between them. Error of the last attempt is propagated
+/// if all attempts fail. Other errors are propagated immediately.
+///
+pub struct Retry {
+    base: ActionBaseMeta,
+    attempts: u32,
+    backoff: RetryBackoff,
+    action: Arc<Mutex<Box<dyn ActionTrait>>>,
+}
+
+impl Retry {
+    async fn execute_impl(
+        action: Arc<Mutex<Box<dyn ActionTrait>>>,
+        attempts: u32,
+        backoff: RetryBackoff,
+    ) -> ActionResult {
+        let mut attempt = 0;
+
+        loop {
+            let future = action.lock().unwrap().try_execute();
+            let result = match future {
+                Ok(future) => future.into_pin().await,
+                Err(_) => Err(ActionExecError::Internal),
+            };
+
+            attempt += 1;
+            match result {
+                Err(ActionExecError::UserError(_) | ActionExecError::Internal) if attempt < attempts => {
+                    debug!("Retry: Attempt {} of {} failed with {:?}", attempt, attempts, result);
+
+                    let delay = backoff.delay(attempt - 1);
+                    if !delay.is_zero() {
+                        sleep::sleep(delay).await;
+                    }
+                },
+                _ => return result,
+            }
+        }
+    }
+}
+
+impl ActionTrait for Retry {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base.reusable_future_pool.next(Retry::execute_impl(
+            Arc::clone(&self.action),
+            self.attempts,
+            self.backoff,
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "Retry"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(
+            f,
+            "{}|-{} (attempts: {}, backoff: {:?}) - {:?}",
+            " ".repeat(nest),
+            self.name(),
+            self.attempts,
+            self.backoff,
+            self.base
+        )?;
+        self.action.lock().unwrap().dbg_fmt(nest + 1, f)
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.action.lock().unwrap().attach_trace_switch(switch);
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::testing::{MockActionBuilder, OrchTestingPoller};
+    use ::core::task::Poll;
+
+    #[test]
+    fn retry_succeeds_after_failed_attempts() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::UserError(1_u64.into())))
+                .will_once_return(Err(ActionExecError::Internal))
+                .will_once_return(Ok(()))
+                .build(),
+        );
+
+        let mut retry = RetryBuilder::new(3, action).build(&design);
+        let mut poller = OrchTestingPoller::new(retry.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn retry_propagates_error_of_last_attempt() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::UserError(1_u64.into())))
+                .will_once_return(Err(ActionExecError::UserError(2_u64.into())))
+                .build(),
+        );
+
+        let mut retry = RetryBuilder::new(2, action).build(&design);
+        let mut poller = OrchTestingPoller::new(retry.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(2_u64.into())))
+        );
+    }
+
+    #[test]
+    fn retry_does_not_repeat_non_recoverable_failure() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::NonRecoverableFailure))
+                .build(),
+        );
+
+        let mut retry = RetryBuilder::new(3, action).build(&design);
+        let mut poller = OrchTestingPoller::new(retry.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::NonRecoverableFailure)));
+    }
+
+    #[test]
+    fn exponential_backoff_is_capped() {
+        let backoff = RetryBackoff::Exponential {
+            initial: Duration::from_millis(10),
+            max: Duration::from_millis(50),
+        };
+
+        assert_eq!(backoff.delay(0), Duration::from_millis(10));
+        assert_eq!(backoff.delay(1), Duration::from_millis(20));
+        assert_eq!(backoff.delay(2), Duration::from_millis(40));
+        assert_eq!(backoff.delay(3), Duration::from_millis(50));
+        assert_eq!(backoff.delay(40), Duration::from_millis(50));
+        assert_eq!(
+            RetryBackoff::Fixed(Duration::from_millis(5)).delay(7),
+            Duration::from_millis(5)
+        );
+    }
+}
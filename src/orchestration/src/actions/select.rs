@@ -11,14 +11,16 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use super::action::{ActionBaseMeta, ActionResult, ActionTrait, ReusableBoxFutureResult};
+use super::action::{
+    ActionBaseMeta, ActionMeta, ActionResult, ActionTrait, ExecutionStrategy, ReusableBoxFutureResult, TraceSwitch,
+};
 use crate::api::design::Design;
 use ::core::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
-use kyron::futures::reusable_box_future::{ReusableBoxFuture, ReusableBoxFuturePool};
+use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
 use kyron_foundation::{
     base::fast_rand::FastRand,
     containers::{
@@ -33,12 +35,16 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// concurrently and return the result of the first action that completes.
 pub struct SelectBuilder {
     cases: Option<GrowableVec<Box<dyn ActionTrait>>>,
+    strategy: ExecutionStrategy,
 }
 
 impl SelectBuilder {
     /// Create the builder.
     pub fn new() -> Self {
-        Self { cases: None }
+        Self {
+            cases: None,
+            strategy: ExecutionStrategy::Inline,
+        }
     }
 
     /// Add an action as one of the cases.
@@ -47,14 +53,22 @@ impl SelectBuilder {
         self
     }
 
+    /// Set how the cases are executed, [`ExecutionStrategy::Inline`] by default.
+    /// With [`ExecutionStrategy::Spawn`] every case runs as its own task and the tasks of the
+    /// remaining cases are aborted as soon as the first case finishes.
+    pub fn with_execution_strategy(&mut self, strategy: ExecutionStrategy) -> &mut Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Build a `Select` action out of the added cases.
     pub fn build(&mut self, design: &Design) -> Box<Select> {
         let cases = self.cases.take().expect("Select requires at least one case.");
         let cases_len = cases.len();
-        let mut reusable_case_pins = ReusableObjects::<Vec<Pin<ReusableBoxFuture<ActionResult>>>>::new(
-            design.config.max_concurrent_action_executions,
-            |_| Vec::new_in_global(cases_len),
-        );
+        let mut reusable_case_pins =
+            ReusableObjects::<Vec<ActionMeta>>::new(design.config.max_concurrent_action_executions, |_| {
+                Vec::new_in_global(cases_len)
+            });
 
         Box::new(Select {
             base: ActionBaseMeta {
@@ -65,11 +79,13 @@ impl SelectBuilder {
                         reusable_case_pins
                             .next_object()
                             .expect("Not enough reusable case handles to build the Select action."),
+                        self.strategy,
                     ),
                 ),
             },
             cases: cases.into(),
             reusable_case_pins,
+            strategy: self.strategy,
         })
     }
 }
@@ -84,6 +100,7 @@ impl Default for SelectBuilder {
 /// the `Select` action will be the result of the first case action that finishes. The remaining
 /// case actions will be cancelled. The order of case actions matters. `Select` polls case actions
 /// in random order. `Select` can be executed multiple times, also concurrently depending on the configuration.
+/// By default the cases are polled inline, see [`SelectBuilder::with_execution_strategy`] for spawning them instead.
 /// # Notes
 /// 1. If multiple case actions finish at the same time, the result of the first polled action will be returned.
 /// 2. Sync actions are not supposed to be used as case actions. If used, they will be executed to completion when polled
@@ -91,7 +108,8 @@ impl Default for SelectBuilder {
 pub struct Select {
     base: ActionBaseMeta,
     cases: Vec<Box<dyn ActionTrait>>,
-    reusable_case_pins: ReusableObjects<Vec<Pin<ReusableBoxFuture<ActionResult>>>>,
+    reusable_case_pins: ReusableObjects<Vec<ActionMeta>>,
+    strategy: ExecutionStrategy,
 }
 
 impl ActionTrait for Select {
@@ -99,10 +117,12 @@ impl ActionTrait for Select {
         let mut case_pins = self.reusable_case_pins.next_object()?;
 
         for case in self.cases.iter_mut() {
-            case_pins.push(case.try_execute()?.into_pin());
+            case_pins.push(ActionMeta::new(case.try_execute()?));
         }
 
-        self.base.reusable_future_pool.next(SelectFuture::new(case_pins))
+        self.base
+            .reusable_future_pool
+            .next(SelectFuture::new(case_pins, self.strategy))
     }
 
     fn name(&self) -> &'static str {
@@ -118,15 +138,21 @@ impl ActionTrait for Select {
             case.dbg_fmt(nest + 1, formatter)
         })
     }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.cases.iter_mut().for_each(|case| case.attach_trace_switch(switch));
+    }
 }
 
 struct SelectFuture {
-    case_pins: ReusableObject<Vec<Pin<ReusableBoxFuture<ActionResult>>>>,
+    case_pins: ReusableObject<Vec<ActionMeta>>,
+    strategy: ExecutionStrategy,
+    is_started: bool,
     rand: FastRand,
 }
 
 impl SelectFuture {
-    fn new(case_pins: ReusableObject<Vec<Pin<ReusableBoxFuture<ActionResult>>>>) -> Self {
+    fn new(case_pins: ReusableObject<Vec<ActionMeta>>, strategy: ExecutionStrategy) -> Self {
         let seed = match SystemTime::now().duration_since(UNIX_EPOCH) {
             Ok(since) => since.as_millis() as u64,
             Err(_) => {
@@ -138,6 +164,8 @@ impl SelectFuture {
 
         Self {
             case_pins,
+            strategy,
+            is_started: false,
             rand: FastRand::new(seed),
         }
     }
@@ -152,6 +180,14 @@ impl Future for SelectFuture {
             "Should not be possible with the current SelectBuilder implementation."
         );
 
+        if !self.is_started {
+            let strategy = self.strategy;
+            self.case_pins.iter_mut().for_each(|case| {
+                case.start(strategy);
+            });
+            self.is_started = true;
+        }
+
         let mut loop_result = None;
         let start_i = self.rand.next() as usize;
         let case_count = self.case_pins.len();
@@ -159,17 +195,18 @@ impl Future for SelectFuture {
         for i in 0..case_count {
             let case_pin = &mut self.case_pins[(start_i + i) % case_count];
 
-            match case_pin.as_mut().poll(cx) {
-                Poll::Ready(result) => {
+            match case_pin.poll_started(cx) {
+                Some(Poll::Ready(result)) => {
                     loop_result = Some(result);
                     break;
                 },
-                Poll::Pending => (),
+                Some(Poll::Pending) | None => (),
             }
         }
 
         if let Some(result) = loop_result {
-            // The idea is to try to cancel the remaining cases.
+            // Cancel the remaining cases, inline futures are dropped and spawned tasks are aborted.
+            self.case_pins.iter_mut().for_each(ActionMeta::abort);
             self.case_pins.clear();
 
             Poll::Ready(result)
@@ -181,7 +218,9 @@ impl Future for SelectFuture {
 
 impl ReusableObjectTrait for SelectFuture {
     fn reusable_clear(&mut self) {
+        self.case_pins.iter_mut().for_each(ActionMeta::abort);
         self.case_pins.clear();
+        self.is_started = false;
     }
 }
 
@@ -197,6 +236,7 @@ mod tests {
     };
     use core::future;
     use kyron::futures::yield_now::yield_now;
+    use kyron::testing::mock;
     use kyron_testing_macros::ensure_clear_mock_runtime;
     async fn async_fn_with_await() -> ActionResult {
         yield_now().await;
@@ -404,4 +444,65 @@ mod tests {
             Poll::Ready(Err(ActionExecError::UserError(0x1234abcd.into())))
         );
     }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn spawned_cases_first_finished_wins() {
+        let mock1 = Box::new(TestAsyncAction::new(future::pending));
+        let mock2 = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(ActionExecError::UserError(0xcafe_u64.into())))
+                .build(),
+        );
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut select = SelectBuilder::new()
+            .with_case(mock1)
+            .with_case(mock2)
+            .with_execution_strategy(ExecutionStrategy::Spawn)
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(select.try_execute().unwrap());
+        // The first poll only spawns the cases.
+        assert_eq!(poller.poll(), Poll::Pending);
+        assert!(mock::runtime::remaining_tasks() > 0);
+
+        mock::runtime::step();
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(0xcafe_u64.into())))
+        );
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn spawned_losing_cases_are_aborted() {
+        let pending = Box::new(TestAsyncAction::new(future::pending));
+        let finishing = Box::new(TestAsyncAction::new(|| async {
+            Err(ActionExecError::UserError(0xcafe_u64.into()))
+        }));
+
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut select = SelectBuilder::new()
+            .with_case(pending)
+            .with_case(finishing)
+            .with_execution_strategy(ExecutionStrategy::Spawn)
+            .build(&design);
+
+        // More executions than pooled futures of the pending case, so they are only available if the losing
+        // executions are aborted instead of running detached.
+        for _ in 0..10 {
+            let mut poller = OrchTestingPoller::new(select.try_execute().unwrap());
+            assert_eq!(poller.poll(), Poll::Pending);
+
+            mock::runtime::step();
+            assert_eq!(
+                poller.poll(),
+                Poll::Ready(Err(ActionExecError::UserError(0xcafe_u64.into())))
+            );
+
+            mock::runtime::step();
+            assert_eq!(mock::runtime::remaining_tasks(), 0);
+        }
+    }
 }
@@ -11,8 +11,11 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use super::action::{ActionBaseMeta, ActionResult, ActionTrait, ReusableBoxFutureResult};
+use super::action::{
+    ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult, StepGate, TraceSwitch,
+};
 use crate::common::tag::Tag;
+use std::sync::Arc;
 
 use kyron::futures::reusable_box_future::{ReusableBoxFuture, ReusableBoxFuturePool};
 use kyron_foundation::{
@@ -24,11 +27,25 @@ const REUSABLE_FUTURE_POOL_SIZE: usize = 2;
 const REUSABLE_VEC_POOL_SIZE: usize = 2;
 const DEFAULT_TAG: &str = "orch::internal::sequence";
 
+/// Decides whether a step of the `Sequence` is executed, based on the results of the previous steps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum StepCondition {
+    /// Executed if there is no unhandled error.
+    Always,
+    /// Executed only if no step failed so far, not even one whose error was handled by an `OnError` step.
+    IfOk,
+    /// Executed only if there is an unhandled error. Its success marks the error as handled.
+    OnError,
+    /// Executed if there is no unhandled error. Its failure is logged only.
+    Optional,
+}
+
 ///
 /// Construct a `SequenceBuilder` for creating a `Sequence` action
 ///
 pub struct SequenceBuilder {
     actions: GrowableVec<Box<dyn ActionTrait>>,
+    conditions: GrowableVec<StepCondition>,
 }
 
 impl Default for SequenceBuilder {
@@ -45,6 +62,7 @@ impl SequenceBuilder {
         const REUSABLE_VEC_SIZE: usize = 4;
         Self {
             actions: GrowableVec::new(REUSABLE_VEC_SIZE),
+            conditions: GrowableVec::new(REUSABLE_VEC_SIZE),
         }
     }
 
@@ -52,7 +70,37 @@ impl SequenceBuilder {
     /// Add an action to the `Sequence`
     ///
     pub fn with_step(&mut self, action: Box<dyn ActionTrait>) -> &mut Self {
+        self.with_conditional_step(action, StepCondition::Always)
+    }
+
+    ///
+    /// Add an action to the `Sequence` that is executed only if all previous steps succeeded.
+    /// In contrast to `with_step()`, it is skipped also when an earlier error was handled by a `with_step_on_error()` step.
+    ///
+    pub fn with_step_if_ok(&mut self, action: Box<dyn ActionTrait>) -> &mut Self {
+        self.with_conditional_step(action, StepCondition::IfOk)
+    }
+
+    ///
+    /// Add an action to the `Sequence` that is executed only if a previous step failed and the error was not handled yet.
+    /// Steps between the failed one and this one are skipped. If the action succeeds, the error is considered handled
+    /// and the `Sequence` continues with next steps, otherwise its error is propagated.
+    ///
+    pub fn with_step_on_error(&mut self, action: Box<dyn ActionTrait>) -> &mut Self {
+        self.with_conditional_step(action, StepCondition::OnError)
+    }
+
+    ///
+    /// Add an action to the `Sequence` whose failure is logged, but neither terminates the `Sequence` nor is propagated.
+    /// It is executed like a `with_step()` step, and skipped by the same conditions.
+    ///
+    pub fn with_optional_step(&mut self, action: Box<dyn ActionTrait>) -> &mut Self {
+        self.with_conditional_step(action, StepCondition::Optional)
+    }
+
+    fn with_conditional_step(&mut self, action: Box<dyn ActionTrait>, condition: StepCondition) -> &mut Self {
         self.actions.push(action);
+        self.conditions.push(condition);
         self
     }
 
@@ -72,9 +120,13 @@ impl SequenceBuilder {
 
         // No more actions may be added beyond this point
         self.actions.lock();
+        self.conditions.lock();
+        let conditions: Arc<[StepCondition]> = self.conditions.iter().copied().collect();
+        let step_tags: Arc<[Tag]> = self.actions.iter().map(|action| action.tag()).collect();
 
         // Create pools
-        let (futures_vec_pool, reusable_future_pool) = SequenceBuilder::create_pools(self.actions.len());
+        let (futures_vec_pool, reusable_future_pool) =
+            SequenceBuilder::create_pools(self.actions.len(), Arc::clone(&conditions), Arc::clone(&step_tags));
 
         // Move the actions from Builder's GrowableVec to Sequence's fixed-sized Vec
         // Here we also reverse the order, so that the actions become already in the correct order,
@@ -89,11 +141,15 @@ impl SequenceBuilder {
         // Finally, return the `Sequence` action
         Box::new(Sequence {
             actions,
+            conditions,
+            step_tags,
             base: ActionBaseMeta {
                 tag: Tag::from_str_static(DEFAULT_TAG),
                 reusable_future_pool,
             },
             futures_vec_pool,
+            trace: TraceSwitch::default(),
+            gate: StepGate::default(),
         })
     }
 
@@ -102,6 +158,8 @@ impl SequenceBuilder {
     ///
     fn create_pools(
         futures_size: usize,
+        conditions: Arc<[StepCondition]>,
+        step_tags: Arc<[Tag]>,
     ) -> (
         ReusableVecPool<ReusableBoxFuture<ActionResult>>,
         ReusableBoxFuturePool<ActionResult>,
@@ -115,7 +173,14 @@ impl SequenceBuilder {
         // Populate the futures' collection to initialize the reusable future pool's layout
         let reusable_future_pool = ReusableBoxFuturePool::<ActionResult>::for_value(
             REUSABLE_FUTURE_POOL_SIZE,
-            Sequence::execute_impl(Tag::from_str_static(DEFAULT_TAG), futures_vec),
+            Sequence::execute_impl(
+                Tag::from_str_static(DEFAULT_TAG),
+                futures_vec,
+                conditions,
+                step_tags,
+                TraceSwitch::default(),
+                StepGate::default(),
+            ),
         );
 
         (futures_vec_pool, reusable_future_pool)
@@ -127,31 +192,78 @@ impl SequenceBuilder {
 /// manner.
 ///
 /// If any action encounters an error, the `Sequence` execution will terminate immediately,
-/// preventing the execution of any remaining actions.
+/// preventing the execution of any remaining actions, except the ones added via `with_step_on_error()`.
+/// Errors of actions added via `with_optional_step()` are ignored.
+///
+/// As the run action of a program, its steps can be executed one by one, see [`crate::api::ProgramStepper`].
 ///
 pub struct Sequence {
     actions: Vec<Box<dyn ActionTrait>>,
+    conditions: Arc<[StepCondition]>,
+    step_tags: Arc<[Tag]>,
     base: ActionBaseMeta,
     futures_vec_pool: ReusableVecPool<ReusableBoxFuture<ActionResult>>,
+    trace: TraceSwitch,
+    gate: StepGate,
 }
 
 impl Sequence {
-    async fn execute_impl(tag: Tag, mut futures: ReusableObject<Vec<ReusableBoxFuture<ActionResult>>>) -> ActionResult {
-        // Execute all futures in the collection, but terminates immediately upon error
+    async fn execute_impl(
+        tag: Tag,
+        mut futures: ReusableObject<Vec<ReusableBoxFuture<ActionResult>>>,
+        conditions: Arc<[StepCondition]>,
+        step_tags: Arc<[Tag]>,
+        trace: TraceSwitch,
+        gate: StepGate,
+    ) -> ActionResult {
+        let mut unhandled_error: Option<ActionExecError> = None;
+        let mut any_error = false;
+
+        // Execute all futures in the collection, but terminates immediately upon error unless error handling step follows.
         // We can directly pop() without reversing the order here, because the reversion already took place
         // during elements transfer from Builder's GrowableVec to Sequence's Vec
+        let mut step = 0;
         while let Some(future) = futures.pop() {
-            tracing_adapter!(step = ?tag, "Before awaiting step");
-            let result = future.into_pin().await;
-            if result.is_err() {
-                // Terminate sequence and propagate the error
-                error!("Error in sequence step {:?}", tag);
-                return result;
+            let index = step;
+            let condition = conditions[index];
+            step += 1;
+
+            let run = match condition {
+                StepCondition::Always | StepCondition::Optional => unhandled_error.is_none(),
+                StepCondition::IfOk => !any_error,
+                StepCondition::OnError => unhandled_error.is_some(),
+            };
+
+            if !run {
+                if unhandled_error.is_some() && !conditions[step..].contains(&StepCondition::OnError) {
+                    // Nothing can handle the error anymore, terminate sequence
+                    break;
+                }
+                continue;
+            }
+
+            gate.wait(index, step_tags[index]).await;
+            if trace.is_on() {
+                tracing_adapter!(step = ?tag, "Before awaiting step");
+            }
+            match future.into_pin().await {
+                Ok(()) => unhandled_error = None,
+                Err(e) if condition == StepCondition::Optional => {
+                    warn!("Ignored error {:?} of optional step in sequence {:?}", e, tag);
+                },
+                Err(e) => {
+                    error!("Error in sequence step {:?}", tag);
+                    unhandled_error = Some(e);
+                    any_error = true;
+                },
+            }
+            if trace.is_on() {
+                tracing_adapter!(step = ?tag, "After awaiting step");
             }
-            tracing_adapter!(step = ?tag, "After awaiting step");
         }
 
-        Ok(())
+        // Propagate the error that was not handled
+        unhandled_error.map_or(Ok(()), Err)
     }
 }
 
@@ -169,9 +281,14 @@ impl ActionTrait for Sequence {
         })?;
 
         // Get a future from the reusable future pool and execute it
-        self.base
-            .reusable_future_pool
-            .next(Sequence::execute_impl(self.base.tag, futures_vec_pool))
+        self.base.reusable_future_pool.next(Sequence::execute_impl(
+            self.base.tag,
+            futures_vec_pool,
+            Arc::clone(&self.conditions),
+            Arc::clone(&self.step_tags),
+            self.trace.clone(),
+            self.gate.clone(),
+        ))
     }
 
     fn name(&self) -> &'static str {
@@ -181,10 +298,29 @@ impl ActionTrait for Sequence {
     fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         let indent = " ".repeat(nest);
         writeln!(f, "{}|-{} - {:?}", indent, self.name(), self.base)?;
-        self.actions.iter().try_for_each(|action| {
-            writeln!(f, "{} |step", indent)?;
-            action.dbg_fmt(nest + 1, f)
-        })
+        self.actions
+            .iter()
+            .zip(self.conditions.iter().rev()) // Actions are stored in reversed order
+            .try_for_each(|(action, condition)| {
+                match condition {
+                    StepCondition::Always => writeln!(f, "{} |step", indent)?,
+                    StepCondition::IfOk => writeln!(f, "{} |step if ok", indent)?,
+                    StepCondition::OnError => writeln!(f, "{} |step on error", indent)?,
+                    StepCondition::Optional => writeln!(f, "{} |optional step", indent)?,
+                }
+                action.dbg_fmt(nest + 1, f)
+            })
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.trace = switch.clone();
+        self.actions
+            .iter_mut()
+            .for_each(|action| action.attach_trace_switch(switch));
+    }
+
+    fn attach_step_gate(&mut self, gate: &StepGate) {
+        self.gate = gate.clone();
     }
 }
 
@@ -193,6 +329,7 @@ impl ActionTrait for Sequence {
 mod tests {
     use super::*;
     use crate::actions::action::{ActionExecError, UserErrValue};
+    use crate::program::{StepControl, StepPosition};
     use crate::testing::{MockActionBuilder, OrchTestingPoller};
 
     use ::core::task::Poll;
@@ -346,4 +483,179 @@ mod tests {
         let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
         assert_eq!(Poll::Ready(Err(ActionExecError::NonRecoverableFailure)), mock.poll());
     }
+
+    #[test]
+    fn on_error_step_is_skipped_when_no_error() {
+        let mock_ok = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let mock_on_error = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let mock_if_ok = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+        let mut seq = SequenceBuilder::new()
+            .with_step(mock_ok)
+            .with_step_on_error(mock_on_error)
+            .with_step_if_ok(mock_if_ok)
+            .build();
+
+        let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
+        assert_eq!(Poll::Ready(Ok(())), mock.poll());
+    }
+
+    #[test]
+    fn on_error_step_handles_error_and_sequence_continues() {
+        let seq = kyron_testing::prelude::Sequence::new();
+        let user_err = ActionExecError::UserError(UserErrValue::from(42));
+        let mock_err = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(user_err))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let mock_skipped = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let mock_on_error = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Ok(()))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let mock_if_ok = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let mock_after = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Ok(()))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let mut seq = SequenceBuilder::new()
+            .with_step(mock_err)
+            .with_step(mock_skipped)
+            .with_step_on_error(mock_on_error)
+            .with_step_if_ok(mock_if_ok)
+            .with_step(mock_after)
+            .build();
+
+        let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
+        assert_eq!(Poll::Ready(Ok(())), mock.poll());
+    }
+
+    #[test]
+    fn failing_on_error_step_propagates_its_error() {
+        let user_err_1 = ActionExecError::UserError(UserErrValue::from(1));
+        let user_err_2 = ActionExecError::UserError(UserErrValue::from(2));
+        let mock_err = Box::new(MockActionBuilder::<()>::new().will_once_return(Err(user_err_1)).build());
+        let mock_on_error = Box::new(MockActionBuilder::<()>::new().will_once_return(Err(user_err_2)).build());
+        let mock_skipped = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let mut seq = SequenceBuilder::new()
+            .with_step(mock_err)
+            .with_step_on_error(mock_on_error)
+            .with_step(mock_skipped)
+            .build();
+
+        let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
+        assert_eq!(Poll::Ready(Err(user_err_2)), mock.poll());
+    }
+
+    #[test]
+    fn failing_optional_step_does_not_terminate_sequence() {
+        let seq = kyron_testing::prelude::Sequence::new();
+        let user_err = ActionExecError::UserError(UserErrValue::from(42));
+        let mock_optional = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Err(user_err))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let mock_on_error = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let mock_if_ok = Box::new(
+            MockActionBuilder::<()>::new()
+                .will_once_return(Ok(()))
+                .in_sequence(&seq)
+                .build(),
+        );
+        let mut seq = SequenceBuilder::new()
+            .with_optional_step(mock_optional)
+            .with_step_on_error(mock_on_error)
+            .with_step_if_ok(mock_if_ok)
+            .build();
+
+        let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
+        assert_eq!(Poll::Ready(Ok(())), mock.poll());
+    }
+
+    #[test]
+    fn optional_step_is_skipped_after_unhandled_error() {
+        let user_err = ActionExecError::UserError(UserErrValue::from(42));
+        let mock_err = Box::new(MockActionBuilder::<()>::new().will_once_return(Err(user_err)).build());
+        let mock_optional = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let mut seq = SequenceBuilder::new()
+            .with_step(mock_err)
+            .with_optional_step(mock_optional)
+            .build();
+
+        let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
+        assert_eq!(Poll::Ready(Err(user_err)), mock.poll());
+    }
+
+    #[test]
+    fn attached_step_gate_executes_one_step_per_permit() {
+        let seq = kyron_testing::prelude::Sequence::new();
+        let mock_1 = Box::new(MockActionBuilder::<()>::new().times(1).in_sequence(&seq).build());
+        let mock_nested_a = Box::new(MockActionBuilder::<()>::new().times(1).in_sequence(&seq).build());
+        let mock_nested_b = Box::new(MockActionBuilder::<()>::new().times(1).in_sequence(&seq).build());
+        let mut seq = SequenceBuilder::new()
+            .with_step(mock_1)
+            .with_step(
+                SequenceBuilder::new()
+                    .with_step(mock_nested_a)
+                    .with_step(mock_nested_b)
+                    .build(),
+            )
+            .build();
+
+        let control = Arc::new(StepControl::new());
+        control.set_enabled(true);
+        seq.attach_step_gate(&StepGate::new(Arc::clone(&control)));
+
+        let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
+        assert_eq!(Poll::Pending, mock.poll());
+        assert_eq!(
+            control.position(),
+            Some(StepPosition {
+                step: 0,
+                tag: Tag::from_str_static("MockAction"),
+                waiting: true
+            })
+        );
+
+        control.step();
+        assert_eq!(Poll::Pending, mock.poll());
+        assert_eq!(
+            control.position(),
+            Some(StepPosition {
+                step: 1,
+                tag: Tag::from_str_static("Sequence"),
+                waiting: true
+            })
+        );
+
+        // Steps of the nested sequence are executed at once
+        control.step();
+        assert_eq!(Poll::Ready(Ok(())), mock.poll());
+        assert_eq!(control.position().map(|position| position.waiting), Some(false));
+    }
+
+    #[test]
+    fn disabling_step_gate_releases_waiting_step() {
+        let mock_1 = Box::new(MockActionBuilder::<()>::new().times(1).build());
+        let mock_2 = Box::new(MockActionBuilder::<()>::new().times(1).build());
+        let mut seq = SequenceBuilder::new().with_step(mock_1).with_step(mock_2).build();
+
+        let control = Arc::new(StepControl::new());
+        control.set_enabled(true);
+        seq.attach_step_gate(&StepGate::new(Arc::clone(&control)));
+
+        let mut mock = OrchTestingPoller::new(seq.try_execute().unwrap());
+        assert_eq!(Poll::Pending, mock.poll());
+
+        control.set_enabled(false);
+        assert_eq!(Poll::Ready(Ok(())), mock.poll());
+        assert_eq!(control.position(), None);
+    }
 }
@@ -0,0 +1,123 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionResult, ActionTrait, ReusableBoxFutureResult};
+use crate::api::design::Design;
+use crate::core::mailbox::Responder;
+use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
+use kyron_foundation::prelude::*;
+use std::sync::Arc;
+
+///
+/// Builder for [`Serve`] action.
+///
+pub struct ServeBuilder<Req, Resp> {
+    responder: Responder<Req, Resp>,
+    handler: Arc<dyn Fn(&Req) -> Resp + Send + Sync>,
+}
+
+impl<Req: Send + 'static, Resp: Send + 'static> ServeBuilder<Req, Resp> {
+    /// Creates a builder for action answering requests of `responder` with `handler`.
+    pub fn new<H>(responder: Responder<Req, Resp>, handler: H) -> Self
+    where
+        H: Fn(&Req) -> Resp + Send + Sync + 'static,
+    {
+        Self {
+            responder,
+            handler: Arc::new(handler),
+        }
+    }
+
+    /// Builds the `Serve` action.
+    pub fn build(self, design: &Design) -> Box<Serve<Req, Resp>> {
+        Box::new(Serve {
+            base: ActionBaseMeta {
+                tag: "orch::internal::serve".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    Serve::execute_impl(self.responder.clone(), Arc::clone(&self.handler)),
+                ),
+            },
+            responder: self.responder,
+            handler: self.handler,
+        })
+    }
+}
+
+///
+/// Waits for the next request of a mailbox, like `Sync` waits for an event, and answers it with the handler.
+/// Every execution answers one request.
+///
+pub struct Serve<Req, Resp> {
+    base: ActionBaseMeta,
+    responder: Responder<Req, Resp>,
+    handler: Arc<dyn Fn(&Req) -> Resp + Send + Sync>,
+}
+
+impl<Req: Send + 'static, Resp: Send + 'static> Serve<Req, Resp> {
+    async fn execute_impl(
+        responder: Responder<Req, Resp>,
+        handler: Arc<dyn Fn(&Req) -> Resp + Send + Sync>,
+    ) -> ActionResult {
+        let request = responder.receive().await;
+        let response = handler(&request);
+        request.respond(response);
+        Ok(())
+    }
+}
+
+impl<Req: Send + 'static, Resp: Send + 'static> ActionTrait for Serve<Req, Resp> {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base
+            .reusable_future_pool
+            .next(Serve::execute_impl(self.responder.clone(), Arc::clone(&self.handler)))
+    }
+
+    fn name(&self) -> &'static str {
+        "Serve"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{common::DesignConfig, core::mailbox::mailbox, testing::OrchTestingPoller};
+    use ::core::task::Poll;
+    use kyron_testing::poller::TestingFuturePoller;
+
+    #[test]
+    fn serve_answers_one_request_per_execution() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let (requester, responder) = mailbox::<u32, u32>(2);
+        let mut serve = ServeBuilder::new(responder, |request: &u32| request * 10).build(&design);
+
+        let mut poller = OrchTestingPoller::new(serve.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Pending);
+
+        let waker = kyron::testing::get_task_based_waker();
+        let mut first = TestingFuturePoller::new(requester.request(1).unwrap());
+        let mut second = TestingFuturePoller::new(requester.request(2).unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(first.poll_with_waker(&waker), Poll::Ready(Ok(10)));
+        assert!(second.poll_with_waker(&waker).is_pending());
+
+        let mut poller = OrchTestingPoller::new(serve.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(second.poll_with_waker(&waker), Poll::Ready(Ok(20)));
+    }
+}
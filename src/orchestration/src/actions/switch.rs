@@ -0,0 +1,316 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult, TraceSwitch};
+use crate::api::design::Design;
+use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
+use kyron_foundation::{containers::growable_vec::GrowableVec, prelude::*};
+use std::sync::{Arc, Mutex};
+
+/// The trait that needs to be implemented by the Switch condition object provided by the user.
+/// The compute method result is the key of the case executed by the Switch action.
+pub trait SwitchCondition {
+    fn compute(&self) -> usize;
+}
+
+/// Evaluates a registered Switch condition.
+pub(crate) type SwitchConditionFn = Arc<dyn Fn() -> usize + Send + Sync>;
+
+/// Object used to construct the [`Switch`] action out of a condition registered in the design.
+pub struct SwitchBuilder {
+    condition: SwitchConditionFn,
+    cases: Option<GrowableVec<(usize, Box<dyn ActionTrait>)>>,
+    default: Option<Box<dyn ActionTrait>>,
+}
+
+impl SwitchBuilder {
+    /// Create the builder for the Switch condition registered in `design` under `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no Switch condition registered under `name`.
+    pub fn new(name: &str, design: &Design) -> Self {
+        let tag = design.get_orchestration_tag(name.into());
+        assert!(tag.is_ok(), "Failed to create switch with name \"{}\"", name);
+
+        let tag = tag.unwrap();
+        let condition = tag.action_provider().borrow_mut().provide_switch_condition(*tag.tag());
+        assert!(condition.is_some(), "Failed to create switch with name \"{}\"", name);
+
+        Self {
+            condition: condition.unwrap(),
+            cases: None,
+            default: None,
+        }
+    }
+
+    /// Add an action executed when the condition returns `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a case with the same key was already added.
+    pub fn with_case(&mut self, key: usize, action: Box<dyn ActionTrait>) -> &mut Self {
+        let cases = self.cases.get_or_insert(GrowableVec::new(2));
+        assert!(
+            !cases.iter().any(|(case_key, _)| *case_key == key),
+            "Switch: Case {} is already added.",
+            key
+        );
+
+        cases.push((key, action));
+        self
+    }
+
+    /// Set an action executed when the condition returns a key without a case.
+    pub fn with_default(&mut self, action: Box<dyn ActionTrait>) -> &mut Self {
+        self.default = Some(action);
+        self
+    }
+
+    /// Build a `Switch` action out of the added cases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no case is added.
+    pub fn build(&mut self, design: &Design) -> Box<Switch> {
+        let cases = self.cases.take().expect("Switch requires at least one case.");
+        let branches = Arc::new(Mutex::new(SwitchBranches {
+            cases: cases.into(),
+            default: self.default.take(),
+        }));
+
+        Box::new(Switch {
+            base: ActionBaseMeta {
+                tag: "orch::internal::switch".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    Switch::execute_impl(Arc::clone(&self.condition), Arc::clone(&branches)),
+                ),
+            },
+            condition: Arc::clone(&self.condition),
+            branches,
+        })
+    }
+}
+
+struct SwitchBranches {
+    cases: Vec<(usize, Box<dyn ActionTrait>)>,
+    default: Option<Box<dyn ActionTrait>>,
+}
+
+impl SwitchBranches {
+    /// Executes the case for `key`, or the default one. Returns `None` if there is neither.
+    fn try_execute(&mut self, key: usize) -> Option<ReusableBoxFutureResult> {
+        self.cases
+            .iter_mut()
+            .find(|(case_key, _)| *case_key == key)
+            .map(|(_, action)| action)
+            .or(self.default.as_mut())
+            .map(|action| action.try_execute())
+    }
+}
+
+/// An orchestration action that executes one of multiple case actions depending on the key returned by the
+/// user-provided condition object. Only the selected case is executed. If there is no case for the key, the default
+/// case is executed if set, otherwise the `Switch` completes without executing anything.
+pub struct Switch {
+    base: ActionBaseMeta,
+    condition: SwitchConditionFn,
+    branches: Arc<Mutex<SwitchBranches>>,
+}
+
+impl Switch {
+    async fn execute_impl(condition: SwitchConditionFn, branches: Arc<Mutex<SwitchBranches>>) -> ActionResult {
+        let key = condition();
+        let future = branches.lock().unwrap().try_execute(key);
+
+        match future {
+            Some(Ok(future)) => future.into_pin().await,
+            Some(Err(_)) => Err(ActionExecError::Internal),
+            None => {
+                debug!("Switch: No case for key {}", key);
+                Ok(())
+            },
+        }
+    }
+}
+
+impl ActionTrait for Switch {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base.reusable_future_pool.next(Switch::execute_impl(
+            Arc::clone(&self.condition),
+            Arc::clone(&self.branches),
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "Switch"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        let indent = " ".repeat(nest);
+        let branches = self.branches.lock().unwrap();
+
+        writeln!(f, "{}|-{} - {:?}", indent, self.name(), self.base)?;
+        branches.cases.iter().try_for_each(|(key, action)| {
+            writeln!(f, "{} |case {}", indent, key)?;
+            action.dbg_fmt(nest + 1, f)
+        })?;
+        if let Some(default) = branches.default.as_ref() {
+            writeln!(f, "{} |default", indent)?;
+            default.dbg_fmt(nest + 1, f)?;
+        }
+        Ok(())
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        let mut branches = self.branches.lock().unwrap();
+        branches
+            .cases
+            .iter_mut()
+            .for_each(|(_, action)| action.attach_trace_switch(switch));
+        if let Some(default) = branches.default.as_mut() {
+            default.attach_trace_switch(switch);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{
+        common::DesignConfig,
+        testing::{MockActionBuilder, OrchTestingPoller},
+    };
+    use ::core::sync::atomic::{AtomicUsize, Ordering};
+    use ::core::task::Poll;
+
+    struct Selected(Arc<AtomicUsize>);
+
+    impl SwitchCondition for Selected {
+        fn compute(&self) -> usize {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    fn design_with_condition(selected: &Arc<AtomicUsize>) -> Design {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design
+            .register_switch_condition("mode".into(), Selected(Arc::clone(selected)))
+            .unwrap();
+        design
+    }
+
+    #[test]
+    fn switch_executes_only_selected_case() {
+        let selected = Arc::new(AtomicUsize::new(2));
+        let design = design_with_condition(&selected);
+
+        let mut switch = SwitchBuilder::new("mode", &design)
+            .with_case(1, Box::new(MockActionBuilder::<()>::new().times(0).build()))
+            .with_case(
+                2,
+                Box::new(
+                    MockActionBuilder::<()>::new()
+                        .will_once_return(Err(ActionExecError::UserError(0xcafe_u64.into())))
+                        .build(),
+                ),
+            )
+            .with_default(Box::new(MockActionBuilder::<()>::new().times(0).build()))
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(switch.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(0xcafe_u64.into())))
+        );
+    }
+
+    #[test]
+    fn switch_executes_default_for_unknown_key() {
+        let selected = Arc::new(AtomicUsize::new(7));
+        let design = design_with_condition(&selected);
+
+        let mut switch = SwitchBuilder::new("mode", &design)
+            .with_case(1, Box::new(MockActionBuilder::<()>::new().times(0).build()))
+            .with_default(Box::new(
+                MockActionBuilder::<()>::new()
+                    .will_once_return(Err(ActionExecError::Internal))
+                    .build(),
+            ))
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(switch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    fn switch_without_matching_case_and_default_does_nothing() {
+        let selected = Arc::new(AtomicUsize::new(7));
+        let design = design_with_condition(&selected);
+
+        let mut switch = SwitchBuilder::new("mode", &design)
+            .with_case(1, Box::new(MockActionBuilder::<()>::new().times(0).build()))
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(switch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn switch_follows_condition_changes() {
+        let selected = Arc::new(AtomicUsize::new(0));
+        let design = design_with_condition(&selected);
+
+        let mut switch = SwitchBuilder::new("mode", &design)
+            .with_case(
+                0,
+                Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build()),
+            )
+            .with_case(
+                1,
+                Box::new(
+                    MockActionBuilder::<()>::new()
+                        .will_once_return(Err(ActionExecError::Internal))
+                        .build(),
+                ),
+            )
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(switch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+
+        selected.store(1, Ordering::Relaxed);
+        let mut poller = OrchTestingPoller::new(switch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Internal)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Switch: Case 1 is already added.")]
+    fn switch_with_duplicate_case_panics() {
+        let selected = Arc::new(AtomicUsize::new(0));
+        let design = design_with_condition(&selected);
+
+        SwitchBuilder::new("mode", &design)
+            .with_case(1, Box::new(MockActionBuilder::<()>::new().build()))
+            .with_case(1, Box::new(MockActionBuilder::<()>::new().build()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to create switch with name \"missing\"")]
+    fn switch_with_unknown_condition_panics() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let _ = SwitchBuilder::new("missing", &design);
+    }
+}
@@ -51,7 +51,7 @@ impl SyncBuilder {
             name, design
         );
 
-        Self::from_tag(&tag.unwrap(), design.config())
+        Self::from_tag(&tag.unwrap(), &design.action_config(name.into()))
     }
 }
 
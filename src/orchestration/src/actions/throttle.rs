@@ -0,0 +1,190 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult, TraceSwitch};
+use crate::api::design::Design;
+use ::core::time::Duration;
+use kyron::{
+    futures::{reusable_box_future::ReusableBoxFuturePool, sleep},
+    time::clock::{Clock, Instant},
+};
+use std::sync::{Arc, Mutex};
+
+///
+/// Start times of the child action reserved by the executions of a [`Throttle`], so executions started in a burst
+/// are spread by the minimal interval.
+///
+struct ThrottleState {
+    min_interval: Duration,
+    last: Option<Instant>, // Time of the last reservation
+    delay: Duration,       // Delay of the last reserved start after `last`
+}
+
+impl ThrottleState {
+    fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last: None,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Reserves the next start of the child action, returns time to wait for it.
+    fn reserve(&mut self) -> Duration {
+        let now = Clock::now();
+        let elapsed = self.last.map(|last| now.saturating_duration_since(last));
+        self.last = Some(now);
+
+        self.reserve_after(elapsed)
+    }
+
+    fn reserve_after(&mut self, elapsed: Option<Duration>) -> Duration {
+        let wait = elapsed.map_or(Duration::ZERO, |elapsed| {
+            (self.delay + self.min_interval).saturating_sub(elapsed)
+        });
+        self.delay = wait;
+        wait
+    }
+}
+
+///
+/// Builder for [`Throttle`] action.
+///
+pub struct ThrottleBuilder {
+    min_interval: Duration,
+    action: Box<dyn ActionTrait>,
+}
+
+impl ThrottleBuilder {
+    /// Creates a builder for action executing `action` at most once per `min_interval`.
+    pub fn new(min_interval: Duration, action: Box<dyn ActionTrait>) -> Self {
+        Self { min_interval, action }
+    }
+
+    /// Builds the `Throttle` action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the minimal interval is zero.
+    pub fn build(self, design: &Design) -> Box<Throttle> {
+        assert!(
+            !self.min_interval.is_zero(),
+            "Throttle requires minimal interval greater than zero."
+        );
+
+        let state = Arc::new(Mutex::new(ThrottleState::new(self.min_interval)));
+        let action = Arc::new(Mutex::new(self.action));
+
+        Box::new(Throttle {
+            base: ActionBaseMeta {
+                tag: "orch::internal::throttle".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    Throttle::execute_impl(Arc::clone(&state), Arc::clone(&action)),
+                ),
+            },
+            state,
+            action,
+        })
+    }
+}
+
+///
+/// Enforces a minimal interval between starts of the child action. If executed sooner, the `Throttle` sleeps the
+/// remainder of the interval before executing the child, e.g. so a pipeline triggered by bursty events does not
+/// exceed a configured rate.
+///
+pub struct Throttle {
+    base: ActionBaseMeta,
+    state: Arc<Mutex<ThrottleState>>,
+    action: Arc<Mutex<Box<dyn ActionTrait>>>,
+}
+
+impl Throttle {
+    async fn execute_impl(state: Arc<Mutex<ThrottleState>>, action: Arc<Mutex<Box<dyn ActionTrait>>>) -> ActionResult {
+        let wait = state.lock().unwrap().reserve();
+        if !wait.is_zero() {
+            sleep::sleep(wait).await;
+        }
+
+        let future = action.lock().unwrap().try_execute();
+        match future {
+            Ok(future) => future.into_pin().await,
+            Err(_) => Err(ActionExecError::Internal),
+        }
+    }
+}
+
+impl ActionTrait for Throttle {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base.reusable_future_pool.next(Throttle::execute_impl(
+            Arc::clone(&self.state),
+            Arc::clone(&self.action),
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "Throttle"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)?;
+        self.action.lock().unwrap().dbg_fmt(nest + 1, f)
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.action.lock().unwrap().attach_trace_switch(switch);
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{
+        common::DesignConfig,
+        testing::{MockActionBuilder, OrchTestingPoller},
+    };
+    use ::core::task::Poll;
+
+    #[test]
+    fn state_spreads_burst_by_min_interval() {
+        let mut state = ThrottleState::new(Duration::from_millis(10));
+
+        assert_eq!(state.reserve_after(None), Duration::ZERO);
+        assert_eq!(
+            state.reserve_after(Some(Duration::from_millis(4))),
+            Duration::from_millis(6)
+        );
+        assert_eq!(state.reserve_after(Some(Duration::ZERO)), Duration::from_millis(16));
+        assert_eq!(state.reserve_after(Some(Duration::from_millis(30))), Duration::ZERO);
+    }
+
+    #[test]
+    fn throttle_executes_first_execution_immediately() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        let mut throttle = ThrottleBuilder::new(Duration::from_secs(1), action).build(&design);
+        let mut poller = OrchTestingPoller::new(throttle.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    #[should_panic(expected = "Throttle requires minimal interval greater than zero.")]
+    fn throttle_panics_for_zero_interval() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let action = Box::new(MockActionBuilder::<()>::new().times(0).build());
+        let _ = ThrottleBuilder::new(Duration::ZERO, action).build(&design);
+    }
+}
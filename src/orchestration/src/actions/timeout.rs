@@ -0,0 +1,219 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{
+    ActionBaseMeta, ActionExecError, ActionMeta, ActionResult, ActionTrait, ExecutionStrategy, ReusableBoxFutureResult,
+    TraceSwitch,
+};
+use crate::{api::design::Design, common::DesignConfig};
+use ::core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::Poll,
+    time::Duration,
+};
+use kyron::{
+    futures::{reusable_box_future::ReusableBoxFuturePool, sleep},
+    time::clock::Clock,
+};
+
+///
+/// Builder for [`Timeout`] action.
+///
+pub struct TimeoutBuilder {
+    duration: Duration,
+    action: Box<dyn ActionTrait>,
+}
+
+impl TimeoutBuilder {
+    /// Creates a builder for action failing with `ActionExecError::Timeout` if `action` does not complete within `duration`.
+    pub fn new(duration: Duration, action: Box<dyn ActionTrait>) -> Self {
+        Self { duration, action }
+    }
+
+    /// Builds the `Timeout` action.
+    pub fn build(self, design: &Design) -> Box<Timeout> {
+        self.build_with_config(&design.config)
+    }
+
+    /// Builds the `Timeout` action for an invoke wrapped by the deployment, see `Deployment::set_invoke_timeout`.
+    pub(crate) fn build_with_config(self, config: &DesignConfig) -> Box<Timeout> {
+        Box::new(Timeout {
+            base: ActionBaseMeta {
+                tag: "orch::internal::timeout".into(),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    config.max_concurrent_action_executions,
+                    Timeout::execute_impl(ActionMeta::Empty, self.duration),
+                ),
+            },
+            duration: self.duration,
+            action: self.action,
+        })
+    }
+}
+
+///
+/// Monitors execution time of the child action. If the child does not complete within configured duration,
+/// `Timeout` fails with `ActionExecError::Timeout`, which can be handled by `Catch` with `ErrorFilter::Timeouts`.
+/// The child is always spawned as a separate task, so it cannot block the monitoring by a synchronous action (like
+/// `Sequence` with `Invoke`). After a timeout the child task is aborted, so the next execution can reuse its future.
+///
+pub struct Timeout {
+    base: ActionBaseMeta,
+    duration: Duration,
+    action: Box<dyn ActionTrait>,
+}
+
+impl Timeout {
+    async fn execute_impl(mut action: ActionMeta, duration: Duration) -> ActionResult {
+        let start = Clock::now();
+        action.start(ExecutionStrategy::Spawn);
+
+        // Deadline is created lazily, only if the child did not complete on the first poll
+        let mut deadline = pin!(None);
+
+        poll_fn(|cx| {
+            match action.poll_started(cx) {
+                Some(Poll::Ready(result)) => return Poll::Ready(result),
+                Some(Poll::Pending) => {},
+                None => return Poll::Ready(Err(ActionExecError::Internal)),
+            }
+
+            let elapsed = Clock::now().saturating_duration_since(start);
+            if elapsed >= duration {
+                action.abort();
+                return Poll::Ready(Err(ActionExecError::Timeout));
+            }
+
+            if deadline.is_none() {
+                deadline.set(Some(sleep::sleep(duration - elapsed)));
+            }
+
+            let expired = deadline
+                .as_mut()
+                .as_pin_mut()
+                .is_some_and(|timer| timer.poll(cx).is_ready());
+            if expired {
+                action.abort();
+                Poll::Ready(Err(ActionExecError::Timeout))
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+impl ActionTrait for Timeout {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        let action = ActionMeta::new(self.action.try_execute()?);
+
+        self.base
+            .reusable_future_pool
+            .next(Timeout::execute_impl(action, self.duration))
+    }
+
+    fn name(&self) -> &'static str {
+        "Timeout"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(
+            f,
+            "{}|-{} ({:?}) - {:?}",
+            " ".repeat(nest),
+            self.name(),
+            self.duration,
+            self.base
+        )?;
+        self.action.dbg_fmt(nest + 1, f)
+    }
+
+    fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+        self.action.attach_trace_switch(switch);
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(miri))]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::catch::{CatchBuilder, ErrorFilter, HandlerErrors},
+        testing::{MockActionBuilder, OrchTestingPoller, TestAsyncAction},
+    };
+    use ::core::future;
+    use kyron::testing::mock;
+    use kyron_testing_macros::ensure_clear_mock_runtime;
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn timeout_fails_when_child_does_not_complete() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let child = Box::new(TestAsyncAction::new(future::pending));
+
+        let mut timeout = TimeoutBuilder::new(Duration::ZERO, child).build(&design);
+        assert_eq!(timeout.name(), "Timeout");
+
+        let mut poller = OrchTestingPoller::new(timeout.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Timeout)));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn timeout_passes_result_of_child_completing_in_time() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let child = Box::new(MockActionBuilder::<()>::new().will_once_return(Ok(())).build());
+
+        let mut timeout = TimeoutBuilder::new(Duration::from_secs(3600), child).build(&design);
+        let mut poller = OrchTestingPoller::new(timeout.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Pending);
+
+        mock::runtime::step();
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn timeout_aborts_child_and_executes_again() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let child = Box::new(TestAsyncAction::new(future::pending));
+
+        let mut timeout = TimeoutBuilder::new(Duration::ZERO, child).build(&design);
+        let mut poller = OrchTestingPoller::new(timeout.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Timeout)));
+
+        // The timed out child was aborted, so it does not stay in the runtime
+        mock::runtime::step();
+        assert_eq!(mock::runtime::remaining_tasks(), 0);
+
+        let mut poller = OrchTestingPoller::new(timeout.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Timeout)));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn timeout_is_handled_by_catch() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let child = Box::new(TestAsyncAction::new(future::pending));
+        let timeout = TimeoutBuilder::new(Duration::ZERO, child).build(&design);
+
+        let mut catch = CatchBuilder::new(ErrorFilter::Timeouts.into(), timeout)
+            .catch_recoverable(|e| e == HandlerErrors::Timeout)
+            .build(&design);
+
+        let mut poller = OrchTestingPoller::new(catch.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+    }
+}
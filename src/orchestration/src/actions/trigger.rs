@@ -53,7 +53,7 @@ impl TriggerBuilder {
             name, design
         );
 
-        Self::from_tag(&tag.unwrap(), design.config())
+        Self::from_tag(&tag.unwrap(), &design.action_config(name.into()))
     }
 }
 
@@ -0,0 +1,261 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use super::action::{ActionBaseMeta, ActionExecError, ActionResult, ActionTrait, ReusableBoxFutureResult};
+use crate::{api::design::Design, common::tag::Tag};
+use ::core::ffi::c_void;
+use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
+use kyron_foundation::prelude::*;
+use std::{
+    os::{fd::RawFd, unix::net::UnixDatagram},
+    sync::{Arc, Mutex},
+};
+
+///
+/// Destination of the watchdog kicks, e.g. a supervisor process or a hardware watchdog.
+///
+pub trait WatchdogSink: Send + 'static {
+    /// Reports progress to the supervisor.
+    fn kick(&mut self) -> Result<(), CommonErrors>;
+}
+
+/// Kicks by calling a user provided function, e.g. one exposed over FFI by the platform supervision.
+pub struct CallbackSink {
+    callback: extern "C" fn(),
+}
+
+impl CallbackSink {
+    pub fn new(callback: extern "C" fn()) -> Self {
+        Self { callback }
+    }
+}
+
+impl WatchdogSink for CallbackSink {
+    fn kick(&mut self) -> Result<(), CommonErrors> {
+        (self.callback)();
+        Ok(())
+    }
+}
+
+/// Kicks by writing a single byte into a file descriptor, e.g. an opened `/dev/watchdog` or a pipe to the supervisor.
+/// The descriptor stays owned by the user.
+pub struct FdSink {
+    fd: RawFd,
+}
+
+impl FdSink {
+    pub fn new(fd: RawFd) -> Self {
+        Self { fd }
+    }
+}
+
+impl WatchdogSink for FdSink {
+    fn kick(&mut self) -> Result<(), CommonErrors> {
+        // Any byte other than magic close 'V' keeps the Linux watchdog device alive
+        let byte = [0_u8];
+        // SAFETY: buffer is valid for its length, invalid descriptor is reported by `write` as error
+        let written = unsafe { libc::write(self.fd, byte.as_ptr() as *const c_void, byte.len()) };
+        if written == 1 {
+            Ok(())
+        } else {
+            Err(CommonErrors::GenericError)
+        }
+    }
+}
+
+/// Kicks systemd service watchdog (`WATCHDOG=1` over `sd_notify` protocol).
+pub struct SdNotifySink {
+    socket: UnixDatagram,
+}
+
+impl SdNotifySink {
+    ///
+    /// Connects to the socket given by `NOTIFY_SOCKET` environment variable.
+    ///
+    /// # Errors
+    ///    `CommonErrors::NotFound` - The process is not run by systemd with notify support.
+    ///    `CommonErrors::GenericError` - Connecting to the socket failed.
+    ///
+    pub fn from_env() -> Result<Self, CommonErrors> {
+        let path = std::env::var("NOTIFY_SOCKET").map_err(|_| CommonErrors::NotFound)?;
+        let socket = UnixDatagram::unbound().map_err(|_| CommonErrors::GenericError)?;
+
+        match path.strip_prefix('@') {
+            Some(name) => Self::connect_abstract(&socket, name)?,
+            None => socket.connect(&path).map_err(|_| CommonErrors::GenericError)?,
+        }
+
+        Ok(Self { socket })
+    }
+
+    #[cfg(target_os = "linux")]
+    fn connect_abstract(socket: &UnixDatagram, name: &str) -> Result<(), CommonErrors> {
+        use std::os::{linux::net::SocketAddrExt, unix::net::SocketAddr};
+
+        let addr = SocketAddr::from_abstract_name(name.as_bytes()).map_err(|_| CommonErrors::GenericError)?;
+        socket.connect_addr(&addr).map_err(|_| CommonErrors::GenericError)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn connect_abstract(_socket: &UnixDatagram, _name: &str) -> Result<(), CommonErrors> {
+        Err(CommonErrors::NotFound)
+    }
+}
+
+impl WatchdogSink for SdNotifySink {
+    fn kick(&mut self) -> Result<(), CommonErrors> {
+        self.socket
+            .send(b"WATCHDOG=1")
+            .map(|_| ())
+            .map_err(|_| CommonErrors::GenericError)
+    }
+}
+
+///
+/// Builder for [`WatchdogKick`] action.
+///
+pub struct WatchdogKickBuilder {
+    sink: Arc<Mutex<dyn WatchdogSink>>,
+}
+
+impl WatchdogKickBuilder {
+    /// Creates the builder with the sink that receives kicks.
+    pub fn new<S: WatchdogSink>(sink: S) -> Self {
+        Self {
+            sink: Arc::new(Mutex::new(sink)),
+        }
+    }
+
+    /// Builds the `WatchdogKick` action.
+    pub fn build(self, design: &Design) -> Box<WatchdogKick> {
+        const DEFAULT_TAG: &str = "orch::internal::watchdog_kick";
+
+        Box::new(WatchdogKick {
+            base: ActionBaseMeta {
+                tag: Tag::from_str_static(DEFAULT_TAG),
+                reusable_future_pool: ReusableBoxFuturePool::for_value(
+                    design.config.max_concurrent_action_executions,
+                    WatchdogKick::execute_impl(Arc::clone(&self.sink)),
+                ),
+            },
+            sink: self.sink,
+        })
+    }
+}
+
+///
+/// Kicks the watchdog sink each time it is executed. Placed at chosen points of the flow, it ties
+/// supervision to actual progress of the program instead of liveness of its threads.
+///
+/// Failed kick is reported as `ActionExecError::Internal`.
+///
+pub struct WatchdogKick {
+    base: ActionBaseMeta,
+    sink: Arc<Mutex<dyn WatchdogSink>>,
+}
+
+impl WatchdogKick {
+    async fn execute_impl(sink: Arc<Mutex<dyn WatchdogSink>>) -> ActionResult {
+        sink.lock().unwrap().kick().map_err(|e| {
+            error!("WatchdogKick: Failed to kick the watchdog: {:?}", e);
+            ActionExecError::Internal
+        })
+    }
+}
+
+impl ActionTrait for WatchdogKick {
+    fn try_execute(&mut self) -> ReusableBoxFutureResult {
+        self.base
+            .reusable_future_pool
+            .next(WatchdogKick::execute_impl(Arc::clone(&self.sink)))
+    }
+
+    fn name(&self) -> &'static str {
+        "WatchdogKick"
+    }
+
+    fn dbg_fmt(&self, nest: usize, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "{}|-{} - {:?}", " ".repeat(nest), self.name(), self.base)
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{common::DesignConfig, testing::OrchTestingPoller};
+    use ::core::task::Poll;
+    use std::os::fd::AsRawFd;
+
+    struct CountingSink {
+        kicks: Arc<Mutex<usize>>,
+        fail: bool,
+    }
+
+    impl WatchdogSink for CountingSink {
+        fn kick(&mut self) -> Result<(), CommonErrors> {
+            if self.fail {
+                return Err(CommonErrors::GenericError);
+            }
+
+            *self.kicks.lock().unwrap() += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn kicks_sink_on_each_execution() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let kicks = Arc::new(Mutex::new(0));
+        let mut action = WatchdogKickBuilder::new(CountingSink {
+            kicks: Arc::clone(&kicks),
+            fail: false,
+        })
+        .build(&design);
+
+        assert_eq!(*kicks.lock().unwrap(), 0);
+        for _ in 0..3 {
+            assert_eq!(
+                OrchTestingPoller::new(action.try_execute().unwrap()).poll(),
+                Poll::Ready(Ok(()))
+            );
+        }
+        assert_eq!(*kicks.lock().unwrap(), 3);
+    }
+
+    #[test]
+    fn failed_kick_is_reported() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let mut action = WatchdogKickBuilder::new(CountingSink {
+            kicks: Arc::new(Mutex::new(0)),
+            fail: true,
+        })
+        .build(&design);
+
+        assert_eq!(
+            OrchTestingPoller::new(action.try_execute().unwrap()).poll(),
+            Poll::Ready(Err(ActionExecError::Internal))
+        );
+    }
+
+    #[test]
+    fn fd_sink_writes_into_descriptor() {
+        let (sender, receiver) = UnixDatagram::pair().unwrap();
+        let mut sink = FdSink::new(sender.as_raw_fd());
+
+        assert!(sink.kick().is_ok());
+        let mut buf = [0xff_u8; 4];
+        assert_eq!(receiver.recv(&mut buf).unwrap(), 1);
+        assert_eq!(buf[0], 0);
+    }
+}
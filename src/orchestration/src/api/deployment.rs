@@ -11,26 +11,43 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+use std::cell::Cell;
 use std::rc::Rc;
 
 use crate::{
+    actions::{gate::GateControl, retry::RetryBackoff},
     api::{
         design::{Design, DesignTag},
-        OrchestrationApi, _DesignTag,
+        ComposedProgram, OrchestrationApi, _DesignTag,
     },
     common::tag::Tag,
     program::ProgramBuilder,
 };
+use ::core::time::Duration;
 use kyron::core::types::UniqueWorkerId;
-use kyron_foundation::prelude::CommonErrors;
+use kyron_foundation::{containers::growable_vec::GrowableVec, prelude::CommonErrors};
+use std::sync::Arc;
 
 pub struct Deployment<'a> {
     api: &'a mut OrchestrationApi<_DesignTag>,
+    _in_use: DeploymentGuard,
+}
+
+/// Marks the deployment as in use until dropped. Kept apart from `Deployment` so that dropping it does not extend the
+/// borrow of the API.
+struct DeploymentGuard(Rc<Cell<bool>>);
+
+impl Drop for DeploymentGuard {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
 }
 
 impl Deployment<'_> {
     pub fn new(api: &mut OrchestrationApi<_DesignTag>) -> Deployment<'_> {
-        Deployment { api }
+        api.deployment_in_use.set(true);
+        let guard = DeploymentGuard(Rc::clone(&api.deployment_in_use));
+        Deployment { api, _in_use: guard }
     }
 
     /// Maps a system events to user events. This means that the specified user events will be treated as global events across all processes.
@@ -65,6 +82,31 @@ impl Deployment<'_> {
         ret
     }
 
+    /// Binds each event of the group registered with [`Design::register_event_group`] as a separate local event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if no design has registered a group with given prefix.
+    pub fn bind_group_as_local(&mut self, prefix: &str) -> Result<(), CommonErrors> {
+        let prefix: Tag = prefix.into();
+
+        // Same group may be registered in multiple designs, bind each event only once
+        let mut events = GrowableVec::<Tag>::default();
+        for d in self.api.designs.iter() {
+            for tag in d.event_group(prefix) {
+                if !events.iter().any(|e| *e == tag) {
+                    events.push(tag);
+                }
+            }
+        }
+
+        if events.is_empty() {
+            return Err(CommonErrors::NotFound);
+        }
+
+        events.iter().try_for_each(|tag| self.bind_events_as_local(&[*tag]))
+    }
+
     /// Binds user events to a timer with given params
     pub fn bind_events_as_timer(
         &mut self,
@@ -108,6 +150,89 @@ impl Deployment<'_> {
         ret
     }
 
+    /// Fails the invoke action with `ActionExecError::Timeout` when it does not complete within `duration`, across all
+    /// designs wherever that invoke action is registered. With a retry policy the timeout applies to each attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if no design has registered an invoke action with given tag and
+    /// `CommonErrors::AlreadyDone` if a timeout is already set for it.
+    pub fn set_invoke_timeout(&mut self, tag: Tag, duration: Duration) -> Result<(), CommonErrors> {
+        let mut ret = Err(CommonErrors::NotFound);
+
+        for d in &mut self.api.designs {
+            // This logic allows to report NotFound only if no design has the invoke.
+            ret = d.db.set_invoke_timeout(tag, duration).or_else(
+                |e| {
+                    if e == CommonErrors::NotFound {
+                        ret
+                    } else {
+                        Err(e)
+                    }
+                },
+            )
+        }
+
+        ret
+    }
+
+    /// Executes the invoke action again when it fails, up to `attempts` times with `backoff` between the attempts,
+    /// across all designs wherever that invoke action is registered, see `RetryBuilder`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if no design has registered an invoke action with given tag and
+    /// `CommonErrors::AlreadyDone` if a retry policy is already set for it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if number of attempts is zero.
+    pub fn set_invoke_retry(&mut self, tag: Tag, attempts: u32, backoff: RetryBackoff) -> Result<(), CommonErrors> {
+        assert!(attempts > 0, "Retry: Number of attempts shall be greater than zero.");
+        let mut ret = Err(CommonErrors::NotFound);
+
+        for d in &mut self.api.designs {
+            // This logic allows to report NotFound only if no design has the invoke.
+            ret = d.db.set_invoke_retry(tag, attempts, backoff).or_else(|e| {
+                if e == CommonErrors::NotFound {
+                    ret
+                } else {
+                    Err(e)
+                }
+            })
+        }
+
+        ret
+    }
+
+    /// Sets the handler called with the tag and measured duration of an action that exceeded its deadline, configured
+    /// with `DesignConfig::deadline` or `DeadlineBuilder`. The action keeps running. Without the handler violations are
+    /// only logged.
+    pub fn set_deadline_handler<H>(&mut self, handler: H)
+    where
+        H: Fn(Tag, Duration) + Send + Sync + 'static,
+    {
+        let handler = Arc::new(handler);
+        for d in &mut self.api.designs {
+            d.db.set_deadline_handler(Arc::clone(&handler));
+        }
+    }
+
+    /// Returns a handle opening and closing the gate registered under `tag` with [`Design::register_gate`] across all
+    /// designs, so parts of programs can be enabled or disabled at runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if no design has registered a gate with given tag.
+    pub fn gate_control(&self, tag: Tag) -> Result<GateControl, CommonErrors> {
+        let flags: Vec<_> = self.api.designs.iter().filter_map(|d| d.gate(tag)).collect();
+        if flags.is_empty() {
+            return Err(CommonErrors::NotFound);
+        }
+
+        Ok(GateControl::new(flags))
+    }
+
     /// Binds a shutdown event as a global event.
     pub fn bind_shutdown_event_as_global(&mut self, system_event: &str, event: Tag) -> Result<(), CommonErrors> {
         let creator = self.api.events.specify_global_event(system_event, &[event])?;
@@ -144,6 +269,51 @@ impl Deployment<'_> {
             Err(CommonErrors::NotFound)
         }
     }
+
+    /// Adds a program assembled from fragments exported by designs with [`Design::export_fragment`]. Fragments are
+    /// executed in sequence, in the given order, e.g. acquisition steps of one design followed by processing steps
+    /// of another one. Fragments are created when the program manager is created.
+    ///
+    /// # Returns
+    /// `Ok(())` if the program was added successfully
+    /// `Err(CommonErrors::NoData)` if no fragments were given
+    /// `Err(CommonErrors::NotFound)` if a design or a fragment does not exist
+    /// `Err(CommonErrors::AlreadyDone)` if a composed program with the same name was already added
+    ///
+    pub fn add_composed_program(
+        &mut self,
+        name: &'static str,
+        fragments: &[(DesignTag, Tag)],
+    ) -> Result<(), CommonErrors> {
+        if fragments.is_empty() {
+            return Err(CommonErrors::NoData);
+        }
+
+        let all_exported = fragments.iter().all(|(design_tag, fragment)| {
+            self.api
+                .designs
+                .iter()
+                .any(|d| d.id() == *design_tag && d.has_fragment(*fragment))
+        });
+        if !all_exported {
+            return Err(CommonErrors::NotFound);
+        }
+
+        if self.api.composed_programs.iter().any(|p| p.name == name) {
+            return Err(CommonErrors::AlreadyDone);
+        }
+
+        let mut composed_fragments = GrowableVec::new(fragments.len());
+        for fragment in fragments {
+            composed_fragments.push(*fragment);
+        }
+
+        self.api.composed_programs.push(ComposedProgram {
+            name,
+            fragments: composed_fragments,
+        });
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -166,6 +336,10 @@ mod tests {
             designs: kyron_foundation::containers::growable_vec::GrowableVec::default(),
             events: crate::events::events_provider::EventsProvider::default(),
             shutdown_events: GrowableVec::default(),
+            composed_programs: GrowableVec::default(),
+            startup_profile: Default::default(),
+            deployment_start: None,
+            deployment_in_use: Default::default(),
             _p: PhantomData,
         };
         api.designs.push(design);
@@ -183,6 +357,10 @@ mod tests {
             designs: kyron_foundation::containers::growable_vec::GrowableVec::default(),
             events: crate::events::events_provider::EventsProvider::default(),
             shutdown_events: GrowableVec::default(),
+            composed_programs: GrowableVec::default(),
+            startup_profile: Default::default(),
+            deployment_start: None,
+            deployment_in_use: Default::default(),
             _p: PhantomData,
         };
         api.designs.push(design);
@@ -255,4 +433,140 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn bind_group_as_local_binds_all_group_events() {
+        let mut design = crate::api::design::Design::new(Tag::from_str_static("test_design"), DesignConfig::default());
+        design.register_event_group("camera", &["front", "rear"]).unwrap();
+        assert!(design.get_orchestration_tag("camera.front".into()).is_ok());
+        assert!(design.get_orchestration_tag("camera.rear".into()).is_ok());
+
+        let mut api = OrchestrationApi {
+            designs: GrowableVec::default(),
+            events: crate::events::events_provider::EventsProvider::default(),
+            shutdown_events: GrowableVec::default(),
+            composed_programs: GrowableVec::default(),
+            startup_profile: Default::default(),
+            deployment_start: None,
+            deployment_in_use: Default::default(),
+            _p: PhantomData,
+        };
+        api.designs.push(design);
+        let mut api = api.design_done();
+
+        let mut deployment = Deployment::new(&mut api);
+        assert_eq!(deployment.bind_group_as_local("camera"), Ok(()));
+        assert_eq!(deployment.bind_group_as_local("lidar"), Err(CommonErrors::NotFound));
+    }
+
+    #[test]
+    fn invoke_policies_are_set_only_for_registered_invokes() {
+        fn step() -> crate::actions::invoke::InvokeResult {
+            Ok(())
+        }
+
+        let mut api = setup_api_multiple_design();
+        let design = api.designs.iter().next().unwrap();
+        design.register_invoke_fn("step".into(), step).unwrap();
+        let mut deployment = Deployment::new(&mut api);
+
+        assert_eq!(
+            deployment.set_invoke_timeout("step".into(), Duration::from_millis(10)),
+            Ok(())
+        );
+        assert_eq!(
+            deployment.set_invoke_timeout("step".into(), Duration::from_millis(20)),
+            Err(CommonErrors::AlreadyDone)
+        );
+        assert_eq!(
+            deployment.set_invoke_retry("step".into(), 3, RetryBackoff::None),
+            Ok(())
+        );
+        assert_eq!(
+            deployment.set_invoke_retry("SomeUserEvent".into(), 3, RetryBackoff::None),
+            Err(CommonErrors::NotFound)
+        );
+        assert_eq!(
+            deployment.set_invoke_timeout("missing".into(), Duration::from_millis(10)),
+            Err(CommonErrors::NotFound)
+        );
+    }
+
+    #[test]
+    fn gate_control_controls_gate_in_all_designs() {
+        let mut api = OrchestrationApi {
+            designs: GrowableVec::default(),
+            events: crate::events::events_provider::EventsProvider::default(),
+            shutdown_events: GrowableVec::default(),
+            composed_programs: GrowableVec::default(),
+            startup_profile: Default::default(),
+            deployment_start: None,
+            deployment_in_use: Default::default(),
+            _p: PhantomData,
+        };
+        for name in ["design1", "design2"] {
+            let mut design = crate::api::design::Design::new(Tag::from_str_static(name), DesignConfig::default());
+            design.register_gate("diagnostics".into(), name == "design1").unwrap();
+            api.designs.push(design);
+        }
+        let mut api = api.design_done();
+
+        let deployment = Deployment::new(&mut api);
+        let control = deployment.gate_control("diagnostics".into()).unwrap();
+        assert!(!control.is_open());
+        control.open();
+        assert!(control.is_open());
+        assert!(deployment.gate_control("logging".into()).is_err());
+    }
+
+    #[test]
+    fn add_composed_program_builds_program_from_fragments_of_multiple_designs() {
+        use crate::actions::invoke::Invoke;
+
+        fn step() -> crate::actions::invoke::InvokeResult {
+            Ok(())
+        }
+
+        let mut acquisition = crate::api::design::Design::new("acquisition".into(), DesignConfig::default());
+        acquisition.register_invoke_fn("read_sensor".into(), step).unwrap();
+        acquisition
+            .export_fragment("acquire".into(), |design| {
+                Ok(Invoke::from_design("read_sensor", design))
+            })
+            .unwrap();
+
+        let mut processing = crate::api::design::Design::new("processing".into(), DesignConfig::default());
+        processing.register_invoke_fn("detect".into(), step).unwrap();
+        processing
+            .export_fragment("process".into(), |design| Ok(Invoke::from_design("detect", design)))
+            .unwrap();
+
+        let mut api = crate::api::Orchestration::new()
+            .add_design(acquisition)
+            .add_design(processing)
+            .design_done();
+
+        let mut deployment = api.get_deployment_mut();
+        assert_eq!(
+            deployment.add_composed_program("pipeline", &[("acquisition".into(), "process".into())]),
+            Err(CommonErrors::NotFound)
+        );
+        assert_eq!(
+            deployment.add_composed_program("pipeline", &[]),
+            Err(CommonErrors::NoData)
+        );
+
+        let fragments: [(DesignTag, Tag); 2] = [
+            ("acquisition".into(), "acquire".into()),
+            ("processing".into(), "process".into()),
+        ];
+        assert_eq!(deployment.add_composed_program("pipeline", &fragments), Ok(()));
+        assert_eq!(
+            deployment.add_composed_program("pipeline", &fragments),
+            Err(CommonErrors::AlreadyDone)
+        );
+
+        let mut manager = api.into_program_manager().unwrap();
+        assert!(manager.get_program("pipeline").is_some());
+    }
 }
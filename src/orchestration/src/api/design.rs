@@ -12,21 +12,37 @@
 // *******************************************************************************
 
 use crate::{
-    actions::{ifelse::IfElseCondition, invoke},
-    api::ShutdownEvent,
-    common::{orch_tag::OrchestrationTag, tag::Tag, DesignConfig},
+    actions::{
+        action::{ActionTrait, UserErrValue},
+        budget::TokenBucket,
+        ifelse::IfElseCondition,
+        invoke,
+        switch::SwitchCondition,
+    },
+    api::{
+        startup_profile::{StartupPhase, StartupProfile},
+        ShutdownEvent,
+    },
+    common::{orch_tag::OrchestrationTag, tag::Tag, DesignConfig, DesignConfigOverrides},
+    core::topic::{Publisher, Subscriber, Topic},
     prelude::InvokeResult,
     program::{Program, ProgramBuilder},
     program_database::ProgramDatabase,
 };
 use ::core::fmt::Debug;
 use ::core::future::Future;
+use ::core::sync::atomic::AtomicBool;
+use ::core::time::Duration;
 use kyron_foundation::{containers::growable_vec::GrowableVec, prelude::CommonErrors};
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 pub type ProgramTag = Tag;
 pub type DesignTag = Tag;
 
+/// Function creating a program fragment exported by a design, see [`Design::export_fragment`].
+pub type FragmentFn = Box<dyn Fn(&Design) -> Result<Box<dyn ActionTrait>, CommonErrors>>;
+
 ///
 /// Design is a container for Application developer to register all it's components (functions, events, conditions, etc.)
 /// and orchestrations (programs) in `config-by-code` approach.  If `config-by-file` is used, user does not need to use
@@ -37,6 +53,18 @@ pub struct Design {
     pub(crate) config: DesignConfig,
     pub(crate) db: ProgramDatabase,
     programs: GrowableVec<ProgramData>,
+    event_groups: GrowableVec<EventGroup>,
+    rate_limiters: GrowableVec<(Tag, Arc<Mutex<TokenBucket>>)>,
+    gates: GrowableVec<(Tag, Arc<AtomicBool>)>,
+    fragments: GrowableVec<(Tag, FragmentFn)>,
+    topics: GrowableVec<(Tag, Topic)>,
+    action_configs: GrowableVec<(Tag, DesignConfigOverrides)>,
+}
+
+/// Events registered together under common prefix, see [`Design::register_event_group`].
+struct EventGroup {
+    prefix: Tag,
+    events: GrowableVec<Tag>,
 }
 
 impl Debug for Design {
@@ -54,6 +82,12 @@ impl Design {
             config,
             db: ProgramDatabase::new(config),
             programs: GrowableVec::new(DEFAULT_PROGRAMS_CNT),
+            event_groups: GrowableVec::default(),
+            rate_limiters: GrowableVec::default(),
+            gates: GrowableVec::default(),
+            fragments: GrowableVec::default(),
+            topics: GrowableVec::default(),
+            action_configs: GrowableVec::default(),
         }
     }
 
@@ -67,6 +101,30 @@ impl Design {
         &self.config
     }
 
+    /// Overrides configuration parameters of the action registered under `tag`. Values not set in `overrides` are
+    /// inherited from the design configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::AlreadyDone` if overrides for the same tag were already set.
+    pub fn override_action_config(&mut self, tag: Tag, overrides: DesignConfigOverrides) -> Result<(), CommonErrors> {
+        if self.action_configs.iter().any(|(registered, _)| *registered == tag) {
+            return Err(CommonErrors::AlreadyDone);
+        }
+
+        self.action_configs.push((tag, overrides));
+        Ok(())
+    }
+
+    /// Returns the resolved configuration of the action registered under `tag`, that is the design configuration with
+    /// the overrides set by [`Design::override_action_config`] applied.
+    pub fn action_config(&self, tag: Tag) -> DesignConfig {
+        self.action_configs
+            .iter()
+            .find(|(registered, _)| *registered == tag)
+            .map_or(self.config, |(_, overrides)| self.config.with_overrides(overrides))
+    }
+
     /// Registers a function as an invoke action.
     pub fn register_invoke_fn(
         &self,
@@ -85,6 +143,41 @@ impl Design {
         self.db.register_invoke_async(tag, action)
     }
 
+    /// Registers a trait object as an async invoke action, e.g. a component selected at runtime.
+    pub fn register_invoke_dyn(
+        &self,
+        tag: Tag,
+        action: Box<dyn invoke::AsyncInvoke>,
+    ) -> Result<OrchestrationTag, CommonErrors> {
+        self.db.register_invoke_dyn(tag, action)
+    }
+
+    /// Registers a function producing a value as an invoke action, see [`ProgramDatabase::register_invoke_producer`].
+    pub fn register_invoke_producer<T, P>(&self, tag: Tag, producer: P) -> Result<OrchestrationTag, CommonErrors>
+    where
+        T: Send + 'static,
+        P: Fn() -> Result<T, UserErrValue> + Send + Sync + 'static,
+    {
+        self.db.register_invoke_producer(tag, producer)
+    }
+
+    /// Registers a function consuming the value of the producer under `producer_tag` as an invoke action, see
+    /// [`ProgramDatabase::register_invoke_consumer`].
+    pub fn register_invoke_consumer<T, C>(
+        &self,
+        tag: Tag,
+        producer_tag: Tag,
+        consumer: C,
+        no_value_err: UserErrValue,
+    ) -> Result<OrchestrationTag, CommonErrors>
+    where
+        T: Send + 'static,
+        C: Fn(T) -> InvokeResult + Send + Sync + 'static,
+    {
+        self.db
+            .register_invoke_consumer(tag, producer_tag, consumer, no_value_err)
+    }
+
     /// Registers a method on an object as an invoke action.
     pub fn register_invoke_method<T: 'static + Send>(
         &self,
@@ -115,6 +208,173 @@ impl Design {
         self.db.register_event(tag)
     }
 
+    /// Registers a group of events named `<prefix>.<name>` for each of `names`. The whole group can be bound in the
+    /// deployment at once with [`crate::api::deployment::Deployment::bind_group_as_local`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::AlreadyDone` if a group with the same prefix was already registered, or any error of
+    /// [`Design::register_event`].
+    pub fn register_event_group(&mut self, prefix: &str, names: &[&str]) -> Result<(), CommonErrors> {
+        let prefix_tag: Tag = prefix.into();
+        if self.event_groups.iter().any(|group| group.prefix == prefix_tag) {
+            return Err(CommonErrors::AlreadyDone);
+        }
+
+        let mut events = GrowableVec::new(names.len());
+        for name in names {
+            let tag: Tag = format!("{}.{}", prefix, name).into();
+            self.register_event(tag)?;
+            events.push(tag);
+        }
+
+        self.event_groups.push(EventGroup {
+            prefix: prefix_tag,
+            events,
+        });
+        Ok(())
+    }
+
+    /// Returns events of the group registered with given prefix, empty if there is no such group.
+    pub(crate) fn event_group(&self, prefix: Tag) -> impl Iterator<Item = Tag> + '_ {
+        self.event_groups
+            .iter()
+            .filter(move |group| group.prefix == prefix)
+            .flat_map(|group| group.events.iter().copied())
+    }
+
+    /// Registers a rate limiter shared by all programs of this design. It holds up to `capacity` tokens and gets one
+    /// token back every `refill_period`. Programs take tokens with [`crate::actions::budget::AcquireBudget`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::AlreadyDone` if a rate limiter with the same tag was already registered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` or `refill_period` is zero.
+    pub fn register_rate_limiter(
+        &mut self,
+        tag: Tag,
+        capacity: u32,
+        refill_period: Duration,
+    ) -> Result<(), CommonErrors> {
+        if self.rate_limiters.iter().any(|(registered, _)| *registered == tag) {
+            return Err(CommonErrors::AlreadyDone);
+        }
+
+        self.rate_limiters
+            .push((tag, Arc::new(Mutex::new(TokenBucket::new(capacity, refill_period)))));
+        Ok(())
+    }
+
+    /// Returns the rate limiter registered under `tag`.
+    pub(crate) fn rate_limiter(&self, tag: Tag) -> Option<Arc<Mutex<TokenBucket>>> {
+        self.rate_limiters
+            .iter()
+            .find(|(registered, _)| *registered == tag)
+            .map(|(_, bucket)| Arc::clone(bucket))
+    }
+
+    /// Registers a gate shared by all programs of this design, initially `open` or closed. Gates are opened and closed
+    /// at runtime with [`crate::api::deployment::Deployment::gate_control`] and checked by
+    /// [`crate::actions::gate::Gate`] actions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::AlreadyDone` if a gate with the same tag was already registered.
+    pub fn register_gate(&mut self, tag: Tag, open: bool) -> Result<(), CommonErrors> {
+        if self.gates.iter().any(|(registered, _)| *registered == tag) {
+            return Err(CommonErrors::AlreadyDone);
+        }
+
+        self.gates.push((tag, Arc::new(AtomicBool::new(open))));
+        Ok(())
+    }
+
+    /// Returns the flag of the gate registered under `tag`.
+    pub(crate) fn gate(&self, tag: Tag) -> Option<Arc<AtomicBool>> {
+        self.gates
+            .iter()
+            .find(|(registered, _)| *registered == tag)
+            .map(|(_, flag)| Arc::clone(flag))
+    }
+
+    /// Registers a topic holding values of type `T`, starting with `init`, and an event with the same name that
+    /// programs can use to notify subscribers about updates. Returns the [`OrchestrationTag`] of the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::AlreadyDone` if a topic with the same name was already registered, or any error of
+    /// [`Design::register_event`].
+    pub fn register_topic<T: Send + 'static>(&mut self, name: Tag, init: T) -> Result<OrchestrationTag, CommonErrors> {
+        if self.topics.iter().any(|(registered, _)| *registered == name) {
+            return Err(CommonErrors::AlreadyDone);
+        }
+
+        let event = self.register_event(name)?;
+        self.topics.push((name, Topic::new(init)));
+        Ok(event)
+    }
+
+    /// Returns a publisher of the topic registered under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if there is no such topic and `CommonErrors::GenericError` if the topic holds
+    /// values of another type.
+    pub fn publish<T: Send + 'static>(&self, name: Tag) -> Result<Publisher<T>, CommonErrors> {
+        self.topic(name)?.publisher().ok_or(CommonErrors::GenericError)
+    }
+
+    /// Returns a subscriber of the topic registered under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Design::publish`].
+    pub fn subscribe<T: Send + 'static>(&self, name: Tag) -> Result<Subscriber<T>, CommonErrors> {
+        self.topic(name)?.subscriber().ok_or(CommonErrors::GenericError)
+    }
+
+    fn topic(&self, name: Tag) -> Result<&Topic, CommonErrors> {
+        self.topics
+            .iter()
+            .find(|(registered, _)| *registered == name)
+            .map(|(_, topic)| topic)
+            .ok_or(CommonErrors::NotFound)
+    }
+
+    /// Exports a program fragment (extension point) under `name`, e.g. acquisition steps of a pipeline. The deployment
+    /// can assemble programs from fragments of multiple designs with
+    /// [`crate::api::deployment::Deployment::add_composed_program`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::AlreadyDone` if a fragment with the same name was already exported.
+    pub fn export_fragment<F>(&mut self, name: Tag, fragment: F) -> Result<(), CommonErrors>
+    where
+        F: Fn(&Design) -> Result<Box<dyn ActionTrait>, CommonErrors> + 'static,
+    {
+        if self.has_fragment(name) {
+            return Err(CommonErrors::AlreadyDone);
+        }
+
+        self.fragments.push((name, Box::new(fragment)));
+        Ok(())
+    }
+
+    pub(crate) fn has_fragment(&self, name: Tag) -> bool {
+        self.fragments.iter().any(|(exported, _)| *exported == name)
+    }
+
+    /// Creates the action of the fragment exported under `name`.
+    pub(crate) fn build_fragment(&self, name: Tag) -> Result<Box<dyn ActionTrait>, CommonErrors> {
+        match self.fragments.iter().find(|(exported, _)| *exported == name) {
+            Some((_, fragment)) => fragment(self),
+            None => Err(CommonErrors::NotFound),
+        }
+    }
+
     /// Registers a condition for an IfElse action.
     pub fn register_if_else_condition<C>(&mut self, tag: Tag, condition: C) -> Result<OrchestrationTag, CommonErrors>
     where
@@ -147,6 +407,14 @@ impl Design {
         self.db.register_if_else_arc_mutex_condition(tag, condition)
     }
 
+    /// Registers a condition for a Switch action.
+    pub fn register_switch_condition<C>(&mut self, tag: Tag, condition: C) -> Result<OrchestrationTag, CommonErrors>
+    where
+        C: SwitchCondition + Send + Sync + 'static,
+    {
+        self.db.register_switch_condition(tag, Arc::new(condition))
+    }
+
     /// Fetches an [`OrchestrationTag`] for a given tag, which can be used to reference the orchestration in programs.
     pub fn get_orchestration_tag(&self, tag: Tag) -> Result<OrchestrationTag, CommonErrors> {
         self.db.get_orchestration_tag(tag)
@@ -168,11 +436,21 @@ impl Design {
         mut self,
         shutdown_events: &GrowableVec<ShutdownEvent>,
         container: &mut GrowableVec<Program>,
+        profile: &mut StartupProfile,
     ) -> Result<(), CommonErrors> {
         while let Some(program_data) = self.programs.pop() {
+            let start = Instant::now();
             let mut builder = ProgramBuilder::new(program_data.0);
+            self.db.begin_program();
+            let design_start = Instant::now();
             (program_data.1)(&mut self, &mut builder)?;
+            profile.record(
+                StartupPhase::ProgramDesign,
+                program_data.0.into(),
+                design_start.elapsed(),
+            );
             container.push(builder.build(shutdown_events, self.config())?);
+            profile.record(StartupPhase::ProgramCreation, program_data.0.into(), start.elapsed());
         }
 
         Ok(())
@@ -210,6 +488,102 @@ mod tests {
         assert_eq!(*design.config(), config);
     }
 
+    #[test]
+    fn action_config_resolves_layered_overrides() {
+        let config = DesignConfig::default()
+            .with_overrides(&DesignConfigOverrides::new().with_max_concurrent_action_executions(4));
+        let mut design = Design::new(Tag::from_str_static("design1"), config);
+
+        assert!(design
+            .override_action_config(
+                "tuned".into(),
+                DesignConfigOverrides::new().with_invoke_dry_run(invoke::InvokeDryRun::NoOp),
+            )
+            .is_ok());
+        assert_eq!(
+            design.override_action_config("tuned".into(), DesignConfigOverrides::new()),
+            Err(CommonErrors::AlreadyDone)
+        );
+
+        let tuned = design.action_config("tuned".into());
+        assert_eq!(tuned.max_concurrent_action_executions, 4);
+        assert_eq!(tuned.invoke_dry_run, invoke::InvokeDryRun::NoOp);
+        assert_eq!(tuned.db_params, DesignConfig::default().db_params);
+        assert_eq!(design.action_config("other".into()), config);
+    }
+
+    #[test]
+    fn register_event_group_registers_prefixed_events_once() {
+        let mut design = Design::new(Tag::from_str_static("design1"), DesignConfig::default());
+
+        assert!(design.register_event_group("camera", &["front", "rear"]).is_ok());
+        assert!(design.get_orchestration_tag("camera.front".into()).is_ok());
+        assert!(design.get_orchestration_tag("camera.rear".into()).is_ok());
+        assert_eq!(design.event_group("camera".into()).count(), 2);
+        assert_eq!(design.event_group("lidar".into()).count(), 0);
+
+        assert_eq!(
+            design.register_event_group("camera", &["side"]),
+            Err(CommonErrors::AlreadyDone)
+        );
+    }
+
+    #[test]
+    fn register_rate_limiter_rejects_duplicates() {
+        let mut design = Design::new(Tag::from_str_static("design1"), DesignConfig::default());
+
+        assert!(design
+            .register_rate_limiter("gpu".into(), 4, Duration::from_millis(10))
+            .is_ok());
+        assert!(design.rate_limiter("gpu".into()).is_some());
+        assert!(design.rate_limiter("npu".into()).is_none());
+
+        assert_eq!(
+            design.register_rate_limiter("gpu".into(), 1, Duration::from_millis(10)),
+            Err(CommonErrors::AlreadyDone)
+        );
+    }
+
+    #[test]
+    fn register_gate_rejects_duplicates() {
+        let mut design = Design::new(Tag::from_str_static("design1"), DesignConfig::default());
+
+        assert!(design.register_gate("diagnostics".into(), false).is_ok());
+        assert!(design.gate("diagnostics".into()).is_some());
+        assert!(design.gate("logging".into()).is_none());
+
+        assert_eq!(
+            design.register_gate("diagnostics".into(), true),
+            Err(CommonErrors::AlreadyDone)
+        );
+    }
+
+    #[test]
+    fn register_topic_shares_value_between_publisher_and_subscriber() {
+        let mut design = Design::new(Tag::from_str_static("design1"), DesignConfig::default());
+
+        assert!(design.register_topic("pose".into(), 0_u32).is_ok());
+        assert!(design.get_orchestration_tag("pose".into()).is_ok());
+        assert_eq!(
+            design.register_topic("pose".into(), 0_u32).err(),
+            Some(CommonErrors::AlreadyDone)
+        );
+
+        let publisher = design.publish::<u32>("pose".into()).unwrap();
+        let subscriber = design.subscribe::<u32>("pose".into()).unwrap();
+        publisher.publish(|value| *value = 5);
+        assert_eq!(subscriber.try_read_new(|version, value| (version, *value)), Ok((1, 5)));
+
+        assert_eq!(
+            design.publish::<u64>("pose".into()).err(),
+            Some(CommonErrors::GenericError)
+        );
+        assert_eq!(
+            design.subscribe::<u32>("speed".into()).err(),
+            Some(CommonErrors::NotFound)
+        );
+    }
+
     fn action() -> Result<(), UserErrValue> {
         Ok(())
     }
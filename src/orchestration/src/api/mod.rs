@@ -45,31 +45,93 @@
 //! This separation ensures that each phase of the orchestration process is modular, testable, and maintainable.
 //!
 
+use crate::actions::sequence::SequenceBuilder;
+use crate::api::startup_profile::{StartupPhase, StartupProfile};
 use crate::common::tag::{AsTagTrait, Tag};
-use crate::events::events_provider::{EventCreator, EventsProvider, ShutdownNotifier};
+use crate::events::events_provider::{
+    EventCreator, EventsProvider, ShutdownNotifier, ShutdownReason, ShutdownReasonReader,
+};
 use crate::{
-    api::{deployment::Deployment, design::Design},
-    program::Program,
+    api::{
+        deployment::Deployment,
+        design::{Design, DesignTag},
+    },
+    program::{
+        Program, ProgramBuilder, ShutdownProgress, ShutdownState, StepControl, StepPosition, TraceControl,
+        TraceSampling,
+    },
 };
+use ::core::future::{poll_fn, Future};
 use ::core::marker::PhantomData;
+use ::core::pin::pin;
+use ::core::task::{Poll, Waker};
+use ::core::time::Duration;
+use kyron::futures::sleep;
+use kyron::time::clock::Clock;
 use kyron_foundation::prelude::vector_extension::VectorExtension;
 use kyron_foundation::prelude::{Vec, Vector};
 use kyron_foundation::{containers::growable_vec::GrowableVec, prelude::CommonErrors};
+use std::cell::Cell;
 use std::path::Path;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
 
 pub mod deployment;
 pub mod design;
+pub mod startup_profile;
 
 ///
 /// The main entry point for the Orchestration API.
 ///
 pub type Orchestration<'a> = OrchestrationApi<_EmptyTag>;
 
+/// Stage of the [`OrchestrationApi`], which defines the operations that are allowed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stage {
+    /// Designs are added, see [`OrchestrationApi::add_design`].
+    Design,
+    /// Designs are bound to the system with [`Deployment`] and programs are created.
+    Deployment,
+}
+
+/// Misuse of the [`OrchestrationApi`] stages, reported by its `try_*` methods.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StageError {
+    /// A design with the same tag was already added.
+    DuplicateDesign(Tag),
+    /// Designs can be added only before [`OrchestrationApi::design_done`].
+    DesignAfterDesignDone(Tag),
+    /// The [`Deployment`] obtained before was not dropped, e.g. it was leaked with `mem::forget`.
+    DeploymentInUse,
+}
+
+impl ::core::fmt::Display for StageError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            StageError::DuplicateDesign(tag) => write!(f, "design {:?} was already added", tag),
+            StageError::DesignAfterDesignDone(tag) => write!(
+                f,
+                "cannot add design {:?} in {:?} stage, all designs shall be added before design_done() is called",
+                tag,
+                Stage::Deployment
+            ),
+            StageError::DeploymentInUse => write!(
+                f,
+                "deployment is already in use, the previous one returned by get_deployment_mut() shall be dropped first"
+            ),
+        }
+    }
+}
+
 pub struct OrchestrationApi<T> {
     designs: GrowableVec<Design>,
     events: EventsProvider,
     shutdown_events: GrowableVec<ShutdownEvent>,
+    composed_programs: GrowableVec<ComposedProgram>,
+    startup_profile: StartupProfile,
+    deployment_start: Option<Instant>,
+    deployment_in_use: Rc<Cell<bool>>,
     _p: PhantomData<T>,
 }
 
@@ -87,15 +149,25 @@ impl OrchestrationApi<_EmptyTag> {
             designs: GrowableVec::default(),
             events: EventsProvider::default(),
             shutdown_events: GrowableVec::default(),
+            composed_programs: GrowableVec::default(),
+            startup_profile: StartupProfile::default(),
+            deployment_start: None,
+            deployment_in_use: Default::default(),
         }
     }
 
+    /// Returns the current stage, [`Stage::Design`] until [`OrchestrationApi::design_done`] is called.
+    pub fn stage(&self) -> Stage {
+        Stage::Design
+    }
+
     ///
     /// Adds a design to the orchestration API.
     ///
     /// # Panics
     ///
-    /// Panics if a design with the same ID already exists in the API.
+    /// Panics with the message of [`StageError`] if a design with the same ID already exists in the API, see
+    /// [`OrchestrationApi::try_add_design`].
     ///
     /// # Arguments
     ///
@@ -105,13 +177,26 @@ impl OrchestrationApi<_EmptyTag> {
     ///
     /// Returns the updated `OrchestrationApi` instance with the new design added.
     pub fn add_design(mut self, design: Design) -> Self {
-        assert!(
-            !self.designs.iter().any(|d| d.id() == design.id()),
-            "Cannot insert same design again"
-        );
+        if let Err(e) = self.try_add_design(design) {
+            panic!("Cannot add design: {}", e);
+        }
+        self
+    }
+
+    ///
+    /// Adds a design to the orchestration API.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::DuplicateDesign` if a design with the same ID already exists in the API.
+    ///
+    pub fn try_add_design(&mut self, design: Design) -> Result<(), StageError> {
+        if self.designs.iter().any(|d| d.id() == design.id()) {
+            return Err(StageError::DuplicateDesign(design.id()));
+        }
 
         self.designs.push(design);
-        self
+        Ok(())
     }
 
     ///
@@ -135,17 +220,68 @@ impl OrchestrationApi<_EmptyTag> {
             designs: self.designs,
             events: self.events,
             shutdown_events: GrowableVec::default(),
+            composed_programs: GrowableVec::default(),
+            startup_profile: self.startup_profile,
+            deployment_start: Some(Instant::now()),
+            deployment_in_use: Default::default(),
         }
     }
 }
 
 impl OrchestrationApi<_DesignTag> {
+    /// Returns the current stage, always [`Stage::Deployment`] after [`OrchestrationApi::design_done`].
+    pub fn stage(&self) -> Stage {
+        Stage::Deployment
+    }
+
+    ///
+    /// Finishing the design stage again has no effect, so code shared between setups can call it unconditionally.
+    ///
+    pub fn design_done(self) -> Self {
+        self
+    }
+
+    ///
+    /// Designs can be added only in the design stage, this reports the misuse with a descriptive error.
+    ///
+    /// # Errors
+    ///
+    /// Always returns `StageError::DesignAfterDesignDone`, add all designs before calling
+    /// [`OrchestrationApi::design_done`].
+    ///
+    pub fn try_add_design(&mut self, design: Design) -> Result<(), StageError> {
+        Err(StageError::DesignAfterDesignDone(design.id()))
+    }
+
     ///
     /// # Returns
     ///
     /// Returns a `Deployment` instance that provides methods to manage the deployment of programs.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message of [`StageError`] if the previous deployment is still in use, see
+    /// [`OrchestrationApi::try_get_deployment_mut`].
     pub fn get_deployment_mut(&mut self) -> Deployment<'_> {
-        Deployment::new(self)
+        match self.try_get_deployment_mut() {
+            Ok(deployment) => deployment,
+            Err(e) => panic!("Cannot get deployment: {}", e),
+        }
+    }
+
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Deployment` instance that provides methods to manage the deployment of programs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageError::DeploymentInUse` if the deployment returned before was not dropped.
+    pub fn try_get_deployment_mut(&mut self) -> Result<Deployment<'_>, StageError> {
+        if self.deployment_in_use.get() {
+            return Err(StageError::DeploymentInUse);
+        }
+        Ok(Deployment::new(self))
     }
 
     ///
@@ -165,21 +301,85 @@ impl OrchestrationApi<_DesignTag> {
     ///
     /// Returns an error if there is an issue while creating the programs, such as a design not being valid.
     pub fn into_program_manager(mut self) -> Result<OrchProgramManager, CommonErrors> {
+        if let Some(deployment_start) = self.deployment_start {
+            self.startup_profile.record(
+                StartupPhase::DeploymentBinding,
+                Tag::from_str_static("deployment"),
+                deployment_start.elapsed(),
+            );
+        }
+
         let mut programs = GrowableVec::default();
+        while let Some(composed) = self.composed_programs.pop() {
+            let start = Instant::now();
+            programs.push(self.build_composed_program(&composed)?);
+            self.startup_profile
+                .record(StartupPhase::ProgramCreation, composed.name.into(), start.elapsed());
+        }
+
         while let Some(design) = self.designs.pop() {
-            design.into_programs(&self.shutdown_events, &mut programs)?
+            let start = Instant::now();
+            let design_id = design.id();
+            design.into_programs(&self.shutdown_events, &mut programs, &mut self.startup_profile)?;
+            self.startup_profile
+                .record(StartupPhase::DesignProgramsCreation, design_id, start.elapsed());
+        }
+
+        let mut trace_controls = Vec::new_in_global(programs.len());
+        let mut step_controls = Vec::new_in_global(programs.len());
+        let mut shutdown_progress = Vec::new_in_global(programs.len());
+        for program in programs.iter() {
+            let _ = trace_controls.push((program.name.clone(), program.trace_control()));
+            let _ = step_controls.push((program.name.clone(), program.step_control()));
+            let _ = shutdown_progress.push((program.name.clone(), program.shutdown_progress()));
         }
 
         Ok(OrchProgramManager {
             programs: programs.into(),
             shutdown_events: self.shutdown_events.into(),
+            trace_controls,
+            step_controls,
+            shutdown_progress,
+            startup_profile: self.startup_profile,
         })
     }
 
+    /// Builds the program as a sequence of fragments, in the order given in the deployment.
+    fn build_composed_program(&self, composed: &ComposedProgram) -> Result<Program, CommonErrors> {
+        let find_design = |design_tag: &DesignTag| {
+            self.designs
+                .iter()
+                .find(|d| d.id() == *design_tag)
+                .ok_or(CommonErrors::NotFound)
+        };
+
+        // Before building any fragment, so fragments of the same design share payload slots only within this program
+        for (design_tag, _) in composed.fragments.iter() {
+            find_design(design_tag)?.db.begin_program();
+        }
+
+        let mut sequence = SequenceBuilder::new();
+        let mut config = None;
+        for (design_tag, fragment) in composed.fragments.iter() {
+            let design = find_design(design_tag)?;
+            sequence.with_step(design.build_fragment(*fragment)?);
+            config.get_or_insert(*design.config());
+        }
+
+        let config = config.ok_or(CommonErrors::NoData)?;
+        let mut builder = ProgramBuilder::new(composed.name);
+        builder.with_run_action(sequence.build());
+        builder.build(&self.shutdown_events, &config)
+    }
+
     pub(crate) fn register_shutdown_event(&mut self, tag: Tag, creator: EventCreator) -> Result<(), CommonErrors> {
         if tag.find_in_collection(self.shutdown_events.iter()).is_some() {
             Err(CommonErrors::AlreadyDone)
-        } else if self.shutdown_events.push(ShutdownEvent { tag, creator }) {
+        } else if self.shutdown_events.push(ShutdownEvent {
+            tag,
+            creator,
+            reason: ShutdownReasonReader::default(),
+        }) {
             Ok(())
         } else {
             Err(CommonErrors::NoSpaceLeft)
@@ -190,6 +390,10 @@ impl OrchestrationApi<_DesignTag> {
 pub struct OrchProgramManager {
     programs: Vec<Program>,
     shutdown_events: Vec<ShutdownEvent>,
+    trace_controls: Vec<(String, Arc<TraceControl>)>,
+    step_controls: Vec<(String, Arc<StepControl>)>,
+    shutdown_progress: Vec<(String, Arc<ShutdownProgress>)>,
+    startup_profile: StartupProfile,
 }
 
 impl OrchProgramManager {
@@ -213,10 +417,76 @@ impl OrchProgramManager {
         }
     }
 
+    /// Returns timing breakdown of the orchestration construction (deployment binding, program closures, programs creation).
+    pub fn startup_profile(&self) -> &StartupProfile {
+        &self.startup_profile
+    }
+
+    /// Enables or disables detailed tracing of the named program at runtime, so a single pipeline can be diagnosed
+    /// without flooding traces from all others. Covers the iterations, start and stop actions of the program and the
+    /// per-step traces of its actions, which are enabled by default. Works also after the program was moved out of
+    /// the manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if there is no program with given name.
+    pub fn set_trace(&self, program: &str, enabled: bool) -> Result<(), CommonErrors> {
+        self.trace_controls
+            .iter()
+            .find(|(name, _)| name == program)
+            .map(|(_, control)| control.set_enabled(enabled))
+            .ok_or(CommonErrors::NotFound)
+    }
+
+    /// Selects which iterations of the named program are traced when its tracing is enabled with
+    /// [`OrchProgramManager::set_trace`], see [`TraceSampling`]. All iterations are traced by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if there is no program with given name.
+    pub fn set_trace_sampling(&self, program: &str, sampling: TraceSampling) -> Result<(), CommonErrors> {
+        self.trace_controls
+            .iter()
+            .find(|(name, _)| name == program)
+            .map(|(_, control)| control.set_sampling(sampling))
+            .ok_or(CommonErrors::NotFound)
+    }
+
+    /// Returns a debug stepping handle of the named program, see [`ProgramStepper`]. Works also after the program was
+    /// moved out of the manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if there is no program with given name.
+    pub fn stepper(&self, program: &str) -> Result<ProgramStepper, CommonErrors> {
+        self.step_controls
+            .iter()
+            .find(|(name, _)| name == program)
+            .map(|(_, control)| ProgramStepper {
+                control: Arc::clone(control),
+            })
+            .ok_or(CommonErrors::NotFound)
+    }
+
+    /// Returns a monitor reporting shutdown progress of all programs. Works also after the programs were moved out of
+    /// the manager.
+    pub fn shutdown_monitor(&self) -> ShutdownMonitor {
+        let mut programs = Vec::new_in_global(self.shutdown_progress.len());
+        for (name, progress) in self.shutdown_progress.iter() {
+            let _ = programs.push((name.clone(), Arc::clone(progress)));
+        }
+
+        ShutdownMonitor { programs }
+    }
+
     /// Retrieve a shutdown notifier for the given event.
     pub fn get_shutdown_notifier(&self, shutdown_event_tag: Tag) -> Result<Box<dyn ShutdownNotifier>, CommonErrors> {
         if let Some(shutdown_event) = shutdown_event_tag.find_in_collection(self.shutdown_events.iter()) {
-            if let Some(shutdown_notifier) = shutdown_event.creator().borrow_mut().create_shutdown_notifier() {
+            if let Some(shutdown_notifier) = shutdown_event
+                .creator()
+                .borrow_mut()
+                .create_shutdown_notifier(shutdown_event.reason())
+            {
                 return Ok(shutdown_notifier);
             } else {
                 return Err(CommonErrors::GenericError);
@@ -226,12 +496,25 @@ impl OrchProgramManager {
         Err(CommonErrors::NotFound)
     }
 
+    /// Retrieve a reader of the reason given to [`ShutdownNotifier::shutdown_with_reason`] for the given event, e.g. to
+    /// differentiate cleanup behavior in stop actions of the programs shut down by the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if there is no shutdown event with given tag.
+    pub fn shutdown_reason_reader(&self, shutdown_event_tag: Tag) -> Result<ShutdownReasonReader, CommonErrors> {
+        shutdown_event_tag
+            .find_in_collection(self.shutdown_events.iter())
+            .map(ShutdownEvent::reason)
+            .ok_or(CommonErrors::NotFound)
+    }
+
     /// Retrieve a shutdown notifier for all shutdown events.
     pub fn get_shutdown_all_notifier(&self) -> Result<Box<dyn ShutdownNotifier>, CommonErrors> {
         let mut shutdown_notifiers = Vec::new_in_global(self.shutdown_events.len());
 
         for event in self.shutdown_events.iter() {
-            if let Some(notifier) = event.creator().borrow_mut().create_shutdown_notifier() {
+            if let Some(notifier) = event.creator().borrow_mut().create_shutdown_notifier(event.reason()) {
                 let _ = shutdown_notifiers.push(notifier);
             } else {
                 return Err(CommonErrors::GenericError);
@@ -242,15 +525,127 @@ impl OrchProgramManager {
     }
 }
 
+///
+/// Debug stepping of a program, for interactive debugging on the bench. While enabled, the program executes one step of
+/// its top-level `Sequence` run action per [`ProgramStepper::step`] call, nested actions of the step run at once. Run
+/// actions other than `Sequence`, start and stop actions are not stopped.
+/// Does not borrow the `OrchProgramManager`, so it can be moved into the task driving the steps.
+///
+pub struct ProgramStepper {
+    control: Arc<StepControl>,
+}
+
+impl ProgramStepper {
+    /// Enables or disables stepping, disabled by default. Disabling releases the waiting step and drops steps not
+    /// taken yet.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.control.set_enabled(enabled);
+    }
+
+    /// Allows the program to execute its next step. Calls made before the program reached the step are counted, each
+    /// allows one step. Has no effect while stepping is disabled.
+    pub fn step(&self) {
+        self.control.step();
+    }
+
+    /// Returns the last step the program reached, `None` if stepping is disabled or no step was reached yet.
+    pub fn position(&self) -> Option<StepPosition> {
+        self.control.position()
+    }
+}
+
+///
+/// Reports shutdown progress of programs after shutdown was requested with [`ShutdownNotifier::shutdown`].
+/// Does not borrow the `OrchProgramManager`, so it can be moved into the task requesting the shutdown.
+///
+pub struct ShutdownMonitor {
+    programs: Vec<(String, Arc<ShutdownProgress>)>,
+}
+
+impl ShutdownMonitor {
+    /// Returns shutdown state of the named program, `None` if there is no such program.
+    pub fn state(&self, program: &str) -> Option<ShutdownState> {
+        self.programs
+            .iter()
+            .find(|(name, _)| name == program)
+            .map(|(_, progress)| progress.state())
+    }
+
+    /// Waits until all programs finished, at most `timeout`. The waiting task is woken when a program finishes.
+    ///
+    /// # Errors
+    ///
+    /// Returns names and states of programs that did not finish within `timeout`.
+    pub async fn wait(&self, timeout: Duration) -> Result<(), Vec<(String, ShutdownState)>> {
+        let start = Clock::now();
+
+        // Timer is created lazily, only if some program did not finish on the first poll
+        let mut timer = pin!(None);
+
+        poll_fn(|cx| {
+            let unfinished = self.unfinished(cx.waker());
+            if unfinished.is_empty() {
+                return Poll::Ready(Ok(()));
+            }
+
+            let elapsed = Clock::now().saturating_duration_since(start);
+            if elapsed >= timeout {
+                return Poll::Ready(Err(unfinished));
+            }
+
+            if timer.is_none() {
+                timer.set(Some(sleep::sleep(timeout - elapsed)));
+            }
+
+            let expired = timer
+                .as_mut()
+                .as_pin_mut()
+                .is_some_and(|timer| timer.poll(cx).is_ready());
+            if expired {
+                Poll::Ready(Err(unfinished))
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Returns programs that did not finish yet, `waker` is woken when any of them finishes.
+    fn unfinished(&self, waker: &Waker) -> Vec<(String, ShutdownState)> {
+        let mut unfinished = Vec::new_in_global(self.programs.len());
+        for (name, progress) in self.programs.iter() {
+            progress.register_waiter(waker);
+            let state = progress.state();
+            if state != ShutdownState::Finished {
+                let _ = unfinished.push((name.clone(), state));
+            }
+        }
+
+        unfinished
+    }
+}
+
+/// Program assembled in the deployment from fragments exported by designs.
+pub(crate) struct ComposedProgram {
+    name: &'static str,
+    fragments: GrowableVec<(DesignTag, Tag)>,
+}
+
 pub(crate) struct ShutdownEvent {
     tag: Tag,
     creator: EventCreator,
+    reason: ShutdownReasonReader,
 }
 
 impl ShutdownEvent {
     pub fn creator(&self) -> EventCreator {
         Rc::clone(&self.creator)
     }
+
+    /// Reason of the last shutdown requested for this event, shared by its notifiers and programs.
+    pub fn reason(&self) -> ShutdownReasonReader {
+        self.reason.clone()
+    }
 }
 
 impl AsTagTrait for ShutdownEvent {
@@ -283,6 +678,14 @@ impl ShutdownNotifier for ShutdownAllNotifierImpl {
 
         Ok(())
     }
+
+    fn shutdown_with_reason(&mut self, reason: ShutdownReason) -> crate::prelude::ActionResult {
+        for notifier in self.shutdown_notifiers.iter_mut() {
+            notifier.shutdown_with_reason(reason)?
+        }
+
+        Ok(())
+    }
 }
 
 #[doc(hidden)]
@@ -290,3 +693,327 @@ pub struct _EmptyTag {}
 
 #[doc(hidden)]
 pub struct _DesignTag {}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{actions::action::ActionExecError, common::DesignConfig, prelude::Invoke};
+    use ::core::sync::atomic::{AtomicUsize, Ordering};
+    use kyron_testing::poller::TestingFuturePoller;
+    use kyron_testing_macros::ensure_clear_mock_runtime;
+    use std::sync::Mutex;
+    use std::task::Wake;
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn design_done_is_idempotent_and_reports_stage() {
+        let orchestration = Orchestration::new();
+        assert_eq!(orchestration.stage(), Stage::Design);
+
+        let orchestration = orchestration
+            .add_design(Design::new("Design".into(), DesignConfig::default()))
+            .design_done();
+        assert_eq!(orchestration.stage(), Stage::Deployment);
+
+        let orchestration = orchestration.design_done();
+        assert_eq!(orchestration.stage(), Stage::Deployment);
+        assert!(orchestration.into_program_manager().is_ok());
+    }
+
+    #[test]
+    fn duplicate_design_is_reported() {
+        let mut orchestration = Orchestration::new();
+        assert_eq!(
+            orchestration.try_add_design(Design::new("Design".into(), DesignConfig::default())),
+            Ok(())
+        );
+        assert_eq!(
+            orchestration.try_add_design(Design::new("Design".into(), DesignConfig::default())),
+            Err(StageError::DuplicateDesign("Design".into()))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "was already added")]
+    fn add_design_panics_with_description_of_duplicate() {
+        let _ = Orchestration::new()
+            .add_design(Design::new("Design".into(), DesignConfig::default()))
+            .add_design(Design::new("Design".into(), DesignConfig::default()));
+    }
+
+    #[test]
+    fn design_after_design_done_is_reported() {
+        let mut orchestration = Orchestration::new().design_done();
+        let error = orchestration
+            .try_add_design(Design::new("Late".into(), DesignConfig::default()))
+            .unwrap_err();
+        assert_eq!(error, StageError::DesignAfterDesignDone("Late".into()));
+        assert!(error
+            .to_string()
+            .contains("all designs shall be added before design_done() is called"));
+    }
+
+    #[test]
+    fn deployment_in_use_is_reported() {
+        let mut orchestration = Orchestration::new().design_done();
+        assert!(orchestration.try_get_deployment_mut().is_ok());
+
+        // A leaked deployment keeps the API locked
+        ::core::mem::forget(orchestration.try_get_deployment_mut().unwrap());
+        assert_eq!(
+            orchestration.try_get_deployment_mut().err(),
+            Some(StageError::DeploymentInUse)
+        );
+    }
+
+    #[test]
+    fn startup_profile_records_program_closures() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design.register_invoke_fn("RunAction".into(), || Ok(())).unwrap();
+        design.add_program("Program", |design, builder| {
+            std::thread::sleep(Duration::from_millis(2));
+            builder.with_run_action(Invoke::from_design("RunAction", design));
+            Ok(())
+        });
+
+        let manager = Orchestration::new()
+            .add_design(design)
+            .design_done()
+            .into_program_manager()
+            .unwrap();
+
+        let profile = manager.startup_profile();
+        let program_design = profile.total(StartupPhase::ProgramDesign);
+        assert!(program_design >= Duration::from_millis(2));
+        assert!(profile.total(StartupPhase::ProgramCreation) >= program_design);
+        assert!(profile
+            .records()
+            .any(|r| r.phase == StartupPhase::ProgramDesign && r.subject == Tag::from_str_static("Program")));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn stepper_executes_one_step_of_program_per_step_call() {
+        static EXECUTED: AtomicUsize = AtomicUsize::new(0);
+
+        fn execute() -> crate::actions::invoke::InvokeResult {
+            EXECUTED.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design.register_invoke_fn("First".into(), execute).unwrap();
+        design.register_invoke_fn("Second".into(), execute).unwrap();
+        design.add_program("Program", |design, builder| {
+            builder.with_run_action(
+                SequenceBuilder::new()
+                    .with_step(Invoke::from_design("First", design))
+                    .with_step(Invoke::from_design("Second", design))
+                    .build(),
+            );
+            Ok(())
+        });
+
+        let mut manager = Orchestration::new()
+            .add_design(design)
+            .design_done()
+            .into_program_manager()
+            .unwrap();
+        assert!(manager.stepper("Unknown").is_err());
+
+        let mut program = manager.get_program("Program").unwrap();
+        let stepper = manager.stepper("Program").unwrap();
+        stepper.set_enabled(true);
+
+        let result = Arc::new(Mutex::new(None));
+        let result_clone = Arc::clone(&result);
+        kyron::testing::mock::spawn(async move {
+            *result_clone.lock().unwrap() = Some(program.run_n(1).await);
+        });
+        let run_steps = || (0..20).for_each(|_| kyron::testing::mock::runtime::step());
+
+        run_steps();
+        assert_eq!(EXECUTED.load(Ordering::Relaxed), 0);
+        assert_eq!(
+            stepper.position(),
+            Some(StepPosition {
+                step: 0,
+                tag: "First".into(),
+                waiting: true
+            })
+        );
+
+        stepper.step();
+        run_steps();
+        assert_eq!(EXECUTED.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            stepper.position(),
+            Some(StepPosition {
+                step: 1,
+                tag: "Second".into(),
+                waiting: true
+            })
+        );
+        assert_eq!(*result.lock().unwrap(), None);
+
+        stepper.step();
+        run_steps();
+        assert_eq!(EXECUTED.load(Ordering::Relaxed), 2);
+        assert_eq!(*result.lock().unwrap(), Some(Ok(())));
+    }
+
+    #[test]
+    fn shutdown_monitor_reports_unfinished_programs() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design.register_invoke_fn("RunAction".into(), || Ok(())).unwrap();
+        design.add_program("Program", |design, builder| {
+            builder.with_run_action(Invoke::from_design("RunAction", design));
+            Ok(())
+        });
+
+        let manager = Orchestration::new()
+            .add_design(design)
+            .design_done()
+            .into_program_manager()
+            .unwrap();
+
+        let monitor = manager.shutdown_monitor();
+        assert_eq!(monitor.state("Program"), Some(ShutdownState::Running));
+        assert_eq!(monitor.state("Unknown"), None);
+
+        let waker = kyron::testing::get_task_based_waker();
+        let mut poller = TestingFuturePoller::new(monitor.wait(Duration::ZERO));
+        match poller.poll_with_waker(&waker) {
+            Poll::Ready(Err(unfinished)) => {
+                assert!(unfinished
+                    .iter()
+                    .eq([("Program".to_string(), ShutdownState::Running)].iter()));
+            },
+            _ => panic!("Monitor shall report unfinished program after timeout"),
+        }
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn shutdown_monitor_is_woken_when_programs_finish() {
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design.register_invoke_fn("RunAction".into(), || Ok(())).unwrap();
+        design.add_program("Program", |design, builder| {
+            builder.with_run_action(Invoke::from_design("RunAction", design));
+            Ok(())
+        });
+
+        let mut manager = Orchestration::new()
+            .add_design(design)
+            .design_done()
+            .into_program_manager()
+            .unwrap();
+        let monitor = manager.shutdown_monitor();
+        let mut program = manager.get_programs().pop().unwrap();
+
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(Arc::clone(&counter));
+        let mut poller = TestingFuturePoller::new(monitor.wait(Duration::from_secs(3600)));
+        assert!(poller.poll_with_waker(&waker).is_pending());
+
+        kyron::testing::mock::spawn(async move {
+            program.run_n(1).await.unwrap();
+        });
+        for _ in 0..10 {
+            kyron::testing::mock::runtime::step();
+        }
+
+        // Woken by the finished program, not by the timer
+        assert_ne!(counter.0.load(Ordering::Relaxed), 0);
+        assert!(matches!(poller.poll_with_waker(&waker), Poll::Ready(Ok(()))));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn composed_programs_do_not_share_payloads() {
+        let consumed = Arc::new(Mutex::new(std::vec::Vec::new()));
+        let consumed_clone = Arc::clone(&consumed);
+
+        let mut design = Design::new("Design".into(), DesignConfig::default());
+        design.register_invoke_producer("produce".into(), || Ok(7_u64)).unwrap();
+        design
+            .register_invoke_consumer(
+                "consume".into(),
+                "produce".into(),
+                move |value: u64| {
+                    consumed_clone.lock().unwrap().push(value);
+                    Ok(())
+                },
+                0xdead_u64.into(),
+            )
+            .unwrap();
+        design
+            .export_fragment("produce_step".into(), |design| {
+                Ok(Invoke::from_design("produce", design))
+            })
+            .unwrap();
+        design
+            .export_fragment("consume_step".into(), |design| {
+                Ok(Invoke::from_design("consume", design))
+            })
+            .unwrap();
+
+        let mut orchestration = Orchestration::new().add_design(design).design_done();
+        let mut deployment = orchestration.get_deployment_mut();
+        deployment
+            .add_composed_program("Producing", &[("Design".into(), "produce_step".into())])
+            .unwrap();
+        deployment
+            .add_composed_program("Consuming", &[("Design".into(), "consume_step".into())])
+            .unwrap();
+        let mut manager = orchestration.into_program_manager().unwrap();
+        let mut producing = manager.get_program("Producing").unwrap();
+        let mut consuming = manager.get_program("Consuming").unwrap();
+
+        let results = Arc::new(Mutex::new(None));
+        let results_clone = Arc::clone(&results);
+        kyron::testing::mock::spawn(async move {
+            let produced = producing.run_n(1).await;
+            let consumed = consuming.run_n(1).await;
+            *results_clone.lock().unwrap() = Some((produced, consumed));
+        });
+        for _ in 0..20 {
+            kyron::testing::mock::runtime::step();
+        }
+
+        // The value produced by the other program is not visible to the consumer
+        assert_eq!(
+            *results.lock().unwrap(),
+            Some((Ok(()), Err(ActionExecError::UserError(0xdead_u64.into()))))
+        );
+        assert!(consumed.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn shutdown_reason_is_kept_per_shutdown_event() {
+        let mut orchestration = Orchestration::new()
+            .add_design(Design::new("Design".into(), DesignConfig::default()))
+            .design_done();
+        let mut deployment = orchestration.get_deployment_mut();
+        deployment.bind_shutdown_event_as_local("Shutdown1".into()).unwrap();
+        deployment.bind_shutdown_event_as_local("Shutdown2".into()).unwrap();
+        let manager = orchestration.into_program_manager().unwrap();
+
+        let reason_1 = manager.shutdown_reason_reader("Shutdown1".into()).unwrap();
+        let reason_2 = manager.shutdown_reason_reader("Shutdown2".into()).unwrap();
+        assert!(manager.shutdown_reason_reader("Unknown".into()).is_err());
+
+        let mut notifier = manager.get_shutdown_notifier("Shutdown1".into()).unwrap();
+        assert_eq!(notifier.shutdown_with_reason(ShutdownReason::Fault), Ok(()));
+        assert_eq!(reason_1.get(), Some(ShutdownReason::Fault));
+        assert_eq!(reason_2.get(), None);
+    }
+}
@@ -0,0 +1,97 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use ::core::time::Duration;
+
+use kyron_foundation::containers::growable_vec::GrowableVec;
+
+use crate::common::tag::Tag;
+
+/// Phase of orchestration construction measured by [`StartupProfile`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StartupPhase {
+    /// From finishing the design phase until programs creation was requested (deployment binding).
+    DeploymentBinding,
+    /// Creation of all programs of a design.
+    DesignProgramsCreation,
+    /// Creation of a single program, including construction of its actions and their pools.
+    ProgramCreation,
+    /// Run of the closure describing a single program added with `Design::add_program`, part of its
+    /// [`StartupPhase::ProgramCreation`]. The rest of the creation is construction of the actions and their pools.
+    ProgramDesign,
+}
+
+/// Single timing entry of [`StartupProfile`].
+#[derive(Clone, Copy, Debug)]
+pub struct StartupRecord {
+    pub phase: StartupPhase,
+    /// Design, program or `deployment` tag the timing belongs to.
+    pub subject: Tag,
+    pub duration: Duration,
+}
+
+///
+/// Timing breakdown of orchestration construction, retrievable from `OrchProgramManager` once the programs are created.
+///
+#[derive(Default)]
+pub struct StartupProfile {
+    records: GrowableVec<StartupRecord>,
+}
+
+impl StartupProfile {
+    pub(crate) fn record(&mut self, phase: StartupPhase, subject: Tag, duration: Duration) {
+        self.records.push(StartupRecord {
+            phase,
+            subject,
+            duration,
+        });
+    }
+
+    /// Returns all records in the order they were taken.
+    pub fn records(&self) -> impl Iterator<Item = &StartupRecord> {
+        self.records.iter()
+    }
+
+    /// Returns the sum of all records of the given phase.
+    pub fn total(&self, phase: StartupPhase) -> Duration {
+        self.records().filter(|r| r.phase == phase).map(|r| r.duration).sum()
+    }
+}
+
+impl ::core::fmt::Debug for StartupProfile {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_list().entries(self.records()).finish()
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_sums_records_of_phase() {
+        let mut profile = StartupProfile::default();
+        profile.record(StartupPhase::ProgramCreation, "a".into(), Duration::from_millis(2));
+        profile.record(
+            StartupPhase::DeploymentBinding,
+            "deployment".into(),
+            Duration::from_millis(5),
+        );
+        profile.record(StartupPhase::ProgramCreation, "b".into(), Duration::from_millis(3));
+
+        assert_eq!(profile.records().count(), 3);
+        assert_eq!(profile.total(StartupPhase::ProgramCreation), Duration::from_millis(5));
+        assert_eq!(profile.total(StartupPhase::ProgramDesign), Duration::ZERO);
+    }
+}
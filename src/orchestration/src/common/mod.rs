@@ -14,6 +14,10 @@
 pub mod orch_tag;
 pub mod tag;
 
+use crate::actions::invoke::{InvokeDryRun, InvokePanicPolicy};
+use crate::core::cooperative::COOPERATIVE_BUDGET;
+use ::core::time::Duration;
+
 #[derive(Clone, Debug, Copy, PartialEq)]
 pub struct ProgramDatabaseParams {
     pub registration_capacity: usize,
@@ -31,6 +35,15 @@ impl Default for ProgramDatabaseParams {
 pub struct DesignConfig {
     pub db_params: ProgramDatabaseParams,
     pub max_concurrent_action_executions: usize,
+    pub invoke_panic_policy: InvokePanicPolicy,
+    pub invoke_dry_run: InvokeDryRun,
+    /// Soft deadline of invokes registered in the design, exceeding it is reported to the handler set by
+    /// `Deployment::set_deadline_handler`. Set it per invoke with `Design::override_action_config`. Other actions
+    /// are monitored with `DeadlineBuilder`.
+    pub deadline: Option<Duration>,
+    /// Time an async invoke may run within a single poll of its task before its cooperative points yield, see
+    /// [`crate::core::cooperative::cooperative_point`].
+    pub cooperative_budget: Duration,
 }
 
 impl Default for DesignConfig {
@@ -38,6 +51,90 @@ impl Default for DesignConfig {
         DesignConfig {
             db_params: ProgramDatabaseParams::default(),
             max_concurrent_action_executions: 2,
+            invoke_panic_policy: InvokePanicPolicy::default(),
+            invoke_dry_run: InvokeDryRun::default(),
+            deadline: None,
+            cooperative_budget: COOPERATIVE_BUDGET,
+        }
+    }
+}
+
+impl DesignConfig {
+    /// Returns a copy of this configuration with the values set in `overrides` replaced.
+    pub fn with_overrides(mut self, overrides: &DesignConfigOverrides) -> Self {
+        if let Some(db_params) = overrides.db_params {
+            self.db_params = db_params;
+        }
+        if let Some(max_concurrent_action_executions) = overrides.max_concurrent_action_executions {
+            self.max_concurrent_action_executions = max_concurrent_action_executions;
+        }
+        if let Some(invoke_panic_policy) = overrides.invoke_panic_policy {
+            self.invoke_panic_policy = invoke_panic_policy;
         }
+        if let Some(invoke_dry_run) = overrides.invoke_dry_run {
+            self.invoke_dry_run = invoke_dry_run;
+        }
+        if let Some(deadline) = overrides.deadline {
+            self.deadline = Some(deadline);
+        }
+        if let Some(cooperative_budget) = overrides.cooperative_budget {
+            self.cooperative_budget = cooperative_budget;
+        }
+        self
+    }
+}
+
+/// Partial [`DesignConfig`] layered on top of another one. Unset values are inherited, so configurations are resolved
+/// as global defaults, then design overrides, then per-action overrides (see `Design::override_action_config`).
+#[derive(Clone, Debug, Copy, Default, PartialEq)]
+pub struct DesignConfigOverrides {
+    pub db_params: Option<ProgramDatabaseParams>,
+    pub max_concurrent_action_executions: Option<usize>,
+    pub invoke_panic_policy: Option<InvokePanicPolicy>,
+    pub invoke_dry_run: Option<InvokeDryRun>,
+    pub deadline: Option<Duration>,
+    pub cooperative_budget: Option<Duration>,
+}
+
+impl DesignConfigOverrides {
+    /// Creates overrides inheriting all values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides parameters of the program database.
+    pub fn with_db_params(mut self, db_params: ProgramDatabaseParams) -> Self {
+        self.db_params = Some(db_params);
+        self
+    }
+
+    /// Overrides the number of executions of an action that can run concurrently.
+    pub fn with_max_concurrent_action_executions(mut self, max_concurrent_action_executions: usize) -> Self {
+        self.max_concurrent_action_executions = Some(max_concurrent_action_executions);
+        self
+    }
+
+    /// Overrides handling of panics in invokes.
+    pub fn with_invoke_panic_policy(mut self, invoke_panic_policy: InvokePanicPolicy) -> Self {
+        self.invoke_panic_policy = Some(invoke_panic_policy);
+        self
+    }
+
+    /// Overrides the dry run mode of invokes.
+    pub fn with_invoke_dry_run(mut self, invoke_dry_run: InvokeDryRun) -> Self {
+        self.invoke_dry_run = Some(invoke_dry_run);
+        self
+    }
+
+    /// Sets the soft deadline of invokes registered in the design.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Overrides the cooperative budget of async invokes.
+    pub fn with_cooperative_budget(mut self, cooperative_budget: Duration) -> Self {
+        self.cooperative_budget = Some(cooperative_budget);
+        self
     }
 }
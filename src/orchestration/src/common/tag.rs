@@ -15,6 +15,8 @@ use ::core::fmt::{Debug, Formatter};
 
 ///
 /// Tag implementation with an 'id' and 'tracing info string'. Supports creation of Tag from &str and String, also from_str_ref().
+/// The id is computed once when the Tag is created, so comparison, ordering and lookups in the program database and
+/// event collections use only the id. The tracing string is kept for debugging purposes only.
 ///
 #[derive(Clone, Copy)]
 pub struct Tag {
@@ -0,0 +1,173 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use ::core::{
+    cell::RefCell,
+    future::{poll_fn, Future},
+    pin::pin,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use kyron::{
+    futures::yield_now::yield_now,
+    time::clock::{Clock, Instant},
+};
+use std::sync::Arc;
+
+/// Default time user code may run within a single poll of its task between two yielding cooperative points, see
+/// `DesignConfig::cooperative_budget`.
+pub const COOPERATIVE_BUDGET: Duration = Duration::from_millis(1);
+
+/// Cancellation of an invoke execution, shared with its task spawned on a dedicated worker.
+pub(crate) type CancelFlag = Arc<AtomicBool>;
+
+/// Execution of the invoke calling [`cooperative_point`] was cancelled, the invoke shall return as soon as possible.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cancelled;
+
+/// Budget of the task currently polled on this worker.
+struct TaskBudget {
+    start: Instant,
+    budget: Duration,
+    cancelled: Option<CancelFlag>,
+}
+
+std::thread_local! {
+    static TASK_BUDGET: RefCell<Option<TaskBudget>> = const { RefCell::new(None) };
+}
+
+///
+/// Polls `future` with the budget restarted on each poll, so cooperative points yield only when the invoke used up
+/// `budget` within a single poll of its task. Cooperative points report [`Cancelled`] once `cancelled` is set.
+///
+pub(crate) async fn budgeted<F: Future>(budget: Duration, cancelled: Option<CancelFlag>, future: F) -> F::Output {
+    let mut future = pin!(future);
+
+    poll_fn(|cx| {
+        let previous = TASK_BUDGET.replace(Some(TaskBudget {
+            start: Clock::now(),
+            budget,
+            cancelled: cancelled.clone(),
+        }));
+        let poll = future.as_mut().poll(cx);
+        TASK_BUDGET.set(previous);
+        poll
+    })
+    .await
+}
+
+///
+/// Cooperative point to be placed inside long running loops of user (async invoke) code.
+/// Yields to the runtime only when the invoke used up its budget (`DesignConfig::cooperative_budget`) within the
+/// current poll of its task, so it is cheap to call frequently, e.g. on every loop iteration. Outside of an invoke the
+/// [`COOPERATIVE_BUDGET`] is used, restarted on each yield.
+///
+/// # Errors
+///
+/// Returns [`Cancelled`] if the execution of the invoke was cancelled, e.g. the invoke bound to a dedicated worker
+/// was aborted by a `Timeout`.
+///
+/// ```ignore
+/// async fn process(frames: &[Frame]) -> InvokeResult {
+///     for frame in frames {
+///         detect_objects(frame);
+///         if cooperative_point().await.is_err() {
+///             break;
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+///
+pub async fn cooperative_point() -> Result<(), Cancelled> {
+    let (budget_used, cancelled) = TASK_BUDGET.with_borrow_mut(|task_budget| {
+        let now = Clock::now();
+        let task_budget = task_budget.get_or_insert_with(|| TaskBudget {
+            start: now,
+            budget: COOPERATIVE_BUDGET,
+            cancelled: None,
+        });
+
+        (
+            now.saturating_duration_since(task_budget.start) >= task_budget.budget,
+            task_budget
+                .cancelled
+                .as_ref()
+                .is_some_and(|cancelled| cancelled.load(Ordering::Acquire)),
+        )
+    });
+
+    if cancelled {
+        return Err(Cancelled);
+    }
+
+    if budget_used {
+        yield_now().await;
+
+        // Within an invoke the next poll already restarted the budget
+        TASK_BUDGET.with_borrow_mut(|task_budget| {
+            if let Some(task_budget) = task_budget {
+                task_budget.start = Clock::now();
+            }
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[cfg(not(miri))]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use ::core::task::Poll;
+    use kyron_testing::poller::TestingFuturePoller;
+
+    #[test]
+    fn cooperative_point_yields_only_when_budget_is_used() {
+        let waker = kyron::testing::get_task_based_waker();
+
+        let mut poller = TestingFuturePoller::new(budgeted(Duration::MAX, None, cooperative_point()));
+        assert_eq!(poller.poll_with_waker(&waker), Poll::Ready(Ok(())));
+
+        let mut poller = TestingFuturePoller::new(budgeted(Duration::ZERO, None, cooperative_point()));
+        assert_eq!(poller.poll_with_waker(&waker), Poll::Pending);
+        assert_eq!(poller.poll_with_waker(&waker), Poll::Ready(Ok(())));
+    }
+
+    #[test]
+    fn budget_is_restarted_on_each_poll() {
+        let waker = kyron::testing::get_task_based_waker();
+
+        // Zero budget yields on every cooperative point, each yield ends the poll
+        let mut poller = TestingFuturePoller::new(budgeted(Duration::ZERO, None, async {
+            cooperative_point().await?;
+            cooperative_point().await
+        }));
+        assert_eq!(poller.poll_with_waker(&waker), Poll::Pending);
+        assert_eq!(poller.poll_with_waker(&waker), Poll::Pending);
+        assert_eq!(poller.poll_with_waker(&waker), Poll::Ready(Ok(())));
+
+        // The budget of the invoke does not leak to code polled outside of it
+        assert!(TASK_BUDGET.with_borrow(|task_budget| task_budget.is_none()));
+    }
+
+    #[test]
+    fn cooperative_point_reports_cancellation() {
+        let waker = kyron::testing::get_task_based_waker();
+        let cancelled = Arc::new(AtomicBool::new(true));
+
+        let mut poller = TestingFuturePoller::new(budgeted(Duration::MAX, Some(cancelled), cooperative_point()));
+        assert_eq!(poller.poll_with_waker(&waker), Poll::Ready(Err(Cancelled)));
+    }
+}
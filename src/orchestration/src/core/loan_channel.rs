@@ -0,0 +1,210 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//!
+//! Loan channel moves ownership of preallocated buffers between a producer and a consumer in the same process
+//! (loan/return semantic). All buffers are allocated when the channel is created, afterwards payloads travel
+//! between actions by pointer, without copies or allocations. Suited for big payloads, like camera frames, exchanged
+//! between programs that are synchronized by orchestration events.
+//!
+
+use ::core::ops::{Deref, DerefMut};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use kyron_foundation::prelude::CommonErrors;
+
+struct Shared<T> {
+    free: Mutex<VecDeque<Box<T>>>,
+    ready: Mutex<VecDeque<Box<T>>>,
+}
+
+impl<T> Shared<T> {
+    fn give_back(&self, buffer: Box<T>) {
+        // Capacity of both queues equals the number of buffers, so this never reallocates
+        self.free.lock().unwrap().push_back(buffer);
+    }
+}
+
+///
+/// Creates a loan channel with `capacity` buffers, each created by `init`.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+///
+pub fn loan_channel<T: Send>(capacity: usize, init: impl Fn() -> T) -> (LoanSender<T>, LoanReceiver<T>) {
+    assert!(capacity > 0, "Loan channel needs at least one buffer");
+
+    let mut free = VecDeque::with_capacity(capacity);
+    (0..capacity).for_each(|_| free.push_back(Box::new(init())));
+
+    let shared = Arc::new(Shared {
+        free: Mutex::new(free),
+        ready: Mutex::new(VecDeque::with_capacity(capacity)),
+    });
+
+    (
+        LoanSender {
+            shared: Arc::clone(&shared),
+        },
+        LoanReceiver { shared },
+    )
+}
+
+/// Producer side of the loan channel.
+pub struct LoanSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> LoanSender<T> {
+    ///
+    /// Loans a free buffer. Buffer still holds the data from its previous use.
+    ///
+    /// # Errors
+    ///    `CommonErrors::NoSpaceLeft` - All buffers are loaned or waiting for the receiver.
+    ///
+    pub fn loan(&self) -> Result<Loan<T>, CommonErrors> {
+        let buffer = self.shared.free.lock().unwrap().pop_front();
+        buffer
+            .map(|buffer| Loan {
+                buffer: Some(buffer),
+                shared: Arc::clone(&self.shared),
+            })
+            .ok_or(CommonErrors::NoSpaceLeft)
+    }
+
+    /// Passes the loaned buffer to the receiver.
+    pub fn send(&self, mut loan: Loan<T>) {
+        let buffer = loan.buffer.take().expect("Loan always holds a buffer until sent");
+        self.shared.ready.lock().unwrap().push_back(buffer);
+    }
+}
+
+/// Consumer side of the loan channel.
+pub struct LoanReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> LoanReceiver<T> {
+    ///
+    /// Takes the oldest sent buffer. The buffer goes back to the pool once the returned [`Sample`] is dropped.
+    ///
+    /// # Errors
+    ///    `CommonErrors::NoData` - Nothing was sent.
+    ///
+    pub fn try_receive(&self) -> Result<Sample<T>, CommonErrors> {
+        let buffer = self.shared.ready.lock().unwrap().pop_front();
+        buffer
+            .map(|buffer| Sample {
+                buffer: Some(buffer),
+                shared: Arc::clone(&self.shared),
+            })
+            .ok_or(CommonErrors::NoData)
+    }
+}
+
+/// Buffer loaned by the producer. Goes back to the pool when dropped without being sent.
+pub struct Loan<T> {
+    buffer: Option<Box<T>>,
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Deref for Loan<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for Loan<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buffer.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for Loan<T> {
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.take() {
+            self.shared.give_back(buffer);
+        }
+    }
+}
+
+/// Buffer received by the consumer. Goes back to the pool when dropped.
+pub struct Sample<T> {
+    buffer: Option<Box<T>>,
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Deref for Sample<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer.as_ref().unwrap()
+    }
+}
+
+impl<T> Drop for Sample<T> {
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.take() {
+            self.shared.give_back(buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffers_move_between_sender_and_receiver_without_copies() {
+        let (sender, receiver) = loan_channel(2, || [0_u8; 1024]);
+
+        let mut loan = sender.loan().unwrap();
+        loan[0] = 42;
+        let address = loan.as_ptr();
+        sender.send(loan);
+
+        let sample = receiver.try_receive().unwrap();
+        assert_eq!(sample[0], 42);
+        assert_eq!(sample.as_ptr(), address);
+    }
+
+    #[test]
+    fn loan_fails_when_all_buffers_are_in_use_and_recovers_after_return() {
+        let (sender, receiver) = loan_channel(1, || 0_u32);
+
+        let loan = sender.loan().unwrap();
+        assert_eq!(sender.loan().err(), Some(CommonErrors::NoSpaceLeft));
+        sender.send(loan);
+        assert_eq!(sender.loan().err(), Some(CommonErrors::NoSpaceLeft));
+
+        let sample = receiver.try_receive().unwrap();
+        assert_eq!(receiver.try_receive().err(), Some(CommonErrors::NoData));
+        drop(sample);
+
+        assert!(sender.loan().is_ok());
+    }
+
+    #[test]
+    fn dropped_loan_returns_to_pool() {
+        let (sender, receiver) = loan_channel(1, || 0_u32);
+
+        drop(sender.loan().unwrap());
+        assert!(sender.loan().is_ok());
+        assert_eq!(receiver.try_receive().err(), Some(CommonErrors::NoData));
+    }
+}
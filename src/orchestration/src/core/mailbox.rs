@@ -0,0 +1,348 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//!
+//! Mailbox passes typed requests from one program to another in the same process and correlates the responses.
+//! Every request occupies one of the slots preallocated when the mailbox is created, until its response is taken or
+//! the requester stops waiting for it. The requester awaits the [`ResponseFuture`], the responder receives requests
+//! in posting order, e.g. with the [`crate::actions::serve::Serve`] action. Waiting for a response can be bounded by
+//! the `Timeout` action, which drops the future and frees the slot.
+//!
+
+use ::core::future::Future;
+use ::core::ops::Deref;
+use ::core::pin::Pin;
+use ::core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use kyron_foundation::prelude::CommonErrors;
+
+enum SlotState<Req, Resp> {
+    Free,
+    Requested(Req),
+    Processing,
+    Responded(Resp),
+    Unanswered,
+}
+
+struct Slot<Req, Resp> {
+    id: u64,
+    state: SlotState<Req, Resp>,
+    waker: Option<Waker>,
+}
+
+struct Inner<Req, Resp> {
+    slots: Box<[Slot<Req, Resp>]>,
+    pending: VecDeque<(usize, u64)>,
+    responder: Option<Waker>,
+    next_id: u64,
+}
+
+type Shared<Req, Resp> = Arc<Mutex<Inner<Req, Resp>>>;
+
+///
+/// Creates a mailbox with up to `capacity` requests in flight.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+///
+pub fn mailbox<Req: Send, Resp: Send>(capacity: usize) -> (Requester<Req, Resp>, Responder<Req, Resp>) {
+    assert!(capacity > 0, "Mailbox needs at least one slot");
+
+    let shared = Arc::new(Mutex::new(Inner {
+        slots: (0..capacity)
+            .map(|_| Slot {
+                id: 0,
+                state: SlotState::Free,
+                waker: None,
+            })
+            .collect(),
+        pending: VecDeque::with_capacity(capacity),
+        responder: None,
+        next_id: 1,
+    }));
+
+    (
+        Requester {
+            shared: Arc::clone(&shared),
+        },
+        Responder { shared },
+    )
+}
+
+/// Requesting side of the mailbox.
+pub struct Requester<Req, Resp> {
+    shared: Shared<Req, Resp>,
+}
+
+impl<Req, Resp> Clone for Requester<Req, Resp> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<Req, Resp> Requester<Req, Resp> {
+    ///
+    /// Posts a request and returns the future of its response.
+    ///
+    /// # Errors
+    ///    `CommonErrors::NoSpaceLeft` - All slots hold requests in flight.
+    ///
+    pub fn request(&self, request: Req) -> Result<ResponseFuture<Req, Resp>, CommonErrors> {
+        let mut inner = self.shared.lock().unwrap();
+        let index = inner
+            .slots
+            .iter()
+            .position(|slot| matches!(slot.state, SlotState::Free))
+            .ok_or(CommonErrors::NoSpaceLeft)?;
+
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.slots[index] = Slot {
+            id,
+            state: SlotState::Requested(request),
+            waker: None,
+        };
+        // Capacity of the queue equals the number of slots, but stale entries of dropped requests stay until received
+        inner.pending.push_back((index, id));
+        let responder = inner.responder.take();
+        drop(inner);
+
+        if let Some(responder) = responder {
+            responder.wake();
+        }
+
+        Ok(ResponseFuture {
+            shared: Arc::clone(&self.shared),
+            index,
+            id,
+            is_done: false,
+        })
+    }
+}
+
+/// Future of the response to a posted request. Dropping it withdraws the request.
+pub struct ResponseFuture<Req, Resp> {
+    shared: Shared<Req, Resp>,
+    index: usize,
+    id: u64,
+    is_done: bool,
+}
+
+impl<Req, Resp> Future for ResponseFuture<Req, Resp> {
+    /// `CommonErrors::NoData` if the responder dropped the request without responding.
+    type Output = Result<Resp, CommonErrors>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let shared = Arc::clone(&self.shared);
+        let mut inner = shared.lock().unwrap();
+        let slot = &mut inner.slots[self.index];
+        debug_assert_eq!(
+            slot.id, self.id,
+            "Slot is reused only after the response future is done"
+        );
+
+        match ::core::mem::replace(&mut slot.state, SlotState::Free) {
+            SlotState::Responded(response) => {
+                self.is_done = true;
+                Poll::Ready(Ok(response))
+            },
+            SlotState::Unanswered => {
+                self.is_done = true;
+                Poll::Ready(Err(CommonErrors::NoData))
+            },
+            state => {
+                slot.state = state;
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            },
+        }
+    }
+}
+
+impl<Req, Resp> Drop for ResponseFuture<Req, Resp> {
+    fn drop(&mut self) {
+        if !self.is_done {
+            let mut inner = self.shared.lock().unwrap();
+            let slot = &mut inner.slots[self.index];
+            slot.state = SlotState::Free;
+            slot.waker = None;
+        }
+    }
+}
+
+/// Responding side of the mailbox.
+pub struct Responder<Req, Resp> {
+    shared: Shared<Req, Resp>,
+}
+
+impl<Req, Resp> Clone for Responder<Req, Resp> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<Req, Resp> Responder<Req, Resp> {
+    ///
+    /// Takes the oldest posted request, if any.
+    ///
+    /// # Errors
+    ///    `CommonErrors::NoData` - No request is waiting.
+    ///
+    pub fn try_receive(&self) -> Result<Request<Req, Resp>, CommonErrors> {
+        let mut inner = self.shared.lock().unwrap();
+
+        while let Some((index, id)) = inner.pending.pop_front() {
+            let slot = &mut inner.slots[index];
+            if slot.id != id {
+                continue; // Request was withdrawn and the slot reused
+            }
+
+            if let SlotState::Requested(request) = ::core::mem::replace(&mut slot.state, SlotState::Processing) {
+                return Ok(Request {
+                    shared: Arc::clone(&self.shared),
+                    index,
+                    id,
+                    request: Some(request),
+                });
+            }
+
+            slot.state = SlotState::Free; // Request was withdrawn
+        }
+
+        Err(CommonErrors::NoData)
+    }
+
+    /// Waits for the oldest posted request.
+    pub async fn receive(&self) -> Request<Req, Resp> {
+        ::core::future::poll_fn(|cx| match self.try_receive() {
+            Ok(request) => Poll::Ready(request),
+            Err(_) => {
+                self.shared.lock().unwrap().responder = Some(cx.waker().clone());
+                // A request may be posted before the waker got registered
+                match self.try_receive() {
+                    Ok(request) => Poll::Ready(request),
+                    Err(_) => Poll::Pending,
+                }
+            },
+        })
+        .await
+    }
+}
+
+/// Request received by the responder. Dropping it without a response fails the requester's future.
+pub struct Request<Req, Resp> {
+    shared: Shared<Req, Resp>,
+    index: usize,
+    id: u64,
+    request: Option<Req>,
+}
+
+impl<Req, Resp> Request<Req, Resp> {
+    /// Passes `response` to the requester, if it still waits for it.
+    pub fn respond(mut self, response: Resp) {
+        self.request = None;
+        self.complete(SlotState::Responded(response));
+    }
+
+    fn complete(&self, state: SlotState<Req, Resp>) {
+        let mut inner = self.shared.lock().unwrap();
+        let slot = &mut inner.slots[self.index];
+        if slot.id != self.id || !matches!(slot.state, SlotState::Processing) {
+            return; // Requester stopped waiting
+        }
+
+        slot.state = state;
+        let waker = slot.waker.take();
+        drop(inner);
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<Req, Resp> Deref for Request<Req, Resp> {
+    type Target = Req;
+
+    fn deref(&self) -> &Self::Target {
+        self.request.as_ref().unwrap()
+    }
+}
+
+impl<Req, Resp> Drop for Request<Req, Resp> {
+    fn drop(&mut self) {
+        if self.request.is_some() {
+            self.complete(SlotState::Unanswered);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use kyron_testing::poller::TestingFuturePoller;
+
+    #[test]
+    fn responses_are_correlated_with_requests() {
+        let (requester, responder) = mailbox::<u32, u32>(2);
+
+        let mut first = TestingFuturePoller::new(requester.request(1).unwrap());
+        let mut second = TestingFuturePoller::new(requester.request(2).unwrap());
+        let waker = kyron::testing::get_task_based_waker();
+        assert!(first.poll_with_waker(&waker).is_pending());
+        assert!(second.poll_with_waker(&waker).is_pending());
+
+        let request = responder.try_receive().unwrap();
+        assert_eq!(*request, 1);
+        let other = responder.try_receive().unwrap();
+        assert_eq!(*other, 2);
+        other.respond(20);
+        request.respond(10);
+
+        assert_eq!(second.poll_with_waker(&waker), Poll::Ready(Ok(20)));
+        assert_eq!(first.poll_with_waker(&waker), Poll::Ready(Ok(10)));
+    }
+
+    #[test]
+    fn capacity_limits_requests_in_flight() {
+        let (requester, responder) = mailbox::<u32, u32>(1);
+
+        let pending = requester.request(1).unwrap();
+        assert_eq!(requester.request(2).err(), Some(CommonErrors::NoSpaceLeft));
+
+        // Withdrawn request frees its slot and is not received
+        drop(pending);
+        assert_eq!(responder.try_receive().err(), Some(CommonErrors::NoData));
+        let _response = requester.request(3).unwrap();
+        assert_eq!(*responder.try_receive().unwrap(), 3);
+    }
+
+    #[test]
+    fn dropped_request_fails_the_response() {
+        let (requester, responder) = mailbox::<u32, u32>(1);
+
+        let mut response = TestingFuturePoller::new(requester.request(1).unwrap());
+        drop(responder.try_receive().unwrap());
+
+        let waker = kyron::testing::get_task_based_waker();
+        assert_eq!(response.poll_with_waker(&waker), Poll::Ready(Err(CommonErrors::NoData)));
+    }
+}
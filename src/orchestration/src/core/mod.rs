@@ -11,6 +11,10 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
+pub mod cooperative;
+pub mod loan_channel;
+pub mod mailbox;
 pub mod metering;
 pub mod orch_locks;
 pub mod runtime_seq_acc;
+pub mod topic;
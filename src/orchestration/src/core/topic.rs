@@ -0,0 +1,158 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+//!
+//! Topics exchange versioned state updates between programs of a design in the same process. Each topic keeps one
+//! preallocated value that the publisher updates in place, so publishing does not allocate. Subscribers read the
+//! latest value and can check whether it changed since their last read. Topics are registered in the design with
+//! [`crate::api::design::Design::register_topic`] together with an event of the same name, so a program that publishes
+//! can notify subscribers by the usual `Trigger`/`Sync` actions, bound locally or globally in the deployment.
+//!
+
+use ::core::any::Any;
+use ::core::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use kyron_foundation::prelude::CommonErrors;
+
+struct Slot<T> {
+    version: u64,
+    value: T,
+}
+
+/// Type erased topic storage kept by the design.
+pub(crate) struct Topic {
+    slot: Arc<dyn Any + Send + Sync>,
+}
+
+impl Topic {
+    pub(crate) fn new<T: Send + 'static>(init: T) -> Self {
+        Self {
+            slot: Arc::new(Mutex::new(Slot {
+                version: 0,
+                value: init,
+            })),
+        }
+    }
+
+    /// Returns the typed slot or `None` if the topic holds values of another type.
+    fn slot<T: Send + 'static>(&self) -> Option<Arc<Mutex<Slot<T>>>> {
+        Arc::clone(&self.slot).downcast::<Mutex<Slot<T>>>().ok()
+    }
+
+    pub(crate) fn publisher<T: Send + 'static>(&self) -> Option<Publisher<T>> {
+        self.slot().map(|slot| Publisher { slot })
+    }
+
+    pub(crate) fn subscriber<T: Send + 'static>(&self) -> Option<Subscriber<T>> {
+        self.slot().map(|slot| Subscriber {
+            slot,
+            seen: AtomicU64::new(0),
+        })
+    }
+}
+
+/// Publishing side of a topic.
+pub struct Publisher<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+}
+
+impl<T> Publisher<T> {
+    /// Updates the topic value in place and returns the new version. Versions start at 1 for the first update.
+    pub fn publish(&self, update: impl FnOnce(&mut T)) -> u64 {
+        let mut slot = self.slot.lock().unwrap();
+        update(&mut slot.value);
+        slot.version += 1;
+        slot.version
+    }
+}
+
+/// Subscribing side of a topic. Every subscriber tracks the last version it has read.
+pub struct Subscriber<T> {
+    slot: Arc<Mutex<Slot<T>>>,
+    seen: AtomicU64,
+}
+
+impl<T> Subscriber<T> {
+    /// Returns the version of the latest update, `0` if nothing was published yet.
+    pub fn version(&self) -> u64 {
+        self.slot.lock().unwrap().version
+    }
+
+    /// Reads the latest value together with its version.
+    pub fn read<R>(&self, reader: impl FnOnce(u64, &T) -> R) -> R {
+        let slot = self.slot.lock().unwrap();
+        self.seen.store(slot.version, Ordering::Relaxed);
+        reader(slot.version, &slot.value)
+    }
+
+    ///
+    /// Reads the latest value only if it was updated since the last read of this subscriber.
+    ///
+    /// # Errors
+    ///    `CommonErrors::NoData` - Nothing was published since the last read.
+    ///
+    pub fn try_read_new<R>(&self, reader: impl FnOnce(u64, &T) -> R) -> Result<R, CommonErrors> {
+        let slot = self.slot.lock().unwrap();
+        if slot.version == self.seen.load(Ordering::Relaxed) {
+            return Err(CommonErrors::NoData);
+        }
+
+        self.seen.store(slot.version, Ordering::Relaxed);
+        Ok(reader(slot.version, &slot.value))
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscribers_see_updates_once_with_versions() {
+        let topic = Topic::new([0_u8; 16]);
+        let publisher = topic.publisher::<[u8; 16]>().unwrap();
+        let subscriber = topic.subscriber::<[u8; 16]>().unwrap();
+
+        assert_eq!(subscriber.try_read_new(|_, _| ()).err(), Some(CommonErrors::NoData));
+        assert_eq!(publisher.publish(|value| value[0] = 7), 1);
+        assert_eq!(publisher.publish(|value| value[1] = 8), 2);
+
+        assert_eq!(
+            subscriber.try_read_new(|version, value| (version, value[0], value[1])),
+            Ok((2, 7, 8))
+        );
+        assert_eq!(subscriber.try_read_new(|_, _| ()).err(), Some(CommonErrors::NoData));
+        assert_eq!(subscriber.read(|version, value| (version, value[0])), (2, 7));
+    }
+
+    #[test]
+    fn every_subscriber_tracks_its_own_reads() {
+        let topic = Topic::new(0_u32);
+        let publisher = topic.publisher::<u32>().unwrap();
+        let first = topic.subscriber::<u32>().unwrap();
+        let second = topic.subscriber::<u32>().unwrap();
+
+        publisher.publish(|value| *value = 42);
+        assert_eq!(first.try_read_new(|_, value| *value), Ok(42));
+        assert_eq!(second.try_read_new(|_, value| *value), Ok(42));
+        assert_eq!(first.version(), 1);
+    }
+
+    #[test]
+    fn wrong_type_is_rejected() {
+        let topic = Topic::new(0_u32);
+        assert!(topic.publisher::<u64>().is_none());
+        assert!(topic.subscriber::<i32>().is_none());
+    }
+}
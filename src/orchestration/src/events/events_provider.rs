@@ -13,6 +13,7 @@
 use ::core::cell::RefCell;
 use core::time::Duration;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 use crate::common::DesignConfig;
 use crate::events::event_traits::{IpcProvider, NotifierTrait};
@@ -170,24 +171,67 @@ pub(crate) enum EventActionType {
 
 pub(crate) type EventCreator = Rc<RefCell<dyn EventCreatorTrait>>;
 
+/// Reason of a shutdown requested with [`ShutdownNotifier::shutdown_with_reason`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShutdownReason {
+    /// Shutdown requested by an operator or a supervising component.
+    Requested,
+    /// Shutdown due to a fault.
+    Fault,
+    /// Shutdown due to power down of the system.
+    PowerDown,
+    /// Application specific reason.
+    Custom(u32),
+}
+
+///
+/// Reason of the last shutdown requested through the notifiers of a single shutdown event, see
+/// [`crate::api::OrchProgramManager::shutdown_reason_reader`]. The reason is not transferred across processes.
+///
+#[derive(Clone, Default)]
+pub struct ShutdownReasonReader(Arc<Mutex<Option<ShutdownReason>>>);
+
+impl ShutdownReasonReader {
+    /// Returns the reason of the last shutdown requested for the event, `None` if no reason was given.
+    pub fn get(&self) -> Option<ShutdownReason> {
+        *self.0.lock().unwrap()
+    }
+
+    fn set(&self, reason: ShutdownReason) {
+        *self.0.lock().unwrap() = Some(reason);
+    }
+}
+
 pub trait ShutdownNotifier {
     fn shutdown(&mut self) -> ActionResult;
+
+    /// Requests the shutdown like [`ShutdownNotifier::shutdown`], recording `reason` for the programs shut down by the
+    /// event first. Notifiers not tracking the reason only request the shutdown.
+    fn shutdown_with_reason(&mut self, _reason: ShutdownReason) -> ActionResult {
+        self.shutdown()
+    }
 }
 
 struct ShutdownNotifierImpl<N: NotifierTrait> {
     notifier: N,
+    reason: ShutdownReasonReader,
 }
 
 impl<N: NotifierTrait> ShutdownNotifier for ShutdownNotifierImpl<N> {
     fn shutdown(&mut self) -> ActionResult {
         self.notifier.notify_sync(0)
     }
+
+    fn shutdown_with_reason(&mut self, reason: ShutdownReason) -> ActionResult {
+        self.reason.set(reason);
+        self.shutdown()
+    }
 }
 
 pub(crate) trait EventCreatorTrait {
     fn create_trigger(&mut self, config: &DesignConfig) -> Option<Box<dyn ActionTrait>>;
     fn create_sync(&mut self, config: &DesignConfig) -> Option<Box<dyn ActionTrait>>;
-    fn create_shutdown_notifier(&mut self) -> Option<Box<dyn ShutdownNotifier>>;
+    fn create_shutdown_notifier(&mut self, reason: ShutdownReasonReader) -> Option<Box<dyn ShutdownNotifier>>;
 }
 
 struct LocalEventCreator {
@@ -211,13 +255,13 @@ impl EventCreatorTrait for LocalEventCreator {
         ) as Box<dyn ActionTrait>)
     }
 
-    fn create_shutdown_notifier(&mut self) -> Option<Box<dyn ShutdownNotifier>> {
+    fn create_shutdown_notifier(&mut self, reason: ShutdownReasonReader) -> Option<Box<dyn ShutdownNotifier>> {
         let n = self.local_event.get_notifier();
         if n.is_none() {
             debug!("Failed to create Trigger Action, notifier is None. Did you tried to create two notifiers for the same event?");
         }
 
-        Some(Box::new(ShutdownNotifierImpl { notifier: n? }))
+        Some(Box::new(ShutdownNotifierImpl { notifier: n?, reason }))
     }
 }
 
@@ -245,12 +289,13 @@ impl<GlobalProvider: IpcProvider> EventCreatorTrait for GlobalEventCreator<Globa
         ) as Box<dyn ActionTrait>)
     }
 
-    fn create_shutdown_notifier(&mut self) -> Option<Box<dyn ShutdownNotifier>> {
+    fn create_shutdown_notifier(&mut self, reason: ShutdownReasonReader) -> Option<Box<dyn ShutdownNotifier>> {
         Some(Box::new(ShutdownNotifierImpl {
             notifier: self
                 .global_provider
                 .borrow_mut()
                 .get_notifier(self.system_event_name.as_str())?,
+            reason,
         }))
     }
 }
@@ -268,7 +313,7 @@ impl EventCreatorTrait for TimerEventCreator {
         Some(Sync::new(TimerEvent::new(self.cycle), config.max_concurrent_action_executions) as Box<dyn ActionTrait>)
     }
 
-    fn create_shutdown_notifier(&mut self) -> Option<Box<dyn ShutdownNotifier>> {
+    fn create_shutdown_notifier(&mut self, _reason: ShutdownReasonReader) -> Option<Box<dyn ShutdownNotifier>> {
         panic!("Cannot create trigger for a event that is bound to a Timer Event type for shutdown!")
     }
 }
@@ -362,6 +407,32 @@ mod tests {
         assert!(trigger_action.is_some());
     }
 
+    #[test]
+    fn shutdown_with_reason_records_reason_and_notifies() {
+        let config = DesignConfig::default();
+        let mut provider: EventsProvider = EventsProvider::new();
+
+        let res = provider.specify_event("100", EventType::Local, &["UserEvt".into()], |_, evt_tag| {
+            LocalEventCreator {
+                local_event: LocalEvent::new(evt_tag),
+            }
+        });
+        assert!(res.is_ok());
+
+        let creator = provider.get_event_creator("100").unwrap();
+        let mut sync_action = creator.borrow_mut().create_sync(&config).unwrap();
+        let reason = ShutdownReasonReader::default();
+        let mut notifier = creator.borrow_mut().create_shutdown_notifier(reason.clone()).unwrap();
+
+        let mut poller = OrchTestingPoller::new(sync_action.try_execute().unwrap());
+        assert!(poller.poll().is_pending());
+        assert_eq!(reason.get(), None);
+
+        assert_eq!(notifier.shutdown_with_reason(ShutdownReason::PowerDown), Ok(()));
+        assert_eq!(reason.get(), Some(ShutdownReason::PowerDown));
+        assert_poll_ready(poller.poll(), Ok(()));
+    }
+
     #[test]
     fn sync_trigger_local_pair_works() {
         let config = DesignConfig::default();
@@ -91,6 +91,8 @@ impl NotifierTrait for LocalNotifier {
     }
 }
 
+/// Listener of a local event. A waiting listener is parked on the channel waker registration,
+/// so the owning task is not polled again until an event is sent (or notifier is gone).
 pub struct LocalListener {
     id: Tag,
     receiver: Arc<OrchTryLock<Receiver<u32, MAX_NUM_OF_EVENTS>>>, // Arc used here to "share between futures, not between actions"
@@ -128,6 +130,18 @@ mod tests {
     use crate::testing::OrchTestingPoller;
 
     use super::*;
+    use ::core::pin::pin;
+    use ::core::sync::atomic::{AtomicUsize, Ordering};
+    use ::core::task::{Context, Poll, Waker};
+    use std::task::Wake;
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
 
     #[test]
     fn local_listener_success() {
@@ -178,4 +192,29 @@ mod tests {
         })
         .is_some());
     }
+
+    #[test]
+    fn local_listener_is_woken_only_by_notification() {
+        let mut event = LocalEvent::new("test_event".into());
+        let notifier = event.get_notifier().expect("Notifier should be available");
+        let mut listener = event.get_listener().expect("Listener should be available");
+
+        let wake_count = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(Arc::clone(&wake_count));
+        let mut cx = Context::from_waker(&waker);
+
+        for iteration in 0..2 {
+            let mut listen = pin!(listener.next());
+            assert_eq!(listen.as_mut().poll(&mut cx), Poll::Pending);
+            assert_eq!(listen.as_mut().poll(&mut cx), Poll::Pending);
+
+            // Nothing sent yet, so there shall be no wake up (no busy re-polling)
+            assert_eq!(wake_count.0.load(Ordering::Relaxed), iteration);
+
+            // Single notification wakes the listener exactly once
+            assert!(notifier.notify_sync(1).is_ok());
+            assert_eq!(wake_count.0.load(Ordering::Relaxed), iteration + 1);
+            assert_eq!(listen.as_mut().poll(&mut cx), Poll::Ready(Ok(())));
+        }
+    }
 }
@@ -19,6 +19,11 @@ pub mod events;
 pub mod prelude;
 pub mod program;
 pub mod program_database;
+pub mod tools;
 
 #[cfg(test)]
 pub mod testing;
+
+// Lets derive macros of `orchestration_macros` refer to this crate as `::orchestration` in tests.
+#[cfg(test)]
+extern crate self as orchestration;
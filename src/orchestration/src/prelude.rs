@@ -12,10 +12,24 @@
 // *******************************************************************************
 
 pub use crate::actions::action::*;
+pub use crate::actions::budget::{AcquireBudget, AcquireBudgetBuilder};
 pub use crate::actions::catch::*;
 pub use crate::actions::concurrency::*;
+pub use crate::actions::custom::{CustomAction, CustomActionBuilder};
+pub use crate::actions::fallback::{Fallback, FallbackBuilder};
+pub use crate::actions::gate::{Gate, GateBuilder, GateControl};
 pub use crate::actions::graph::*;
 pub use crate::actions::invoke::*;
+pub use crate::actions::repeat::{Loop, LoopBuilder, WhileBuilder};
+pub use crate::actions::retry::*;
 pub use crate::actions::sequence::*;
+pub use crate::actions::serve::{Serve, ServeBuilder};
 pub use crate::actions::sync::*;
+pub use crate::actions::throttle::{Throttle, ThrottleBuilder};
+pub use crate::actions::timeout::*;
 pub use crate::actions::trigger::*;
+pub use crate::actions::watchdog::*;
+pub use crate::core::cooperative::{cooperative_point, Cancelled};
+pub use crate::core::loan_channel::{loan_channel, Loan, LoanReceiver, LoanSender, Sample};
+pub use crate::core::mailbox::{mailbox, Request, Requester, Responder, ResponseFuture};
+pub use crate::core::topic::{Publisher, Subscriber};
@@ -22,18 +22,21 @@ use crate::{
     api::ShutdownEvent,
     common::{tag::Tag, DesignConfig},
     core::metering::{MeterTrait, NoneMeter},
-    prelude::{ActionExecError, ActionResult, ActionTrait},
+    events::events_provider::ShutdownReasonReader,
+    prelude::{ActionExecError, ActionResult, ActionTrait, StepGate, TraceSwitch},
 };
 use ::core::{
     fmt::Debug,
     future::Future,
     pin::Pin,
-    task::{Context, Poll},
+    sync::atomic::{AtomicU64, Ordering},
+    task::{Context, Poll, Waker},
     time::Duration,
 };
-use kyron::{time::clock::Clock, JoinHandle};
+use kyron::{futures::sleep, time::clock::Clock, JoinHandle};
 use kyron_foundation::prelude::*;
 use kyron_foundation::{containers::growable_vec::GrowableVec, prelude::CommonErrors};
+use std::sync::{Arc, Mutex};
 
 #[cfg(not(any(test, feature = "runtime-api-mock")))]
 use kyron::safety::spawn_from_reusable;
@@ -50,7 +53,262 @@ pub struct Program {
     stop_action: Option<Box<dyn ActionTrait>>,
     #[allow(dead_code)]
     stop_timeout: Duration,
+    min_iteration_gap: Duration,
     shutdown_sync: Option<Box<dyn ActionTrait>>,
+    shutdown_reason: ShutdownReasonReader,
+    trace: Arc<TraceControl>,
+    stepping: Arc<StepControl>,
+    shutdown_progress: Arc<ShutdownProgress>,
+}
+
+/// Selects which iterations of a traced program are traced, so tracing stays cheap in production but still captures
+/// anomalies. `All` and `OneInN` trace the actions of selected iterations, `OnlyOnError` and `LatencyAbove` can only
+/// decide once the iteration finished, so they emit just a summary line of matching iterations and no per-action
+/// traces. See [`crate::api::OrchProgramManager::set_trace_sampling`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TraceSampling {
+    /// Every iteration is traced.
+    #[default]
+    All,
+    /// Every n-th iteration is traced, starting with the first one.
+    OneInN(usize),
+    /// Only summary of iterations finished with an error is traced.
+    OnlyOnError,
+    /// Only summary of iterations taking longer than given duration is traced.
+    LatencyAbove(Duration),
+}
+
+impl TraceSampling {
+    const KIND_SHIFT: u32 = 62;
+    const VALUE_MASK: u64 = (1 << Self::KIND_SHIFT) - 1;
+
+    /// Returns `true` if actions of the iteration are traced.
+    fn traces_actions(&self, iteration: usize) -> bool {
+        match *self {
+            TraceSampling::All => true,
+            TraceSampling::OneInN(n) => iteration % n.max(1) == 0,
+            TraceSampling::OnlyOnError | TraceSampling::LatencyAbove(_) => false,
+        }
+    }
+
+    /// Returns `true` if the summary of the finished iteration is traced.
+    fn is_sampled(&self, iteration: usize, duration: Duration, result: &ActionResult) -> bool {
+        match *self {
+            TraceSampling::All => true,
+            TraceSampling::OneInN(n) => iteration % n.max(1) == 0,
+            TraceSampling::OnlyOnError => result.is_err(),
+            TraceSampling::LatencyAbove(threshold) => duration > threshold,
+        }
+    }
+
+    /// Packs the policy into a single word, values are saturated to the 62 bits left by the kind.
+    fn encode(&self) -> u64 {
+        let (kind, value) = match *self {
+            TraceSampling::All => (0, 0),
+            TraceSampling::OneInN(n) => (1, n as u64),
+            TraceSampling::OnlyOnError => (2, 0),
+            TraceSampling::LatencyAbove(threshold) => (3, u64::try_from(threshold.as_nanos()).unwrap_or(u64::MAX)),
+        };
+        (kind << Self::KIND_SHIFT) | value.min(Self::VALUE_MASK)
+    }
+
+    fn decode(word: u64) -> Self {
+        let value = word & Self::VALUE_MASK;
+        match word >> Self::KIND_SHIFT {
+            0 => TraceSampling::All,
+            1 => TraceSampling::OneInN(value as usize),
+            2 => TraceSampling::OnlyOnError,
+            _ => TraceSampling::LatencyAbove(Duration::from_nanos(value)),
+        }
+    }
+}
+
+/// Tracing state of a program shared with `OrchProgramManager`. The sampling policy is kept in a single atomic word, so
+/// it is read on each iteration without locking. `actions` is the switch attached to the actions of the program, set
+/// before each iteration from the enable flag and the policy.
+pub(crate) struct TraceControl {
+    enabled: FoundationAtomicBool,
+    sampling: AtomicU64,
+    actions: Arc<FoundationAtomicBool>,
+}
+
+impl TraceControl {
+    fn new() -> Self {
+        Self {
+            enabled: FoundationAtomicBool::new(true),
+            sampling: AtomicU64::new(TraceSampling::default().encode()),
+            actions: Arc::new(FoundationAtomicBool::new(true)),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+        self.actions
+            .store(enabled && self.sampling().traces_actions(0), Ordering::Relaxed);
+    }
+
+    pub(crate) fn sampling(&self) -> TraceSampling {
+        TraceSampling::decode(self.sampling.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_sampling(&self, sampling: TraceSampling) {
+        self.sampling.store(sampling.encode(), Ordering::Relaxed);
+        self.actions
+            .store(self.is_enabled() && sampling.traces_actions(0), Ordering::Relaxed);
+    }
+
+    /// Switches tracing of actions for the iteration, returns the policy to be applied to its summary.
+    fn begin_iteration(&self, iteration: usize) -> TraceSampling {
+        let sampling = self.sampling();
+        self.actions.store(
+            self.is_enabled() && sampling.traces_actions(iteration),
+            Ordering::Relaxed,
+        );
+        sampling
+    }
+}
+
+/// Position of a stepped program, see [`crate::api::ProgramStepper::position`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StepPosition {
+    /// Index of the step in the top-level `Sequence` of the program.
+    pub step: usize,
+    /// Tag of the action of the step.
+    pub tag: Tag,
+    /// `true` while the step waits for [`crate::api::ProgramStepper::step`], `false` once it executes.
+    pub waiting: bool,
+}
+
+/// Debug stepping state of a program shared with `ProgramStepper`. While enabled, each step of the top-level `Sequence`
+/// of the program waits for a permit given by `step()`. `enabled` mirrors the flag in `state`, so steps of programs
+/// that are not stepped do not lock.
+pub(crate) struct StepControl {
+    enabled: FoundationAtomicBool,
+    state: Mutex<StepState>,
+}
+
+#[derive(Default)]
+struct StepState {
+    enabled: bool,
+    permits: usize,
+    position: Option<StepPosition>,
+    waker: Option<Waker>, // Of the step waiting for a permit
+}
+
+impl StepControl {
+    pub(crate) fn new() -> Self {
+        Self {
+            enabled: FoundationAtomicBool::new(false),
+            state: Mutex::new(StepState::default()),
+        }
+    }
+
+    pub(crate) fn set_enabled(&self, enabled: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.enabled = enabled;
+        self.enabled.store(enabled, Ordering::Relaxed);
+        if !enabled {
+            // Release the waiting step, the program continues without stopping
+            state.permits = 0;
+            state.position = None;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    pub(crate) fn step(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.enabled {
+            state.permits += 1;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    pub(crate) fn position(&self) -> Option<StepPosition> {
+        self.state.lock().unwrap().position
+    }
+
+    /// Polled before the `step` with `tag` is executed, ready once stepping is disabled or a permit is available.
+    pub(crate) fn poll_step(&self, step: usize, tag: Tag, cx: &mut Context<'_>) -> Poll<()> {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return Poll::Ready(());
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if !state.enabled {
+            return Poll::Ready(());
+        }
+
+        let waiting = state.permits == 0;
+        state.position = Some(StepPosition { step, tag, waiting });
+        if waiting {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        } else {
+            state.permits -= 1;
+            Poll::Ready(())
+        }
+    }
+}
+
+/// Shutdown state of a program, see [`crate::api::ShutdownMonitor`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShutdownState {
+    /// Program did not observe the shutdown event yet.
+    Running,
+    /// Program observed the shutdown event and executes its stop action.
+    Acknowledged,
+    /// Program finished its execution.
+    Finished,
+}
+
+/// Shutdown progress of a program, shared with `OrchProgramManager`.
+pub(crate) struct ShutdownProgress {
+    acknowledged: FoundationAtomicBool,
+    finished: FoundationAtomicBool,
+    waiters: Mutex<std::vec::Vec<Waker>>, // Woken when the program finishes
+}
+
+impl ShutdownProgress {
+    fn new() -> Self {
+        Self {
+            acknowledged: FoundationAtomicBool::new(false),
+            finished: FoundationAtomicBool::new(false),
+            waiters: Mutex::new(std::vec::Vec::new()),
+        }
+    }
+
+    /// Registers `waker` to be woken when the program finishes. Shall be called before checking the state, so the
+    /// finish cannot be missed.
+    pub(crate) fn register_waiter(&self, waker: &Waker) {
+        let mut waiters = self.waiters.lock().unwrap();
+        if !waiters.iter().any(|registered| registered.will_wake(waker)) {
+            waiters.push(waker.clone());
+        }
+    }
+
+    fn finish(&self) {
+        self.finished.store(true, Ordering::Release);
+        let waiters = ::core::mem::take(&mut *self.waiters.lock().unwrap());
+        waiters.into_iter().for_each(Waker::wake);
+    }
+
+    pub(crate) fn state(&self) -> ShutdownState {
+        if self.finished.load(Ordering::Acquire) {
+            ShutdownState::Finished
+        } else if self.acknowledged.load(Ordering::Acquire) {
+            ShutdownState::Acknowledged
+        } else {
+            ShutdownState::Running
+        }
+    }
 }
 
 impl Debug for Program {
@@ -67,6 +325,7 @@ pub struct ProgramBuilder {
     start_action: Option<Box<dyn ActionTrait>>,
     stop_action: Option<Box<dyn ActionTrait>>,
     stop_timeout: Duration,
+    min_iteration_gap: Duration,
     shutdown_event_tag: Option<Tag>,
 }
 
@@ -78,6 +337,7 @@ impl ProgramBuilder {
             start_action: None,
             stop_action: None,
             stop_timeout: Default::default(),
+            min_iteration_gap: Duration::ZERO,
             shutdown_event_tag: None,
         }
     }
@@ -99,6 +359,13 @@ impl ProgramBuilder {
         self
     }
 
+    /// Sets the minimal time between the end of one iteration and the start of the next one, so back-to-back
+    /// iterations (e.g. of a program driven by frequent events) keep a bounded duty cycle. No gap by default.
+    pub fn with_min_iteration_gap(&mut self, gap: Duration) -> &mut Self {
+        self.min_iteration_gap = gap;
+        self
+    }
+
     pub fn with_shutdown_event(&mut self, name: Tag) -> &mut Self {
         self.shutdown_event_tag = Some(name);
         self
@@ -115,32 +382,86 @@ impl ProgramBuilder {
         }
 
         let mut shutdown_sync = None;
+        let mut shutdown_reason = ShutdownReasonReader::default();
 
         if let Some(tag) = self.shutdown_event_tag {
             if let Some(shutdown_event) = tag.find_in_collection(shutdown_events.iter()) {
                 shutdown_sync = shutdown_event.creator().borrow_mut().create_sync(config);
+                shutdown_reason = shutdown_event.reason();
             } else {
                 trace!("Shutdown event {} not found", tag.tracing_str());
                 return Err(CommonErrors::NotFound);
             }
         }
 
+        // All actions of the program are traced together, enabled until turned off by `OrchProgramManager::set_trace`
+        let trace = Arc::new(TraceControl::new());
+        let trace_switch = TraceSwitch::new(Arc::clone(&trace.actions));
+        let mut run_action = self.run_action.unwrap();
+        let mut start_action = self.start_action;
+        let mut stop_action = self.stop_action;
+        run_action.attach_trace_switch(&trace_switch);
+        start_action
+            .iter_mut()
+            .chain(stop_action.iter_mut())
+            .for_each(|action| action.attach_trace_switch(&trace_switch));
+
+        // Only the run action is stepped, the start and stop actions are executed at once
+        let stepping = Arc::new(StepControl::new());
+        run_action.attach_step_gate(&StepGate::new(Arc::clone(&stepping)));
+
         Ok(Program {
             name: self.name,
-            run_action: self.run_action.unwrap(),
-            start_action: self.start_action,
-            stop_action: self.stop_action,
+            run_action,
+            start_action,
+            stop_action,
             stop_timeout: self.stop_timeout,
+            min_iteration_gap: self.min_iteration_gap,
             shutdown_sync,
+            shutdown_reason,
+            trace,
+            stepping,
+            shutdown_progress: Arc::new(ShutdownProgress::new()),
         })
     }
 }
 
 impl Program {
+    pub(crate) fn start_action(&self) -> Option<&dyn ActionTrait> {
+        self.start_action.as_deref()
+    }
+
+    pub(crate) fn run_action(&self) -> &dyn ActionTrait {
+        self.run_action.as_ref()
+    }
+
+    pub(crate) fn stop_action(&self) -> Option<&dyn ActionTrait> {
+        self.stop_action.as_deref()
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// Tracing switch and sampling policy of this program, shared with `OrchProgramManager`.
+    pub(crate) fn trace_control(&self) -> Arc<TraceControl> {
+        Arc::clone(&self.trace)
+    }
+
+    /// Debug stepping state of this program, shared with `OrchProgramManager`.
+    pub(crate) fn step_control(&self) -> Arc<StepControl> {
+        Arc::clone(&self.stepping)
+    }
+
+    /// Shutdown progress of this program, shared with `OrchProgramManager`.
+    pub(crate) fn shutdown_progress(&self) -> Arc<ShutdownProgress> {
+        Arc::clone(&self.shutdown_progress)
+    }
+
+    fn is_traced(&self) -> bool {
+        self.trace.is_enabled()
+    }
+
     /// Execute the run action in an infinite loop.
     pub async fn run(&mut self) -> ActionResult {
         self.internal_run::<NoneMeter>(None, None).await
@@ -189,10 +510,19 @@ impl Program {
     }
 
     async fn internal_run<T: MeterTrait>(&mut self, n: Option<usize>, cycle: Option<Duration>) -> ActionResult {
+        let result = self.internal_run_impl::<T>(n, cycle).await;
+        self.shutdown_progress.finish();
+        result
+    }
+
+    async fn internal_run_impl<T: MeterTrait>(&mut self, n: Option<usize>, cycle: Option<Duration>) -> ActionResult {
         let iteration_count: usize = n.unwrap_or_default();
         let mut iteration = 0_usize;
         let mut shutdown_handle = self.create_shutdown_handle()?;
 
+        // Start and stop actions are traced like the first iteration.
+        self.trace.begin_iteration(0);
+
         // Stop execution if the start action is present and results in an error.
         self.run_start_action().await?;
 
@@ -200,6 +530,7 @@ impl Program {
 
         while n.is_none() || iteration < iteration_count {
             let start_time = Clock::now();
+            let sampling = self.trace.begin_iteration(iteration);
 
             let run_future = self.run_action.as_mut().try_execute();
             if run_future.is_err() {
@@ -215,8 +546,31 @@ impl Program {
 
             match join_either.await {
                 Ok(result) => match result.0 {
-                    JoinedHandle::Run => result.1?,
-                    JoinedHandle::Shutdown => break, // Not checking for ActionExecError on a Sync action.
+                    JoinedHandle::Run => {
+                        if self.is_traced() {
+                            let duration = start_time.elapsed();
+                            if sampling.is_sampled(iteration, duration, &result.1) {
+                                trace!(
+                                    "Program {}: iteration {} finished after {:?} with {:?}",
+                                    self.name,
+                                    iteration,
+                                    duration,
+                                    result.1
+                                );
+                            }
+                        }
+                        result.1?
+                    },
+                    JoinedHandle::Shutdown => {
+                        // Not checking for ActionExecError on a Sync action.
+                        self.shutdown_progress.acknowledged.store(true, Ordering::Release);
+                        info!(
+                            "Program {}: shutdown requested, reason {:?}",
+                            self.name,
+                            self.shutdown_reason.get()
+                        );
+                        break;
+                    },
                 },
                 Err(_) => {
                     trace!("Failed to execute run action or shutdown sync");
@@ -235,20 +589,31 @@ impl Program {
             }
 
             iteration += 1;
+
+            let is_last = n.is_some() && iteration >= iteration_count;
+            if !is_last && !self.min_iteration_gap.is_zero() {
+                sleep::sleep(self.min_iteration_gap).await;
+            }
         }
 
+        self.trace.begin_iteration(0);
         self.run_stop_action().await
     }
 
     async fn run_start_action(&mut self) -> ActionResult {
         if let Some(ref mut start_action) = self.start_action.take() {
-            match start_action.try_execute() {
+            let result = match start_action.try_execute() {
                 Ok(future) => match spawn_from_reusable(future).await {
                     Ok(result) => result,
                     Err(_) => Err(ActionExecError::Internal),
                 },
                 Err(_) => Err(ActionExecError::Internal),
+            };
+
+            if self.is_traced() {
+                trace!("Program {}: start action finished with {:?}", self.name, result);
             }
+            result
         } else {
             Ok(())
         }
@@ -256,13 +621,18 @@ impl Program {
 
     async fn run_stop_action(&mut self) -> ActionResult {
         if let Some(ref mut stop_action) = self.stop_action.take() {
-            match stop_action.try_execute() {
+            let result = match stop_action.try_execute() {
                 Ok(future) => match spawn_from_reusable(future).await {
                     Ok(result) => result,
                     Err(_) => Err(ActionExecError::Internal),
                 },
                 Err(_) => Err(ActionExecError::Internal),
+            };
+
+            if self.is_traced() {
+                trace!("Program {}: stop action finished with {:?}", self.name, result);
             }
+            result
         } else {
             Ok(())
         }
@@ -325,7 +695,7 @@ mod tests {
     use crate::{
         api::design::Design,
         common::DesignConfig,
-        prelude::{Invoke, InvokeResult},
+        prelude::{Invoke, InvokeResult, ReusableBoxFutureResult, SequenceBuilder},
     };
     use core::time::Duration;
     use kyron::testing;
@@ -399,4 +769,232 @@ mod tests {
         assert!(flags.run_called);
         assert!(flags.stop_called);
     }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn shutdown_progress_reports_finished_program() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let run_tag = design.register_invoke_fn("RunAction".into(), || Ok(())).unwrap();
+
+        let mut builder = ProgramBuilder::new("Program");
+        builder.with_run_action(Invoke::from_tag(&run_tag, design.config()));
+        let mut program = builder.build(&GrowableVec::default(), design.config()).unwrap();
+
+        let progress = program.shutdown_progress();
+        assert_eq!(progress.state(), ShutdownState::Running);
+
+        testing::mock::spawn(async move {
+            program.run_n(1).await.unwrap();
+        });
+
+        for _ in 0..10 {
+            testing::mock::runtime::step();
+        }
+
+        assert_eq!(progress.state(), ShutdownState::Finished);
+    }
+
+    #[test]
+    fn min_iteration_gap_is_passed_to_program() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let run_tag = design.register_invoke_fn("RunAction".into(), || Ok(())).unwrap();
+
+        let mut builder = ProgramBuilder::new("Program");
+        builder.with_run_action(Invoke::from_tag(&run_tag, design.config()));
+        let program = builder.build(&GrowableVec::default(), design.config()).unwrap();
+        assert_eq!(program.min_iteration_gap, Duration::ZERO);
+
+        let mut builder = ProgramBuilder::new("Program");
+        builder
+            .with_run_action(Invoke::from_tag(&run_tag, design.config()))
+            .with_min_iteration_gap(Duration::from_millis(5));
+        let program = builder.build(&GrowableVec::default(), design.config()).unwrap();
+        assert_eq!(program.min_iteration_gap, Duration::from_millis(5));
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn min_iteration_gap_is_waited_between_iterations() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let runs = Arc::new(Mutex::new(0));
+        let runs_clone = Arc::clone(&runs);
+        let run_tag = design
+            .register_invoke_fn("RunAction".into(), move || {
+                *runs_clone.lock().unwrap() += 1;
+                Ok(())
+            })
+            .unwrap();
+
+        let build_program = |design: &Design| {
+            let mut builder = ProgramBuilder::new("Program");
+            builder
+                .with_run_action(Invoke::from_tag(&run_tag, design.config()))
+                .with_min_iteration_gap(Duration::from_secs(3600));
+            builder.build(&GrowableVec::default(), design.config()).unwrap()
+        };
+
+        // No gap is waited after the last iteration
+        let mut program = build_program(&design);
+        let progress = program.shutdown_progress();
+        testing::mock::spawn(async move {
+            program.run_n(1).await.unwrap();
+        });
+        for _ in 0..10 {
+            testing::mock::runtime::step();
+        }
+        assert_eq!(*runs.lock().unwrap(), 1);
+        assert_eq!(progress.state(), ShutdownState::Finished);
+
+        // Second iteration waits for the gap to pass
+        let mut program = build_program(&design);
+        let progress = program.shutdown_progress();
+        testing::mock::spawn(async move {
+            program.run_n(2).await.unwrap();
+        });
+        for _ in 0..10 {
+            testing::mock::runtime::step();
+        }
+        assert_eq!(*runs.lock().unwrap(), 2);
+        assert_eq!(progress.state(), ShutdownState::Running);
+    }
+
+    #[test]
+    fn trace_switch_is_shared_with_program() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let run_tag = design.register_invoke_fn("RunAction".into(), || Ok(())).unwrap();
+
+        let mut builder = ProgramBuilder::new("Traced");
+        builder.with_run_action(Invoke::from_tag(&run_tag, design.config()));
+        let program = builder.build(&GrowableVec::default(), design.config()).unwrap();
+
+        assert!(program.is_traced());
+        program.trace_control().set_enabled(false);
+        assert!(!program.is_traced());
+        program.trace_control().set_enabled(true);
+        assert!(program.is_traced());
+    }
+
+    /// Action keeping the trace switch attached to it.
+    struct TraceProbe(Arc<Mutex<TraceSwitch>>);
+
+    impl ActionTrait for TraceProbe {
+        fn try_execute(&mut self) -> ReusableBoxFutureResult {
+            Err(CommonErrors::NoData)
+        }
+
+        fn name(&self) -> &'static str {
+            "TraceProbe"
+        }
+
+        fn dbg_fmt(&self, _nest: usize, _f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            Ok(())
+        }
+
+        fn attach_trace_switch(&mut self, switch: &TraceSwitch) {
+            *self.0.lock().unwrap() = switch.clone();
+        }
+    }
+
+    #[test]
+    fn untoggled_program_traces_its_actions() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let probe = Arc::new(Mutex::new(TraceSwitch::default()));
+
+        let mut builder = ProgramBuilder::new("Untoggled");
+        builder.with_run_action(Box::new(TraceProbe(Arc::clone(&probe))));
+        let program = builder.build(&GrowableVec::default(), design.config()).unwrap();
+
+        assert!(program.is_traced());
+        assert!(probe.lock().unwrap().is_on());
+    }
+
+    #[test]
+    fn trace_switch_is_attached_to_nested_actions() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let run_tag = design.register_invoke_fn("RunAction".into(), || Ok(())).unwrap();
+        let probe = Arc::new(Mutex::new(TraceSwitch::default()));
+        let stop_probe = Arc::new(Mutex::new(TraceSwitch::default()));
+
+        // Actions not attached to a program are always traced
+        assert!(probe.lock().unwrap().is_on());
+
+        let mut builder = ProgramBuilder::new("Traced");
+        builder
+            .with_run_action(
+                SequenceBuilder::new()
+                    .with_step(Invoke::from_tag(&run_tag, design.config()))
+                    .with_step(Box::new(TraceProbe(Arc::clone(&probe))))
+                    .build(),
+            )
+            .with_stop_action(Box::new(TraceProbe(Arc::clone(&stop_probe))), Duration::from_secs(1));
+        let program = builder.build(&GrowableVec::default(), design.config()).unwrap();
+
+        // Untoggled program keeps tracing its actions
+        assert!(probe.lock().unwrap().is_on());
+        assert!(stop_probe.lock().unwrap().is_on());
+        program.trace_control().set_enabled(false);
+        assert!(!probe.lock().unwrap().is_on());
+        assert!(!stop_probe.lock().unwrap().is_on());
+    }
+
+    #[test]
+    fn trace_sampling_survives_encoding() {
+        for sampling in [
+            TraceSampling::All,
+            TraceSampling::OneInN(7),
+            TraceSampling::OnlyOnError,
+            TraceSampling::LatencyAbove(Duration::from_millis(15)),
+        ] {
+            assert_eq!(TraceSampling::decode(sampling.encode()), sampling);
+        }
+    }
+
+    #[test]
+    fn trace_sampling_switches_tracing_of_actions_per_iteration() {
+        let control = TraceControl::new();
+        let switch = TraceSwitch::new(Arc::clone(&control.actions));
+        assert!(switch.is_on());
+
+        control.set_sampling(TraceSampling::OneInN(3));
+        assert_eq!(control.begin_iteration(0), TraceSampling::OneInN(3));
+        assert!(switch.is_on());
+        control.begin_iteration(1);
+        assert!(!switch.is_on());
+        control.begin_iteration(3);
+        assert!(switch.is_on());
+
+        // Only summaries are traced for policies deciding after the iteration
+        control.set_sampling(TraceSampling::OnlyOnError);
+        assert!(!switch.is_on());
+        control.begin_iteration(0);
+        assert!(!switch.is_on());
+
+        control.set_sampling(TraceSampling::All);
+        control.set_enabled(false);
+        control.begin_iteration(0);
+        assert!(!switch.is_on());
+    }
+
+    #[test]
+    fn trace_sampling_selects_logged_iterations() {
+        let ok: ActionResult = Ok(());
+        let err: ActionResult = Err(ActionExecError::Internal);
+        let fast = Duration::from_millis(1);
+        let slow = Duration::from_millis(20);
+
+        assert!(TraceSampling::All.is_sampled(7, fast, &ok));
+
+        let one_in_three = TraceSampling::OneInN(3);
+        assert!(one_in_three.is_sampled(0, fast, &ok));
+        assert!(!one_in_three.is_sampled(1, fast, &ok));
+        assert!(!one_in_three.is_sampled(2, fast, &ok));
+        assert!(one_in_three.is_sampled(3, fast, &ok));
+
+        assert!(!TraceSampling::OnlyOnError.is_sampled(0, slow, &ok));
+        assert!(TraceSampling::OnlyOnError.is_sampled(0, fast, &err));
+
+        let latency = TraceSampling::LatencyAbove(Duration::from_millis(10));
+        assert!(!latency.is_sampled(0, fast, &err));
+        assert!(latency.is_sampled(0, slow, &ok));
+    }
 }
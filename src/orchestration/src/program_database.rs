@@ -11,15 +11,19 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 
-use crate::actions::ifelse::{IfElse, IfElseCondition};
+use crate::actions::deadline::{DeadlineHandler, DeadlineMonitor};
+use crate::actions::ifelse::{ConditionFn, IfElse, IfElseCondition};
+use crate::actions::switch::{SwitchCondition, SwitchConditionFn};
 use crate::common::orch_tag::OrchestrationTag;
 use crate::common::tag::Tag;
 use crate::common::DesignConfig;
 use crate::events::events_provider::EventActionType;
 use crate::{
     actions::{
-        action::ActionTrait,
-        invoke::{Invoke, InvokeFunctionType, InvokeResult},
+        action::{ActionTrait, ExecutionStrategy, UserErrValue},
+        invoke::{AsyncInvoke, Invoke, InvokeDryRun, InvokeFunctionType, InvokeResult},
+        retry::{RetryBackoff, RetryBuilder},
+        timeout::TimeoutBuilder,
     },
     events::events_provider::EventCreator,
 };
@@ -32,24 +36,84 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use ::core::{cell::RefCell, fmt::Debug, future::Future};
+use ::core::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    fmt::Debug,
+    future::Future,
+    time::Duration,
+};
+use kyron_foundation::containers::growable_vec::GrowableVec;
+
+/// Value passed from a producer invoke to a consumer invoke, see [`ProgramDatabase::register_invoke_producer`].
+type PayloadSlot<T> = Arc<Mutex<Option<T>>>;
+
+/// Payload slots of the producers. Slots are created for each program instance, so programs created from the same
+/// design do not consume values produced by each other, see [`ProgramDatabase::begin_program`].
+#[derive(Default)]
+struct PayloadSlots {
+    producers: GrowableVec<(Tag, TypeId)>,
+    slots: GrowableVec<(Tag, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl PayloadSlots {
+    /// Returns the slot of the producer in the current program instance, creates it if not used yet.
+    fn slot<T: Send + 'static>(&mut self, producer_tag: Tag) -> PayloadSlot<T> {
+        let existing = self
+            .slots
+            .iter()
+            .find(|(registered, _)| *registered == producer_tag)
+            .and_then(|(_, slot)| Arc::clone(slot).downcast::<Mutex<Option<T>>>().ok());
+
+        existing.unwrap_or_else(|| {
+            let slot: PayloadSlot<T> = Arc::new(Mutex::new(None));
+            self.slots
+                .push((producer_tag, Arc::clone(&slot) as Arc<dyn Any + Send + Sync>));
+            slot
+        })
+    }
+}
 
 pub(crate) struct ActionProvider {
     data: FlatMap<Tag, ActionData>,
+    deadline_handler: Option<DeadlineHandler>,
 }
 
 impl ActionProvider {
     pub(crate) fn new(config: DesignConfig) -> Self {
         Self {
             data: FlatMap::new(config.db_params.registration_capacity),
+            deadline_handler: None,
         }
     }
 
     pub(crate) fn provide_invoke(&mut self, tag: Tag, config: &DesignConfig) -> Option<Box<dyn ActionTrait>> {
-        self.data.get_ref(&tag).and_then(|data| match data {
-            ActionData::Invoke(invoke_data) => Some((invoke_data.generator)(tag, invoke_data.worker_id, config)),
+        let (invoke, strategy, policy) = self.data.get_ref(&tag).and_then(|data| match data {
+            ActionData::Invoke(invoke_data) => match config.invoke_dry_run {
+                // An invoke bound to a worker spawns its work by itself, so the monitor does not spawn it again
+                InvokeDryRun::Disabled => Some((
+                    (invoke_data.generator)(tag, invoke_data.worker_id, config),
+                    match invoke_data.worker_id {
+                        Some(_) => ExecutionStrategy::Inline,
+                        None => ExecutionStrategy::Spawn,
+                    },
+                    invoke_data.policy,
+                )),
+                InvokeDryRun::NoOp => Some((
+                    Invoke::stub(tag, Duration::ZERO, config),
+                    ExecutionStrategy::Spawn,
+                    invoke_data.policy,
+                )),
+                InvokeDryRun::Delay(delay) => Some((
+                    Invoke::stub(tag, delay, config),
+                    ExecutionStrategy::Spawn,
+                    invoke_data.policy,
+                )),
+            },
             _ => None,
-        })
+        })?;
+
+        Some(policy.apply(self.monitored(tag, invoke, strategy, config), config))
     }
 
     pub(crate) fn provide_event(
@@ -58,13 +122,15 @@ impl ActionProvider {
         t: EventActionType,
         config: &DesignConfig,
     ) -> Option<Box<dyn ActionTrait>> {
-        self.data.get_ref(&tag).and_then(|data| match data {
+        let event = self.data.get_ref(&tag).and_then(|data| match data {
             ActionData::Event(event_data) => match t {
                 EventActionType::Trigger => event_data.creator()?.borrow_mut().create_trigger(config),
                 EventActionType::Sync => event_data.creator()?.borrow_mut().create_sync(config),
             },
             _ => None,
-        })
+        })?;
+
+        Some(event)
     }
 
     pub(crate) fn provide_if_else(
@@ -74,9 +140,42 @@ impl ActionProvider {
         false_branch: Box<dyn ActionTrait>,
         config: &DesignConfig,
     ) -> Option<Box<dyn ActionTrait>> {
-        self.data.get_ref(&tag).and_then(|data| match data {
+        let if_else = self.data.get_ref(&tag).and_then(|data| match data {
             ActionData::IfElse(ifelse_data) => Some((ifelse_data.generator)(true_branch, false_branch, config)),
             _ => None,
+        })?;
+
+        Some(if_else)
+    }
+
+    /// Wraps the invoke into deadline monitor if a deadline is configured for it, see `DesignConfig::deadline`.
+    /// Other actions are monitored explicitly with `DeadlineBuilder`.
+    fn monitored(
+        &self,
+        tag: Tag,
+        invoke: Box<dyn ActionTrait>,
+        strategy: ExecutionStrategy,
+        config: &DesignConfig,
+    ) -> Box<dyn ActionTrait> {
+        match config.deadline {
+            Some(deadline) => {
+                DeadlineMonitor::wrap(tag, invoke, deadline, strategy, self.deadline_handler.clone(), config)
+            },
+            None => invoke,
+        }
+    }
+
+    pub(crate) fn provide_condition(&mut self, tag: Tag) -> Option<ConditionFn> {
+        self.data.get_ref(&tag).and_then(|data| match data {
+            ActionData::IfElse(ifelse_data) => Some(Arc::clone(&ifelse_data.condition)),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn provide_switch_condition(&mut self, tag: Tag) -> Option<SwitchConditionFn> {
+        self.data.get_ref(&tag).and_then(|data| match data {
+            ActionData::Switch(switch_data) => Some(Arc::clone(&switch_data.condition)),
+            _ => None,
         })
     }
 }
@@ -89,6 +188,7 @@ impl Debug for ActionProvider {
 
 pub struct ProgramDatabase {
     action_provider: Rc<RefCell<ActionProvider>>,
+    payload_slots: Rc<RefCell<PayloadSlots>>,
 }
 
 impl ProgramDatabase {
@@ -96,6 +196,7 @@ impl ProgramDatabase {
     pub fn new(config: DesignConfig) -> Self {
         Self {
             action_provider: Rc::new(RefCell::new(ActionProvider::new(config))),
+            payload_slots: Rc::new(RefCell::new(PayloadSlots::default())),
         }
     }
 
@@ -107,6 +208,7 @@ impl ProgramDatabase {
             tag,
             ActionData::Invoke(InvokeData {
                 worker_id: None,
+                policy: InvokePolicy::default(),
                 generator: Rc::new(
                     move |tag: Tag, worker_id: Option<UniqueWorkerId>, config: &DesignConfig| {
                         Invoke::from_fn(tag, action, worker_id, config)
@@ -132,6 +234,7 @@ impl ProgramDatabase {
             tag,
             ActionData::Invoke(InvokeData {
                 worker_id: None,
+                policy: InvokePolicy::default(),
                 generator: Rc::new(
                     move |tag: Tag, worker_id: Option<UniqueWorkerId>, config: &DesignConfig| {
                         Invoke::from_async(tag, action.clone(), worker_id, config)
@@ -145,6 +248,133 @@ impl ProgramDatabase {
         }
     }
 
+    /// Registers a trait object as an async invoke action that can be created multiple times.
+    pub fn register_invoke_dyn(
+        &self,
+        tag: Tag,
+        action: Box<dyn AsyncInvoke>,
+    ) -> Result<OrchestrationTag, CommonErrors> {
+        let action: Arc<dyn AsyncInvoke> = Arc::from(action);
+        self.register_invoke_async(tag, move || {
+            let action = Arc::clone(&action);
+            async move { action.execute().await }
+        })
+    }
+
+    /// Registers a function producing a value of type `T` as an invoke action. Each execution stores the value in the
+    /// payload slot of the action, replacing a value not consumed yet. The value is taken by the invoke registered with
+    /// [`ProgramDatabase::register_invoke_consumer`] for this producer, e.g. the next step of a sequence. Each program
+    /// instance has its own slot, so values are passed only between actions of the same program.
+    pub fn register_invoke_producer<T, P>(&self, tag: Tag, producer: P) -> Result<OrchestrationTag, CommonErrors>
+    where
+        T: Send + 'static,
+        P: Fn() -> Result<T, UserErrValue> + Send + Sync + 'static,
+    {
+        let producer = Arc::new(producer);
+        let payload_slots = Rc::clone(&self.payload_slots);
+
+        let orch_tag = self.register_invoke_generator(
+            tag,
+            Rc::new(
+                move |tag: Tag, worker_id: Option<UniqueWorkerId>, config: &DesignConfig| {
+                    let producer = Arc::clone(&producer);
+                    let slot = payload_slots.borrow_mut().slot::<T>(tag);
+                    let action = move || {
+                        let producer = Arc::clone(&producer);
+                        let slot = Arc::clone(&slot);
+                        async move { producer().map(|value| *slot.lock().unwrap() = Some(value)) }
+                    };
+                    Invoke::from_async(tag, action, worker_id, config)
+                },
+            ),
+        )?;
+
+        self.payload_slots.borrow_mut().producers.push((tag, TypeId::of::<T>()));
+        Ok(orch_tag)
+    }
+
+    /// Registers a function consuming the value of the producer registered under `producer_tag` as an invoke action.
+    /// If the producer has not stored a value since the last consumption, the function is not called and the action
+    /// fails with `no_value_err`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommonErrors::NotFound` if there is no producer under `producer_tag`, `CommonErrors::GenericError` if
+    /// the producer produces values of another type, or any error of invoke registration.
+    pub fn register_invoke_consumer<T, C>(
+        &self,
+        tag: Tag,
+        producer_tag: Tag,
+        consumer: C,
+        no_value_err: UserErrValue,
+    ) -> Result<OrchestrationTag, CommonErrors>
+    where
+        T: Send + 'static,
+        C: Fn(T) -> InvokeResult + Send + Sync + 'static,
+    {
+        let (_, type_id) = *self
+            .payload_slots
+            .borrow()
+            .producers
+            .iter()
+            .find(|(registered, _)| *registered == producer_tag)
+            .ok_or(CommonErrors::NotFound)?;
+        if type_id != TypeId::of::<T>() {
+            return Err(CommonErrors::GenericError);
+        }
+
+        let consumer = Arc::new(consumer);
+        let payload_slots = Rc::clone(&self.payload_slots);
+
+        self.register_invoke_generator(
+            tag,
+            Rc::new(
+                move |tag: Tag, worker_id: Option<UniqueWorkerId>, config: &DesignConfig| {
+                    let consumer = Arc::clone(&consumer);
+                    let slot = payload_slots.borrow_mut().slot::<T>(producer_tag);
+                    let action = move || {
+                        let consumer = Arc::clone(&consumer);
+                        let slot = Arc::clone(&slot);
+                        async move {
+                            let value = slot.lock().unwrap().take();
+                            match value {
+                                Some(value) => consumer(value),
+                                None => Err(no_value_err),
+                            }
+                        }
+                    };
+                    Invoke::from_async(tag, action, worker_id, config)
+                },
+            ),
+        )
+    }
+
+    /// Starts creation of a new program instance, following producers and consumers get new payload slots.
+    pub(crate) fn begin_program(&self) {
+        self.payload_slots.borrow_mut().slots = GrowableVec::default();
+    }
+
+    fn register_invoke_generator(
+        &self,
+        tag: Tag,
+        generator: Rc<InvokeGenerator>,
+    ) -> Result<OrchestrationTag, CommonErrors> {
+        let mut ap = self.action_provider.borrow_mut();
+
+        match ap.data.insert(
+            tag,
+            ActionData::Invoke(InvokeData {
+                worker_id: None,
+                policy: InvokePolicy::default(),
+                generator,
+            }),
+        ) {
+            Ok(_) => Ok(OrchestrationTag::new(tag, Rc::clone(&self.action_provider))),
+            Err(FlatMapError::IsFull) => Err(CommonErrors::NoSpaceLeft),
+            Err(FlatMapError::KeyAlreadyExists) => Err(CommonErrors::AlreadyDone),
+        }
+    }
+
     /// Registers a method on an object as an invoke action.
     pub fn register_invoke_method<T: 'static + Send>(
         &self,
@@ -158,6 +388,7 @@ impl ProgramDatabase {
             tag,
             ActionData::Invoke(InvokeData {
                 worker_id: None,
+                policy: InvokePolicy::default(),
                 generator: Rc::new(
                     move |tag: Tag, worker_id: Option<UniqueWorkerId>, config: &DesignConfig| {
                         Invoke::from_method(tag, Arc::clone(&object), method, worker_id, config)
@@ -189,6 +420,7 @@ impl ProgramDatabase {
             tag,
             ActionData::Invoke(InvokeData {
                 worker_id: None,
+                policy: InvokePolicy::default(),
                 generator: Rc::new(
                     move |tag: Tag, worker_id: Option<UniqueWorkerId>, config: &DesignConfig| {
                         Invoke::from_method_async(tag, Arc::clone(&object), method.clone(), worker_id, config)
@@ -226,10 +458,12 @@ impl ProgramDatabase {
         C: IfElseCondition + Send + Sync + 'static,
     {
         let mut ap = self.action_provider.borrow_mut();
+        let evaluated = Arc::clone(&condition);
 
         match ap.data.insert(
             tag,
             ActionData::IfElse(IfElseData {
+                condition: Arc::new(move || evaluated.compute()),
                 generator: Rc::new(
                     move |true_branch: Box<dyn ActionTrait>,
                           false_branch: Box<dyn ActionTrait>,
@@ -255,10 +489,12 @@ impl ProgramDatabase {
         C: IfElseCondition + Send + 'static,
     {
         let mut ap = self.action_provider.borrow_mut();
+        let evaluated = Arc::clone(&condition);
 
         match ap.data.insert(
             tag,
             ActionData::IfElse(IfElseData {
+                condition: Arc::new(move || evaluated.lock().unwrap().compute()),
                 generator: Rc::new(
                     move |true_branch: Box<dyn ActionTrait>,
                           false_branch: Box<dyn ActionTrait>,
@@ -274,6 +510,29 @@ impl ProgramDatabase {
         }
     }
 
+    /// Registers an arc condition for a Switch action.
+    pub fn register_switch_condition<C>(
+        &mut self,
+        tag: Tag,
+        condition: Arc<C>,
+    ) -> Result<OrchestrationTag, CommonErrors>
+    where
+        C: SwitchCondition + Send + Sync + 'static,
+    {
+        let mut ap = self.action_provider.borrow_mut();
+
+        match ap.data.insert(
+            tag,
+            ActionData::Switch(SwitchData {
+                condition: Arc::new(move || condition.compute()),
+            }),
+        ) {
+            Ok(_) => Ok(OrchestrationTag::new(tag, Rc::clone(&self.action_provider))),
+            Err(FlatMapError::IsFull) => Err(CommonErrors::NoSpaceLeft),
+            Err(FlatMapError::KeyAlreadyExists) => Err(CommonErrors::AlreadyDone),
+        }
+    }
+
     /// Returns an `OrchestrationTag` for an action previously registered with the given tag.
     ///
     /// # Returns
@@ -311,6 +570,55 @@ impl ProgramDatabase {
         }
     }
 
+    pub(crate) fn set_invoke_timeout(&mut self, tag: Tag, duration: Duration) -> Result<(), CommonErrors> {
+        self.update_invoke_policy(tag, |policy| match policy.timeout {
+            Some(_) => Err(CommonErrors::AlreadyDone),
+            None => {
+                trace!("Setting timeout {:?} for invoke action with tag {:?}", duration, tag);
+                policy.timeout = Some(duration);
+                Ok(())
+            },
+        })
+    }
+
+    pub(crate) fn set_invoke_retry(
+        &mut self,
+        tag: Tag,
+        attempts: u32,
+        backoff: RetryBackoff,
+    ) -> Result<(), CommonErrors> {
+        self.update_invoke_policy(tag, |policy| match policy.retry {
+            Some(_) => Err(CommonErrors::AlreadyDone),
+            None => {
+                trace!(
+                    "Setting {} retry attempts for invoke action with tag {:?}",
+                    attempts,
+                    tag
+                );
+                policy.retry = Some((attempts, backoff));
+                Ok(())
+            },
+        })
+    }
+
+    fn update_invoke_policy<F>(&mut self, tag: Tag, update: F) -> Result<(), CommonErrors>
+    where
+        F: FnOnce(&mut InvokePolicy) -> Result<(), CommonErrors>,
+    {
+        match self.action_provider.borrow_mut().data.get_mut_ref(&tag) {
+            Some(ActionData::Invoke(invoke_data)) => update(&mut invoke_data.policy),
+            _ => Err(CommonErrors::NotFound),
+        }
+    }
+
+    pub(crate) fn set_deadline_handler(&self, handler: DeadlineHandler) {
+        self.action_provider.borrow_mut().deadline_handler = Some(handler);
+    }
+
+    pub(crate) fn deadline_handler(&self) -> Option<DeadlineHandler> {
+        self.action_provider.borrow().deadline_handler.clone()
+    }
+
     pub(crate) fn set_creator_for_events(
         &self,
         creator: EventCreator,
@@ -343,9 +651,34 @@ impl Default for ProgramDatabase {
 type InvokeGenerator = dyn Fn(Tag, Option<UniqueWorkerId>, &DesignConfig) -> Box<dyn ActionTrait>;
 type IfElseGenerator = dyn Fn(Box<dyn ActionTrait>, Box<dyn ActionTrait>, &DesignConfig) -> Box<dyn ActionTrait>;
 
+/// Timeout and retry policy attached to an invoke by the deployment.
+#[derive(Clone, Copy, Default)]
+struct InvokePolicy {
+    timeout: Option<Duration>,
+    retry: Option<(u32, RetryBackoff)>,
+}
+
+impl InvokePolicy {
+    /// Wraps the invoke into `Timeout` applied to each attempt and then into `Retry`, as configured.
+    fn apply(&self, invoke: Box<dyn ActionTrait>, config: &DesignConfig) -> Box<dyn ActionTrait> {
+        let invoke: Box<dyn ActionTrait> = match self.timeout {
+            Some(duration) => TimeoutBuilder::new(duration, invoke).build_with_config(config),
+            None => invoke,
+        };
+
+        match self.retry {
+            Some((attempts, backoff)) => RetryBuilder::new(attempts, invoke)
+                .with_backoff(backoff)
+                .build_with_config(config),
+            None => invoke,
+        }
+    }
+}
+
 #[derive(Clone)]
 struct InvokeData {
     worker_id: Option<UniqueWorkerId>,
+    policy: InvokePolicy,
     // Rc needed for Clone
     generator: Rc<InvokeGenerator>,
 }
@@ -373,15 +706,22 @@ impl EventData {
 
 #[derive(Clone)]
 struct IfElseData {
+    condition: ConditionFn,
     // Rc needed for Clone
     generator: Rc<IfElseGenerator>,
 }
 
+#[derive(Clone)]
+struct SwitchData {
+    condition: SwitchConditionFn,
+}
+
 #[derive(Clone)]
 enum ActionData {
     Invoke(InvokeData),
     Event(EventData),
     IfElse(IfElseData),
+    Switch(SwitchData),
 }
 
 #[cfg(test)]
@@ -389,7 +729,7 @@ enum ActionData {
 mod tests {
     use super::*;
     use crate::{
-        actions::action::ActionExecError,
+        actions::{action::ActionExecError, invoke::InvokeFuture},
         events::events_provider::{EventCreatorTrait, ShutdownNotifier},
         testing::OrchTestingPoller,
     };
@@ -430,6 +770,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_register_invoke_dyn() {
+        let config = DesignConfig::default();
+        let pd = ProgramDatabase::default();
+
+        struct Component {
+            code: u64,
+        }
+
+        impl AsyncInvoke for Component {
+            fn execute(&self) -> InvokeFuture {
+                let code = self.code;
+                Box::pin(async move { InvokeResult::Err(code.into()) })
+            }
+        }
+
+        let tag = pd
+            .register_invoke_dyn("tag1".into(), Box::new(Component { code: 0xcafe }))
+            .unwrap();
+        assert!(pd
+            .register_invoke_dyn("tag1".into(), Box::new(Component { code: 0xbeef }))
+            .is_err());
+
+        let mut invoke = Invoke::from_tag(&tag, &config);
+        let mut poller = OrchTestingPoller::new(invoke.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(0xcafe_u64.into())))
+        );
+    }
+
+    #[test]
+    fn test_register_invoke_producer_and_consumer() {
+        let config = DesignConfig::default();
+        let pd = ProgramDatabase::default();
+
+        let producer = pd
+            .register_invoke_producer("produce".into(), || Ok([7_u8; 32]))
+            .unwrap();
+        let consumer = pd
+            .register_invoke_consumer(
+                "consume".into(),
+                "produce".into(),
+                |frame: [u8; 32]| Err(u64::from(frame[0]).into()),
+                0xdead_u64.into(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            pd.register_invoke_consumer("other".into(), "missing".into(), |_: u32| Ok(()), 0xdead_u64.into())
+                .err(),
+            Some(CommonErrors::NotFound)
+        );
+        assert_eq!(
+            pd.register_invoke_consumer("other".into(), "produce".into(), |_: u32| Ok(()), 0xdead_u64.into())
+                .err(),
+            Some(CommonErrors::GenericError)
+        );
+
+        let mut produce = Invoke::from_tag(&producer, &config);
+        let mut consume = Invoke::from_tag(&consumer, &config);
+
+        // Nothing produced yet, so the consumer reports the missing value.
+        let mut poller = OrchTestingPoller::new(consume.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(0xdead_u64.into())))
+        );
+
+        let mut poller = OrchTestingPoller::new(produce.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        let mut poller = OrchTestingPoller::new(consume.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(7_u64.into())))
+        );
+    }
+
+    #[test]
+    fn test_payload_slots_are_separate_per_program() {
+        let config = DesignConfig::default();
+        let pd = ProgramDatabase::default();
+
+        let producer = pd.register_invoke_producer("produce".into(), || Ok(7_u64)).unwrap();
+        let consumer = pd
+            .register_invoke_consumer(
+                "consume".into(),
+                "produce".into(),
+                |value: u64| Err(value.into()),
+                0xdead_u64.into(),
+            )
+            .unwrap();
+
+        pd.begin_program();
+        let mut first_produce = Invoke::from_tag(&producer, &config);
+        let mut first_consume = Invoke::from_tag(&consumer, &config);
+
+        pd.begin_program();
+        let mut second_consume = Invoke::from_tag(&consumer, &config);
+
+        let mut poller = OrchTestingPoller::new(first_produce.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+
+        // The value is visible only to the consumer of the same program.
+        let mut poller = OrchTestingPoller::new(second_consume.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(0xdead_u64.into())))
+        );
+        let mut poller = OrchTestingPoller::new(first_consume.try_execute().unwrap());
+        assert_eq!(
+            poller.poll(),
+            Poll::Ready(Err(ActionExecError::UserError(7_u64.into())))
+        );
+    }
+
     #[test]
     fn test_register_invoke_async() {
         let config = DesignConfig::default();
@@ -774,4 +1230,62 @@ mod tests {
 
         assert!(Rc::ptr_eq(&c1.unwrap(), &c2.unwrap()));
     }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn invoke_with_retry_policy_is_executed_again_after_failure() {
+        use ::core::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        fn fails_once() -> InvokeResult {
+            match CALLS.fetch_add(1, Ordering::Relaxed) {
+                0 => Err(0xcafe_u64.into()),
+                _ => Ok(()),
+            }
+        }
+
+        let config = DesignConfig::default();
+        let mut pd = ProgramDatabase::default();
+        let tag = pd.register_invoke_fn("tag1".into(), fails_once).unwrap();
+
+        assert_eq!(pd.set_invoke_retry("tag1".into(), 2, RetryBackoff::None), Ok(()));
+        assert_eq!(
+            pd.set_invoke_retry("tag1".into(), 3, RetryBackoff::None),
+            Err(CommonErrors::AlreadyDone)
+        );
+        assert_eq!(
+            pd.set_invoke_retry("unknown".into(), 2, RetryBackoff::None),
+            Err(CommonErrors::NotFound)
+        );
+
+        let mut invoke = Invoke::from_tag(&tag, &config);
+        assert_eq!(invoke.name(), "Retry");
+        let mut poller = OrchTestingPoller::new(invoke.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Ok(())));
+        assert_eq!(CALLS.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    #[ensure_clear_mock_runtime]
+    fn invoke_with_timeout_policy_fails_when_not_completed_in_time() {
+        async fn never_completes() -> InvokeResult {
+            ::core::future::pending().await
+        }
+
+        let config = DesignConfig::default();
+        let mut pd = ProgramDatabase::default();
+        let tag = pd.register_invoke_async("tag1".into(), never_completes).unwrap();
+
+        assert_eq!(pd.set_invoke_timeout("tag1".into(), Duration::ZERO), Ok(()));
+        assert_eq!(
+            pd.set_invoke_timeout("tag1".into(), Duration::ZERO),
+            Err(CommonErrors::AlreadyDone)
+        );
+
+        let mut invoke = Invoke::from_tag(&tag, &config);
+        assert_eq!(invoke.name(), "Timeout");
+        let mut poller = OrchTestingPoller::new(invoke.try_execute().unwrap());
+        assert_eq!(poller.poll(), Poll::Ready(Err(ActionExecError::Timeout)));
+    }
 }
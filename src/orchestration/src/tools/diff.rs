@@ -0,0 +1,237 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use ::core::fmt::{Debug, Display, Formatter};
+
+use crate::{actions::action::ActionTrait, program::Program};
+
+/// Single line of a structural diff between two programs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiffLine {
+    /// Line present in both programs.
+    Same(String),
+    /// Line present only in the second program.
+    Added(String),
+    /// Line present only in the first program.
+    Removed(String),
+}
+
+/// Structural diff of two programs' action trees, see [`diff`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgramDiff {
+    lines: Vec<DiffLine>,
+}
+
+impl ProgramDiff {
+    /// Returns `true` if both programs have the same structure.
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(|line| matches!(line, DiffLine::Same(_)))
+    }
+
+    /// All lines of the diff, including the unchanged ones.
+    pub fn lines(&self) -> &[DiffLine] {
+        &self.lines
+    }
+
+    /// Lines only present in the second program.
+    pub fn added(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            DiffLine::Added(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Lines only present in the first program.
+    pub fn removed(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().filter_map(|line| match line {
+            DiffLine::Removed(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl Display for ProgramDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::core::fmt::Result {
+        self.lines.iter().try_for_each(|line| match line {
+            DiffLine::Same(text) => writeln!(f, "  {}", text),
+            DiffLine::Added(text) => writeln!(f, "+ {}", text),
+            DiffLine::Removed(text) => writeln!(f, "- {}", text),
+        })
+    }
+}
+
+///
+/// Produces a structural diff of action trees (start, run and stop actions) of two programs.
+/// Reordered steps show up as a removal and an addition.
+///
+/// Useful to check that a program built from a config file has the same structure as the reference built in code.
+///
+pub fn diff(program_a: &Program, program_b: &Program) -> ProgramDiff {
+    let a = structure_lines(program_a);
+    let b = structure_lines(program_b);
+
+    ProgramDiff {
+        lines: diff_lines(&a, &b),
+    }
+}
+
+struct ActionTree<'a>(&'a dyn ActionTrait);
+
+impl Debug for ActionTree<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::core::fmt::Result {
+        self.0.dbg_fmt(1, f)
+    }
+}
+
+fn structure_lines(program: &Program) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    let mut push_section = |header: &str, action: Option<&dyn ActionTrait>| {
+        lines.push(header.to_string());
+        if let Some(action) = action {
+            lines.extend(format!("{:?}", ActionTree(action)).lines().map(str::to_string));
+        }
+    };
+
+    push_section("Start:", program.start_action());
+    push_section("Body:", Some(program.run_action()));
+    push_section("Stop:", program.stop_action());
+
+    lines
+}
+
+/// Line diff based on the longest common subsequence. Action trees are small, so quadratic cost is fine here.
+fn diff_lines(a: &[String], b: &[String]) -> Vec<DiffLine> {
+    // lcs[i][j] is the LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0_usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            result.push(DiffLine::Same(a[i].clone()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push(DiffLine::Removed(a[i].clone()));
+            i += 1;
+        } else {
+            result.push(DiffLine::Added(b[j].clone()));
+            j += 1;
+        }
+    }
+
+    result.extend(a[i..].iter().cloned().map(DiffLine::Removed));
+    result.extend(b[j..].iter().cloned().map(DiffLine::Added));
+    result
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::{
+            catch::{CatchBuilder, ErrorFilter, ErrorFilters},
+            sequence::SequenceBuilder,
+        },
+        api::design::Design,
+        common::DesignConfig,
+        program::ProgramBuilder,
+        testing::MockAction,
+    };
+    use kyron_foundation::containers::growable_vec::GrowableVec;
+
+    fn to_lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn diff_lines_reports_added_removed_and_same() {
+        let a = to_lines(&["seq", "a", "b", "c"]);
+        let b = to_lines(&["seq", "a", "c", "d"]);
+
+        assert_eq!(
+            diff_lines(&a, &b),
+            vec![
+                DiffLine::Same("seq".into()),
+                DiffLine::Same("a".into()),
+                DiffLine::Removed("b".into()),
+                DiffLine::Same("c".into()),
+                DiffLine::Added("d".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_of_programs() {
+        let build = |steps: usize| {
+            let mut sequence = SequenceBuilder::new();
+            for _ in 0..steps {
+                sequence.with_step(Box::new(MockAction::<()>::default()));
+            }
+
+            let mut builder = ProgramBuilder::new("Program");
+            builder.with_run_action(sequence.build());
+            builder.build(&GrowableVec::default(), &Default::default()).unwrap()
+        };
+
+        assert!(diff(&build(2), &build(2)).is_empty());
+
+        let changed = diff(&build(2), &build(3));
+        assert!(!changed.is_empty());
+        assert_eq!(changed.added().count(), 2); // extra step marker and the step itself
+        assert_eq!(changed.removed().count(), 0);
+    }
+
+    #[test]
+    fn diff_of_programs_with_catch() {
+        let design = Design::new("Design".into(), DesignConfig::default());
+        let build = |filters: ErrorFilters| {
+            let mut sequence = SequenceBuilder::new();
+            sequence.with_step(Box::new(MockAction::<()>::default()));
+
+            let catch = CatchBuilder::new(filters, sequence.build())
+                .catch_recoverable(|_| true)
+                .build(&design);
+
+            let mut builder = ProgramBuilder::new("Program");
+            builder.with_run_action(catch);
+            builder.build(&GrowableVec::default(), &Default::default()).unwrap()
+        };
+
+        assert!(diff(
+            &build(ErrorFilter::UserErrors.into()),
+            &build(ErrorFilter::UserErrors.into())
+        )
+        .is_empty());
+
+        // Only the line of the Catch changes, the guarded sequence stays the same
+        let changed = diff(
+            &build(ErrorFilter::UserErrors.into()),
+            &build(ErrorFilter::UserErrors | ErrorFilter::Timeouts),
+        );
+        assert_eq!(changed.added().count(), 1);
+        assert_eq!(changed.removed().count(), 1);
+        assert!(changed.added().all(|line| line.contains("Catch")));
+    }
+}
@@ -0,0 +1,323 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+
+use crate::actions::graph::NodeId;
+use ::core::cmp::Reverse;
+use ::core::fmt::{Display, Formatter};
+use ::core::time::Duration;
+
+/// Invokes with their measured costs and the dependencies between them, the input of [`suggest_partitioning`].
+/// Nodes and edges follow `LocalGraphActionBuilder`: an edge from a node to another means the other node starts once
+/// the node finished. Invokes without edges are independent.
+#[derive(Clone, Debug, Default)]
+pub struct CostGraph {
+    invokes: Vec<(String, Duration)>,
+    edges: Vec<Vec<NodeId>>,
+}
+
+impl CostGraph {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the invoke with `tag` and its measured execution time, returning its NodeId.
+    pub fn add_invoke(&mut self, tag: &str, cost: Duration) -> NodeId {
+        self.invokes.push((tag.to_string(), cost));
+        self.edges.push(Vec::new());
+        self.invokes.len() - 1
+    }
+
+    /// Adds directed edges from the node with `node_id` to each node in `edges`.
+    /// Returns a mutable reference to self.
+    /// Panics if `node_id` or any edge in `edges` is invalid, or if there are self-loop edges.
+    pub fn add_edges(&mut self, node_id: NodeId, edges: &[NodeId]) -> &mut Self {
+        assert!(node_id < self.invokes.len(), "Invalid node ID.");
+        for edge in edges {
+            assert!(
+                *edge < self.invokes.len(),
+                "Invalid edge ID. Edge {} of node {}.",
+                edge,
+                node_id
+            );
+            assert!(*edge != node_id, "Self-loop edges are not allowed. Node {}.", node_id);
+            if !self.edges[node_id].contains(edge) {
+                self.edges[node_id].push(*edge);
+            }
+        }
+        self
+    }
+
+    /// Nodes in topological order, panics if the graph contains a cycle.
+    fn topological_order(&self) -> Vec<NodeId> {
+        let mut indegree = self.indegrees();
+        let mut order: Vec<NodeId> = (0..self.invokes.len()).filter(|node| indegree[*node] == 0).collect();
+        let mut next = 0;
+        while next < order.len() {
+            for edge in self.edges[order[next]].iter() {
+                indegree[*edge] -= 1;
+                if indegree[*edge] == 0 {
+                    order.push(*edge);
+                }
+            }
+            next += 1;
+        }
+
+        assert!(order.len() == self.invokes.len(), "Cycle detected in the graph.");
+        order
+    }
+
+    fn indegrees(&self) -> Vec<usize> {
+        let mut indegree = vec![0; self.invokes.len()];
+        self.edges.iter().flatten().for_each(|edge| indegree[*edge] += 1);
+        indegree
+    }
+
+    /// Cost of the longest path from each node to the end of the graph, including the node itself.
+    fn bottom_levels(&self) -> Vec<Duration> {
+        let mut levels = vec![Duration::ZERO; self.invokes.len()];
+        for node in self.topological_order().into_iter().rev() {
+            let successors = self.edges[node].iter().map(|edge| levels[*edge]).max();
+            levels[node] = self.invokes[node].1 + successors.unwrap_or(Duration::ZERO);
+        }
+        levels
+    }
+
+    /// List scheduling on `worker_count` workers: the ready invoke with the longest path to the end of the graph
+    /// starts first, on the worker where it can start earliest. Returns the workers and the time all invokes finish.
+    fn schedule(&self, levels: &[Duration], worker_count: usize) -> (Vec<WorkerLoad>, Duration) {
+        let mut indegree = self.indegrees();
+        let mut ready: Vec<NodeId> = (0..self.invokes.len()).filter(|node| indegree[*node] == 0).collect();
+        let mut ready_at = vec![Duration::ZERO; self.invokes.len()];
+        let mut free_at = vec![Duration::ZERO; worker_count];
+        let mut workers = vec![
+            WorkerLoad {
+                invokes: Vec::new(),
+                load: Duration::ZERO,
+            };
+            worker_count
+        ];
+        let mut makespan = Duration::ZERO;
+
+        loop {
+            let Some(position) = ready
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, node)| (levels[**node], Reverse(**node)))
+                .map(|(position, _)| position)
+            else {
+                break;
+            };
+            let node = ready.swap_remove(position);
+            let (tag, cost) = &self.invokes[node];
+            let (worker, start) = free_at
+                .iter()
+                .enumerate()
+                .map(|(worker, free)| (worker, (*free).max(ready_at[node])))
+                .min_by_key(|(_, start)| *start)
+                .expect("Partitioning requires at least one worker");
+
+            let finish = start + *cost;
+            free_at[worker] = finish;
+            workers[worker].invokes.push(tag.clone());
+            workers[worker].load += *cost;
+            makespan = makespan.max(finish);
+
+            for edge in self.edges[node].iter() {
+                ready_at[*edge] = ready_at[*edge].max(finish);
+                indegree[*edge] -= 1;
+                if indegree[*edge] == 0 {
+                    ready.push(*edge);
+                }
+            }
+        }
+
+        workers.retain(|worker| !worker.invokes.is_empty());
+        (workers, makespan)
+    }
+}
+
+/// Invokes assigned to one dedicated worker, see [`suggest_partitioning`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkerLoad {
+    /// Tags of invokes bound to the worker, in the order they start.
+    pub invokes: Vec<String>,
+    /// Sum of measured costs of the invokes.
+    pub load: Duration,
+}
+
+/// Suggested invoke-to-worker bindings, see [`suggest_partitioning`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Partitioning {
+    cycle: Duration,
+    makespan: Duration,
+    workers: Vec<WorkerLoad>,
+}
+
+impl Partitioning {
+    /// Number of dedicated workers needed.
+    pub fn worker_count(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Suggested workers with their invokes, `dedicated_worker_<index>` in the deployment snippet.
+    pub fn workers(&self) -> &[WorkerLoad] {
+        &self.workers
+    }
+
+    /// Time from the start of the cycle until all invokes finished, including waiting for their dependencies.
+    pub fn makespan(&self) -> Duration {
+        self.makespan
+    }
+
+    /// Returns `true` if the invokes cannot finish within the cycle, e.g. a chain of dependent invokes alone takes
+    /// longer than the cycle, so no partitioning can meet it.
+    pub fn is_overloaded(&self) -> bool {
+        self.makespan > self.cycle
+    }
+}
+
+/// Emits deployment snippet binding the invokes to workers, to start from instead of manual trial and error.
+impl Display for Partitioning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::core::fmt::Result {
+        writeln!(f, "// makespan {:?} of {:?} cycle", self.makespan, self.cycle)?;
+        for (index, worker) in self.workers.iter().enumerate() {
+            writeln!(f, "// dedicated_worker_{}: load {:?}", index, worker.load)?;
+            for invoke in worker.invokes.iter() {
+                writeln!(
+                    f,
+                    "deployment.bind_invoke_to_worker(\"{}\".into(), \"dedicated_worker_{}\".into())?;",
+                    invoke, index
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+///
+/// Suggests number of dedicated workers and invoke-to-worker bindings, so the invokes of `graph` finish within the
+/// `cycle` of the program. Costs are measured execution times of the invokes, e.g. taken from metering of the program.
+///
+/// Uses list scheduling respecting the dependencies of the graph with increasing number of workers, and returns the
+/// least workers meeting the cycle. If the cycle cannot be met, returns the least workers with the shortest makespan,
+/// see [`Partitioning::is_overloaded`]. Panics if the graph contains a cycle.
+///
+/// Treat the result as a starting point and verify it on target.
+///
+pub fn suggest_partitioning(graph: &CostGraph, cycle: Duration) -> Partitioning {
+    let levels = graph.bottom_levels();
+    let critical_path = levels.iter().max().copied().unwrap_or(Duration::ZERO);
+
+    let mut worker_count = 1;
+    loop {
+        let (workers, makespan) = graph.schedule(&levels, worker_count);
+        // With a worker per invoke each starts once its dependencies finished, so the critical path is reached
+        if makespan <= cycle || makespan <= critical_path || worker_count >= graph.invokes.len() {
+            return Partitioning {
+                cycle,
+                makespan,
+                workers,
+            };
+        }
+        worker_count += 1;
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(loom))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn independent_invokes_are_packed_into_cycle() {
+        let ms = Duration::from_millis;
+        let mut graph = CostGraph::new();
+        graph.add_invoke("fusion", ms(3));
+        graph.add_invoke("camera", ms(6));
+        graph.add_invoke("radar", ms(4));
+        graph.add_invoke("planner", ms(5));
+
+        let partitioning = suggest_partitioning(&graph, ms(10));
+        assert_eq!(partitioning.worker_count(), 2);
+        assert!(!partitioning.is_overloaded());
+        assert_eq!(partitioning.workers()[0].invokes, vec!["camera", "fusion"]);
+        assert_eq!(partitioning.workers()[1].invokes, vec!["planner", "radar"]);
+        assert_eq!(partitioning.workers()[1].load, ms(9));
+        assert_eq!(partitioning.makespan(), ms(9));
+
+        let snippet = partitioning.to_string();
+        assert!(
+            snippet.contains("deployment.bind_invoke_to_worker(\"fusion\".into(), \"dedicated_worker_0\".into())?;")
+        );
+        assert!(snippet.contains("deployment.bind_invoke_to_worker(\"radar\".into(), \"dedicated_worker_1\".into())?;"));
+    }
+
+    #[test]
+    fn dependent_invokes_start_after_their_dependencies() {
+        let ms = Duration::from_millis;
+        let mut graph = CostGraph::new();
+        let camera = graph.add_invoke("camera", ms(4));
+        let radar = graph.add_invoke("radar", ms(4));
+        let fusion = graph.add_invoke("fusion", ms(3));
+        let planner = graph.add_invoke("planner", ms(3));
+        graph
+            .add_edges(camera, &[fusion])
+            .add_edges(radar, &[fusion])
+            .add_edges(fusion, &[planner]);
+
+        // Sum of costs fits two cycles, the chain through fusion fits only when camera and radar run in parallel
+        let partitioning = suggest_partitioning(&graph, ms(10));
+        assert_eq!(partitioning.worker_count(), 2);
+        assert!(!partitioning.is_overloaded());
+        assert_eq!(partitioning.makespan(), ms(10));
+        assert_eq!(partitioning.workers()[0].invokes, vec!["camera", "fusion", "planner"]);
+        assert_eq!(partitioning.workers()[1].invokes, vec!["radar"]);
+    }
+
+    #[test]
+    fn chain_longer_than_cycle_is_reported() {
+        let ms = Duration::from_millis;
+        let mut graph = CostGraph::new();
+        let first = graph.add_invoke("first", ms(6));
+        let second = graph.add_invoke("second", ms(6));
+        graph.add_edges(first, &[second]);
+
+        // Each invoke fits the cycle, but the second one waits for the first, so more workers do not help
+        let partitioning = suggest_partitioning(&graph, ms(10));
+        assert_eq!(partitioning.worker_count(), 1);
+        assert_eq!(partitioning.makespan(), ms(12));
+        assert!(partitioning.is_overloaded());
+    }
+
+    #[test]
+    fn invoke_longer_than_cycle_is_reported() {
+        let mut graph = CostGraph::new();
+        graph.add_invoke("slow", Duration::from_millis(20));
+
+        let partitioning = suggest_partitioning(&graph, Duration::from_millis(10));
+        assert_eq!(partitioning.worker_count(), 1);
+        assert!(partitioning.is_overloaded());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cycle detected in the graph.")]
+    fn cyclic_graph_panics() {
+        let mut graph = CostGraph::new();
+        let first = graph.add_invoke("first", Duration::from_millis(1));
+        let second = graph.add_invoke("second", Duration::from_millis(1));
+        graph.add_edges(first, &[second]).add_edges(second, &[first]);
+
+        suggest_partitioning(&graph, Duration::from_millis(10));
+    }
+}
@@ -13,7 +13,7 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, punctuated::Punctuated, ItemStruct, LitStr, Token};
+use syn::{parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Fields, ItemStruct, LitStr, Token};
 
 /// Macro to generate `extern "C"` FFI declarations for a C++ class.
 ///
@@ -190,3 +190,88 @@ pub fn import_from_cpp(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Derive macro mapping a fieldless enum to and from `UserErrValue`.
+///
+/// Each variant is represented by its discriminant, so explicit discriminants can be used to keep codes stable.
+///
+/// # Usage
+/// ```ignore
+/// #[derive(Debug, OrchUserError)]
+/// pub enum CameraError {
+///     NotConnected = 100,
+///     FrameDropped = 101,
+/// }
+///
+/// fn capture() -> InvokeResult {
+///     Err(CameraError::FrameDropped.into())
+/// }
+///
+/// CatchBuilder::new(ErrorFilter::UserErrors.into(), action).catch(|e| match e.downcast::<CameraError>() {
+///     Some(CameraError::NotConnected) => { ... },
+///     _ => { ... },
+/// });
+/// ```
+///
+/// This generates:
+/// - `impl OrchUserError for CameraError`
+/// - `impl From<CameraError> for UserErrValue`
+///
+/// # Requirements
+/// The enum shall have only unit variants.
+///
+#[proc_macro_derive(OrchUserError)]
+pub fn derive_orch_user_error(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    let enum_ident = &input.ident;
+
+    let Data::Enum(data) = &input.data else {
+        return syn::Error::new_spanned(&input.ident, "OrchUserError can only be derived for enums")
+            .to_compile_error()
+            .into();
+    };
+
+    if let Some(variant) = data.variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return syn::Error::new_spanned(
+            variant,
+            "OrchUserError can only be derived for enums with unit variants",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let variants: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Paths are absolute, so the generated code does not depend on what is in scope at the derive site.
+    let expanded = quote! {
+        impl #impl_generics ::orchestration::actions::action::OrchUserError for #enum_ident #ty_generics #where_clause {
+            fn to_user_err_value(&self) -> ::orchestration::actions::action::UserErrValue {
+                match self {
+                    #(Self::#variants => ::orchestration::actions::action::UserErrValue::from(Self::#variants as u64),)*
+                }
+            }
+
+            fn from_user_err_value(
+                value: ::orchestration::actions::action::UserErrValue,
+            ) -> ::core::option::Option<Self> {
+                #(
+                    if *value == Self::#variants as u64 {
+                        return ::core::option::Option::Some(Self::#variants);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#enum_ident #ty_generics>
+            for ::orchestration::actions::action::UserErrValue #where_clause
+        {
+            fn from(value: #enum_ident #ty_generics) -> Self {
+                ::orchestration::actions::action::OrchUserError::to_user_err_value(&value)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -98,6 +98,15 @@ fn main() {
                 &passthrough_args,
             );
         },
+        "build:qnx" => {
+            cli_env_vars
+                .entry("TARGET".into())
+                .or_insert_with(|| "aarch64-unknown-nto-qnx710".into());
+            cross_build(envs, cli_env_vars, &passthrough_args);
+        },
+        "build:cross" => {
+            cross_build(envs, cli_env_vars, &passthrough_args);
+        },
         "check_lic" => {
             check_license_header();
         },
@@ -137,6 +146,19 @@ fn main() {
                 &passthrough_args,
             );
         },
+        "miri" => {
+            miri(envs, cli_env_vars, &passthrough_args);
+        },
+        "bench" => {
+            // Pinned target dir, so results of criterion are compared against the previous run of the same build
+            run_build("bench_build", &["bench"], envs, cli_env_vars, &passthrough_args);
+        },
+        "flame" => {
+            flame(envs, cli_env_vars, &passthrough_args);
+        },
+        "cit" => {
+            cit(envs, cli_env_vars, &passthrough_args);
+        },
         _ => print_usage_and_exit(),
     }
 }
@@ -159,6 +181,237 @@ fn debug_build(envs: HashMap<String, String>, cli_env_vars: HashMap<String, Stri
     run_build("debug_build", &["build"], envs, cli_env_vars, passthrough_args);
 }
 
+/// Tests excluded from miri runs: they panic inside `ReusableObject` on purpose, which miri reports as leaks.
+const MIRI_SKIPPED_TESTS: &[&str] = &[
+    "actions::concurrency::tests::concurrency_panics_if_polled_after_future_reported_ready",
+    "actions::graph::tests::graph_action_panics_if_polled_after_future_reported_ready",
+];
+
+fn miri(mut envs: HashMap<String, String>, cli_env_vars: HashMap<String, String>, passthrough_args: &[String]) {
+    // Isolation is disabled since actions and events read the system clock
+    envs.insert("MIRIFLAGS".into(), "-Zmiri-disable-isolation".into());
+
+    let mut extra_args = passthrough_args.to_vec();
+    if !extra_args.iter().any(|arg| arg == "--") {
+        extra_args.push("--".into());
+    }
+
+    for test in MIRI_SKIPPED_TESTS {
+        extra_args.push("--skip".into());
+        extra_args.push(test.to_string());
+    }
+
+    // IPC (iceoryx2) is excluded since it relies on shared memory which miri does not support
+    run_build(
+        "miri_build",
+        &[
+            "+nightly",
+            "miri",
+            "test",
+            "-p",
+            "orchestration",
+            "--no-default-features",
+        ],
+        envs,
+        cli_env_vars,
+        &extra_args,
+    );
+}
+
+/// Known cross targets: (target triple, toolchain override, linker).
+/// For QNX targets the linker is resolved from `QNX_HOST`.
+const CROSS_TARGETS: &[(&str, Option<&str>, &str)] = &[
+    ("aarch64-unknown-linux-gnu", None, "aarch64-linux-gnu-gcc"),
+    ("x86_64-pc-nto-qnx710", Some("+qnx7.1_rust"), "qcc"),
+    ("aarch64-unknown-nto-qnx710", Some("+qnx7.1_rust"), "qcc"),
+];
+
+fn cross_build(
+    mut envs: HashMap<String, String>,
+    mut cli_env_vars: HashMap<String, String>,
+    passthrough_args: &[String],
+) {
+    let Some(target) = cli_env_vars.remove("TARGET") else {
+        eprintln!("build:cross requires TARGET=<triple>, known targets:");
+        CROSS_TARGETS
+            .iter()
+            .for_each(|(triple, _, _)| eprintln!("    {}", triple));
+        exit(1);
+    };
+
+    let mut cargo_args = Vec::new();
+    let linker_env = format!("CARGO_TARGET_{}_LINKER", target.to_uppercase().replace('-', "_"));
+
+    match CROSS_TARGETS.iter().find(|(triple, _, _)| *triple == target) {
+        Some((_, toolchain, linker)) => {
+            if let Some(toolchain) = toolchain {
+                cargo_args.push(*toolchain);
+            }
+
+            let linker = if target.contains("-nto-") {
+                let qnx_host = qnx_sdp_var("QNX_HOST", &cli_env_vars);
+                let qnx_target = qnx_sdp_var("QNX_TARGET", &cli_env_vars);
+                envs.insert("QNX_HOST".into(), qnx_host.clone());
+                envs.insert("QNX_TARGET".into(), qnx_target);
+                format!("{}/usr/bin/{}", qnx_host, linker)
+            } else {
+                linker.to_string()
+            };
+
+            envs.insert(linker_env, linker);
+        },
+        None => {
+            println!("> Unknown target {}, linker taken from {} if set", target, linker_env);
+        },
+    }
+
+    if let Some(sysroot) = cli_env_vars.remove("SYSROOT") {
+        envs.insert("RUSTFLAGS".into(), format!("-C link-arg=--sysroot={}", sysroot));
+    }
+
+    cargo_args.extend(["build", "--target", target.as_str()]);
+    run_build("cross_build", &cargo_args, envs, cli_env_vars, passthrough_args);
+}
+
+/// Takes QNX SDP variable from CLI or environment (set by `qnxsdp-env.sh`)
+fn qnx_sdp_var(key: &str, cli_env_vars: &HashMap<String, String>) -> String {
+    cli_env_vars
+        .get(key)
+        .cloned()
+        .or_else(|| env::var(key).ok())
+        .unwrap_or_else(|| {
+            eprintln!("{} is not set, source qnxsdp-env.sh or pass {}=<path>", key, key);
+            exit(1);
+        })
+}
+
+const SCENARIOS_MANIFEST: &str = "tests/test_scenarios/rust/Cargo.toml";
+
+/// Builds test scenarios and runs the ones matching `FILTER` (glob, `*` and `?` supported), each with the inputs of its
+/// cases declared by test scenarios, or once with `INPUT` if given. `INPUT` can be either a JSON string or a path to a
+/// JSON file. Pass `--list` to only list matching scenarios.
+/// Scenarios are run by the test scenarios executable itself (`--jobs`), each in a child process with its expected
+/// outcome and timeout declared by test scenarios. `JOBS` sets number of scenarios run in parallel.
+/// `RESULTS` writes machine readable results as JSON into given file. Each entry holds the verdict of the scenario
+/// process (pass/fail reason) and the results reported by the scenario itself with `--results` (duration and
+/// checkpoints), `null` if the scenario did not report them, e.g. when aborted.
+fn cit(envs: HashMap<String, String>, mut cli_env_vars: HashMap<String, String>, passthrough_args: &[String]) {
+    let filter = cli_env_vars.remove("FILTER").unwrap_or_else(|| "*".into());
+    let input = scenario_input(&mut cli_env_vars);
+    let results_file = cli_env_vars.remove("RESULTS");
+    let jobs = cli_env_vars.remove("JOBS").unwrap_or_else(|| "1".into());
+    let list_only = passthrough_args.iter().any(|arg| arg == "--list");
+    let cargo_args: Vec<String> = passthrough_args
+        .iter()
+        .filter(|arg| *arg != "--list")
+        .cloned()
+        .collect();
+
+    let target_dir = cli_env_vars
+        .get("CARGO_TARGET_DIR")
+        .cloned()
+        .unwrap_or_else(|| "target/debug_build".into());
+
+    run_build(
+        "debug_build",
+        &["build", "--manifest-path", SCENARIOS_MANIFEST],
+        envs,
+        cli_env_vars,
+        &cargo_args,
+    );
+
+    let binary = Path::new(&target_dir).join("debug").join("test_scenarios");
+    let mut command = Command::new(&binary);
+    command.args(["--jobs", &jobs, "--filter", &filter]);
+    if let Some(input) = &input {
+        command.args(["--input", input]);
+    }
+    if let Some(results_file) = &results_file {
+        command.args(["--results", results_file]);
+    }
+    if list_only {
+        command.arg("--list");
+    }
+
+    println!("> Running scenarios matching '{}' using {}", filter, binary.display());
+    let status = command
+        .status()
+        .unwrap_or_else(|_| panic!("Failed to run scenarios using {}", binary.display()));
+    if !status.success() {
+        exit(status.code().unwrap_or(1));
+    }
+}
+
+/// Builds test scenarios in release mode with frame pointers and records `SCENARIO` under `perf`, with `INPUT` or the
+/// input of the first case declared for the scenario.
+fn flame(mut envs: HashMap<String, String>, mut cli_env_vars: HashMap<String, String>, passthrough_args: &[String]) {
+    let Some(scenario) = cli_env_vars.remove("SCENARIO") else {
+        eprintln!("flame requires SCENARIO=<group.scenario>");
+        exit(1);
+    };
+    let input = scenario_input(&mut cli_env_vars);
+    let target_dir = cli_env_vars
+        .get("CARGO_TARGET_DIR")
+        .cloned()
+        .unwrap_or_else(|| "target/flame_build".into());
+
+    envs.insert("RUSTFLAGS".into(), "-C force-frame-pointers=yes".into());
+    envs.insert("CARGO_PROFILE_RELEASE_DEBUG".into(), "true".into());
+    run_build(
+        "flame_build",
+        &["build", "--release", "--manifest-path", SCENARIOS_MANIFEST],
+        envs,
+        cli_env_vars,
+        passthrough_args,
+    );
+
+    let binary = Path::new(&target_dir).join("release").join("test_scenarios");
+    let Some(input) = input.or_else(|| declared_input(&binary, &scenario)) else {
+        eprintln!("No case declared for {}, pass INPUT=<json|file>", scenario);
+        exit(1);
+    };
+    let perf_data = Path::new(&target_dir).join("perf.data");
+    println!("> Recording scenario {} with perf", scenario);
+
+    let status = Command::new("perf")
+        .args(["record", "-F", "999", "-g", "-o"])
+        .arg(&perf_data)
+        .arg("--")
+        .arg(&binary)
+        .args(["--name", &scenario, "--input", &input])
+        .status()
+        .unwrap_or_else(|_| panic!("Failed to run perf, is it installed?"));
+    if !status.success() {
+        exit(status.code().unwrap_or(1));
+    }
+
+    println!(
+        "> Profile written to {}, inspect with `perf report -i {}` or convert using `inferno-flamegraph`",
+        perf_data.display(),
+        perf_data.display()
+    );
+}
+
+/// Takes scenario `INPUT` from CLI, either JSON string or path to JSON file.
+fn scenario_input(cli_env_vars: &mut HashMap<String, String>) -> Option<String> {
+    cli_env_vars
+        .remove("INPUT")
+        .map(|input| fs::read_to_string(&input).unwrap_or(input))
+}
+
+/// Input of the first case declared for the scenario, from `<name> <outcome> <timeout_ms> <input>` lines printed by
+/// `test_scenarios --list-expectations`.
+fn declared_input(binary: &Path, scenario: &str) -> Option<String> {
+    let output = Command::new(binary).arg("--list-expectations").output().ok()?;
+    String::from_utf8_lossy(&output.stdout).lines().find_map(|line| {
+        let mut fields = line.splitn(4, ' ');
+        (fields.next() == Some(scenario))
+            .then(|| fields.nth(2))
+            .flatten()
+            .map(String::from)
+    })
+}
+
 fn run_build(
     target_dir: &str,
     cargo_args: &[&str],
@@ -213,9 +466,16 @@ fn print_usage_and_exit() -> ! {
     build:loom          builds and runs loom tests only
     build:qnx_x86_64    build for QNX7.1 target: x86_64-pc-nto-qnx710
     build:qnx_arm       build for QNX7.1 target: aarch64-pc-nto-qnx710
+    build:qnx           build for QNX target, TARGET=<triple> (default: aarch64-unknown-nto-qnx710)
+    build:cross         cross build, TARGET=<triple> [SYSROOT=<path>]
     clippy              runs clippy
     check               runs fundamental checks, good to run before push
     check_lic           runs source code license check
+    miri                runs orchestration tests under miri (requires nightly toolchain)
+    bench               runs benchmarks, target dir pinned to target/bench_build
+    flame               records perf profile of test scenario, SCENARIO=<group.scenario> [INPUT=<json|file>]
+    cit                 builds and runs test scenarios with declared inputs, FILTER=<glob> [INPUT=<json|file>] [--list]
+                        JOBS=<n> RESULTS=<file>
 
     [ENV_VAR=value ...] [-- cargo args...]"
     );
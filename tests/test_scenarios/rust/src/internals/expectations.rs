@@ -0,0 +1,391 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Timeout of scenario cases, matches default execution timeout of the Python test cases.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Graphs of `orchestration.graphs.graph_program` and `integration_graph` built successfully.
+const VALID_GRAPHS: &[&str] = &[
+    "two_nodes",
+    "no_edges",
+    "one_node",
+    "multiple_edges",
+    "empty_edges",
+    "cube",
+    "parallel_flows",
+    "two_steps",
+    "two_programs",
+    "concurrency",
+];
+
+/// Graphs of `orchestration.graphs.graph_program` rejected by the graph builder with panic.
+const INVALID_GRAPHS: &[&str] = &[
+    "loop",
+    "self_loop",
+    "not_enough_nodes",
+    "invalid_edge",
+    "invalid_node",
+    "duplicated_edge",
+];
+
+/// Expected outcome of a scenario process.
+#[derive(Clone, Copy, Debug)]
+pub enum Outcome {
+    /// Scenario exits with success.
+    Success,
+    /// Scenario panics or aborts.
+    Failure,
+    /// Scenario runs until it is killed after its timeout.
+    Endless,
+}
+
+impl Outcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Success => "success",
+            Outcome::Failure => "failure",
+            Outcome::Endless => "endless",
+        }
+    }
+}
+
+/// Declared run of a scenario: its input together with expected outcome and timeout, used by `--jobs`.
+/// Scenario can be declared with multiple cases, e.g. one per graph of `orchestration.graphs.graph_program`.
+pub struct Case {
+    pub name: &'static str,
+    pub input: Value,
+    pub outcome: Outcome,
+    pub timeout: Duration,
+}
+
+impl Case {
+    fn success(name: &'static str, input: Value) -> Self {
+        Self {
+            name,
+            input,
+            outcome: Outcome::Success,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    fn failure(name: &'static str, input: Value) -> Self {
+        Self {
+            outcome: Outcome::Failure,
+            ..Self::success(name, input)
+        }
+    }
+
+    fn endless(name: &'static str, input: Value, timeout: Duration) -> Self {
+        Self {
+            outcome: Outcome::Endless,
+            timeout,
+            ..Self::success(name, input)
+        }
+    }
+
+    fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /// Name of the scenario followed by its `test` input, distinguishes cases of the same scenario.
+    pub fn title(&self) -> String {
+        match self.input.get("test") {
+            Some(test) => format!("{} {}", self.name, test),
+            None => self.name.to_string(),
+        }
+    }
+}
+
+fn runtime(workers: usize) -> Value {
+    json!({"task_queue_size": 256, "workers": workers})
+}
+
+fn runtime_with_dedicated(workers: usize, dedicated: usize) -> Value {
+    let dedicated: Vec<Value> = (0..dedicated)
+        .map(|i| json!({"id": format!("dedicated_worker_{}", i)}))
+        .collect();
+    json!({"task_queue_size": 256, "workers": workers, "dedicated_workers": dedicated})
+}
+
+fn with_test(runtime: Value, test: Value) -> Value {
+    json!({"runtime": runtime, "test": test})
+}
+
+fn runtime_only(runtime: Value) -> Value {
+    json!({ "runtime": runtime })
+}
+
+fn basic_cases() -> Vec<Case> {
+    let program_run = |run_type: &str, run_count: u64, run_delay: u64| {
+        with_test(
+            runtime(2),
+            json!({"run_type": run_type, "run_count": run_count, "run_delay": run_delay}),
+        )
+    };
+
+    vec![
+        Case::endless(
+            "basic.demo",
+            with_test(runtime(3), json!({"cycle_duration_ms": 100})),
+            Duration::from_secs(2),
+        ),
+        Case::endless(
+            "basic.program_run",
+            program_run("run", 0, 0),
+            Duration::from_millis(500),
+        ),
+        Case::endless(
+            "basic.program_run",
+            program_run("run_cycle", 0, 10),
+            Duration::from_millis(500),
+        ),
+        Case::success("basic.program_run", program_run("run_n", 42, 0)),
+        Case::success("basic.program_run", program_run("run_n_cycle", 5, 10)),
+        Case::endless(
+            "basic.program_run_metered",
+            program_run("run_metered", 0, 0),
+            Duration::from_millis(500),
+        ),
+        Case::endless(
+            "basic.program_run_metered",
+            program_run("run_cycle_metered", 0, 10),
+            Duration::from_millis(500),
+        ),
+        Case::success("basic.program_run_metered", program_run("run_n_metered", 42, 0)),
+        Case::success("basic.program_run_metered", program_run("run_n_cycle_metered", 5, 10)),
+    ]
+}
+
+fn flow_cases() -> Vec<Case> {
+    let mut cases: Vec<Case> = [
+        "orchestration.sequence.single",
+        "orchestration.sequence.nested",
+        "orchestration.sequence.await",
+        "orchestration.concurrency.single",
+        "orchestration.concurrency.multiple",
+        "orchestration.concurrency.nested",
+        "orchestration.trigger_sync.1_trigger_1_sync_2_programs",
+        "orchestration.trigger_sync.1_trigger_2_syncs_3_programs",
+        "orchestration.trigger_sync.nested_branches",
+        "orchestration.trigger_sync.one_after_another",
+        "orchestration.shutdown.single_program_single_shutdown",
+        "orchestration.shutdown.two_programs_single_shutdown",
+        "orchestration.shutdown.two_programs_two_shutdowns",
+        "orchestration.shutdown.two_programs_all_shutdowns",
+        "orchestration.shutdown.before_start",
+    ]
+    .into_iter()
+    .map(|name| Case::success(name, runtime_only(runtime(4))))
+    .collect();
+
+    cases.push(Case::endless(
+        "orchestration.shutdown.one_program_not_shut",
+        runtime_only(runtime(4)),
+        Duration::from_secs(1),
+    ));
+    cases.push(
+        Case::success(
+            "orchestration.sleep.under_load",
+            with_test(
+                runtime(2),
+                json!({"sleep_duration_ms": 100, "run_count": 1, "cpu_load": "high"}),
+            ),
+        )
+        .with_timeout(Duration::from_secs(10)),
+    );
+
+    for condition in [true, false] {
+        cases.push(Case::success(
+            "orchestration.if_else.basic",
+            with_test(runtime(4), json!({ "condition": condition })),
+        ));
+        for inner_condition in [true, false] {
+            cases.push(Case::success(
+                "orchestration.if_else.nested",
+                with_test(
+                    runtime(4),
+                    json!({"outer_condition": condition, "inner_condition": inner_condition}),
+                ),
+            ));
+        }
+    }
+
+    cases
+}
+
+fn catch_cases() -> Vec<Case> {
+    let mut cases: Vec<Case> = ["unrecoverable", "recoverable_false", "recoverable_true"]
+        .into_iter()
+        .map(|design_type| {
+            Case::success(
+                "orchestration.catch.sequence_user_error",
+                with_test(
+                    runtime(4),
+                    json!({"design_type": design_type, "error_code": 42, "run_count": 3}),
+                ),
+            )
+        })
+        .collect();
+
+    for name in [
+        "orchestration.catch.nested_sequence_user_error",
+        "orchestration.catch.per_nested_sequence",
+    ] {
+        cases.push(Case::success(name, with_test(runtime(4), json!({"error_code": 42}))));
+    }
+    for name in [
+        "orchestration.catch.concurrency_user_error",
+        "orchestration.catch.nested_concurrency_user_error",
+    ] {
+        cases.push(Case::success(
+            name,
+            with_test(
+                runtime(4),
+                json!({"concurrent_valid_tasks": ["task_A", "task_B", "task_C"], "error_code": 42}),
+            ),
+        ));
+    }
+
+    cases.push(Case::success(
+        "orchestration.catch.double_recoverable_user_error",
+        with_test(runtime(4), json!({"error_codes": [0, 43]})),
+    ));
+    cases.push(Case::success(
+        "orchestration.catch.double_mixed_user_error",
+        with_test(runtime(4), json!({"error_codes": [u64::MAX, 43]})),
+    ));
+    cases.push(Case::failure(
+        "orchestration.catch.double_same_handler_error",
+        runtime_only(runtime(4)),
+    ));
+    cases.push(Case::failure(
+        "orchestration.catch.double_diff_handler_error",
+        runtime_only(runtime(4)),
+    ));
+
+    cases
+}
+
+fn tag_methods_cases() -> Vec<Case> {
+    let mut cases = vec![Case::success(
+        "orchestration.tag_methods.tag_methods",
+        runtime_only(runtime(4)),
+    )];
+
+    for program_name in [
+        "register_same_method_twice",
+        "register_same_async_method_twice",
+        "get_invalid_tag",
+    ] {
+        cases.push(Case::failure(
+            "orchestration.tag_methods.error_scenarios",
+            json!({"runtime": runtime(4), "program_name": program_name}),
+        ));
+    }
+    for capacity in [1, 5, 256] {
+        cases.push(Case::failure(
+            "orchestration.tag_methods.too_many_tags",
+            json!({"runtime": runtime(4), "registration_capacity": capacity}),
+        ));
+    }
+
+    cases
+}
+
+fn dedicated_worker_cases() -> Vec<Case> {
+    let mut cases: Vec<Case> = [
+        "orchestration.dedicated_worker.bind_tags",
+        "orchestration.dedicated_worker.dedicated_works_on_regular",
+    ]
+    .into_iter()
+    .map(|name| Case::success(name, runtime_only(runtime_with_dedicated(1, 3))))
+    .collect();
+
+    cases.extend(
+        [
+            "orchestration.dedicated_worker.repeat_tag_assignment",
+            "orchestration.dedicated_worker.assign_non_existent_tag",
+            "orchestration.dedicated_worker.assign_to_non_existent_dedicated_worker",
+        ]
+        .into_iter()
+        .map(|name| Case::failure(name, runtime_only(runtime_with_dedicated(1, 3)))),
+    );
+
+    cases
+}
+
+fn graph_cases() -> Vec<Case> {
+    let mut cases = Vec::new();
+    for name in [
+        "orchestration.graphs.graph_program",
+        "orchestration.graphs.integration_graph",
+    ] {
+        cases.extend(
+            VALID_GRAPHS
+                .iter()
+                .map(|graph_name| Case::success(name, with_test(runtime(4), json!({ "graph_name": graph_name })))),
+        );
+    }
+
+    cases.extend(INVALID_GRAPHS.iter().map(|graph_name| {
+        Case::failure(
+            "orchestration.graphs.graph_program",
+            with_test(runtime(4), json!({ "graph_name": graph_name })),
+        )
+    }));
+    cases.push(Case::success(
+        "orchestration.graphs.dedicated_graph",
+        runtime_only(runtime_with_dedicated(4, 1)),
+    ));
+
+    cases
+}
+
+/// Declared cases of all scenarios.
+pub fn cases() -> Vec<Case> {
+    [
+        basic_cases(),
+        flow_cases(),
+        catch_cases(),
+        tag_methods_cases(),
+        dedicated_worker_cases(),
+        graph_cases(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns expected outcome and timeout of the scenario run with the input, from the case declared with the same
+/// input, success within the default timeout otherwise.
+pub fn expectation_of(name: &str, input: &Value) -> (Outcome, Duration) {
+    cases()
+        .into_iter()
+        .find(|case| case.name == name && case.input == *input)
+        .map_or((Outcome::Success, DEFAULT_TIMEOUT), |case| (case.outcome, case.timeout))
+}
+
+/// Prints `<name> <outcome> <timeout_ms> <input>` line for each declared case.
+pub fn print_expectations() {
+    for case in cases() {
+        println!(
+            "{} {} {} {}",
+            case.name,
+            case.outcome.as_str(),
+            case.timeout.as_millis(),
+            case.input
+        );
+    }
+}
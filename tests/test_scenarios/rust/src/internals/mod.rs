@@ -10,4 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
+pub mod expectations;
+pub mod parallel;
+pub mod results;
 pub mod runtime_helper;
@@ -0,0 +1,344 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use crate::internals::expectations::{cases, expectation_of, Outcome};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Parallel execution of scenarios in child processes of this executable, requested with `--jobs <N>` together with
+/// `--name <GROUP>` (all scenarios of the group) or `--filter <GLOB>` (scenarios matching the glob, `*` and `?` are
+/// supported). Used also by `cargo xtask cit`.
+/// Each selected scenario runs once per its declared case with the input of the case, or once with `--input` if it is
+/// given. Scenarios without declared case require `--input`.
+/// Output of each scenario is captured and printed at once after the scenario finished, so outputs of parallel
+/// scenarios are not interleaved. `--results <FILE>` writes results of all scenarios as JSON array, `--list` only
+/// lists selected runs.
+pub struct ParallelRun {
+    jobs: usize,
+    pattern: String,
+    input: Option<String>,
+    results: Option<String>,
+    list_only: bool,
+    arguments: Vec<String>,
+}
+
+/// Single run of a scenario with its input and expectation.
+struct Run {
+    title: String,
+    name: String,
+    input: Value,
+    outcome: Outcome,
+    timeout: Duration,
+}
+
+/// Verdict of a scenario process with the results reported by the scenario itself.
+struct Verdict {
+    index: usize,
+    result: Result<(), String>,
+    reported: Option<Value>,
+}
+
+impl ParallelRun {
+    /// Takes `--jobs <N>` and the options of parallel run out of the arguments, returns `None` if not requested.
+    pub fn from_args(arguments: &mut Vec<String>) -> Option<Result<Self, String>> {
+        let jobs = take_option(arguments, "--jobs")?;
+        let Some(jobs) = jobs else {
+            return Some(Err("--jobs requires number of parallel scenarios".into()));
+        };
+        let Ok(jobs) = jobs.parse::<usize>() else {
+            return Some(Err(format!(
+                "--jobs shall be number of parallel scenarios, got '{}'",
+                jobs
+            )));
+        };
+
+        let pattern = match (
+            take_option(arguments, "--name").flatten(),
+            take_option(arguments, "--filter").flatten(),
+        ) {
+            (Some(group), None) => group,
+            (None, Some(filter)) => filter,
+            _ => {
+                return Some(Err(
+                    "--jobs requires either --name of the scenario group or --filter".into()
+                ))
+            },
+        };
+        let input = take_option(arguments, "--input").flatten();
+        let results = take_option(arguments, "--results").flatten();
+        let list_only = arguments.iter().any(|arg| arg == "--list");
+
+        // Arguments of the child processes, program name, `--name` and `--input` are set per run.
+        let arguments = arguments
+            .iter()
+            .skip(1)
+            .filter(|arg| *arg != "--list")
+            .cloned()
+            .collect();
+
+        Some(Ok(Self {
+            jobs: jobs.max(1),
+            pattern,
+            input,
+            results,
+            list_only,
+            arguments,
+        }))
+    }
+
+    /// Runs selected scenarios, fails if any of them does not finish with its expected outcome.
+    pub fn run(&self) -> Result<(), String> {
+        let executable = std::env::current_exe().map_err(|e| format!("failed to locate executable: {}", e))?;
+        let scenarios = self.scenarios(&executable)?;
+        if scenarios.is_empty() {
+            return Err(format!("No scenarios matching '{}'", self.pattern));
+        }
+
+        let runs = self.runs(&scenarios)?;
+        if self.list_only {
+            runs.iter().for_each(|run| println!("{}", run.title));
+            return Ok(());
+        }
+
+        let next_run = AtomicUsize::new(0);
+        let verdicts = Mutex::new(Vec::new());
+        thread::scope(|scope| {
+            for _ in 0..self.jobs {
+                scope.spawn(|| loop {
+                    let index = next_run.fetch_add(1, Ordering::Relaxed);
+                    let Some(run) = runs.get(index) else {
+                        break;
+                    };
+
+                    let (result, reported, output) = self.run_scenario(&executable, index, run);
+                    let verdict = result
+                        .as_ref()
+                        .map_or_else(|reason| format!("FAILED: {}", reason), |_| "ok".into());
+                    println!("> Scenario {}: {}\n{}", run.title, verdict, output);
+                    verdicts.lock().expect("Failed to lock verdicts").push(Verdict {
+                        index,
+                        result,
+                        reported,
+                    });
+                });
+            }
+        });
+
+        let mut verdicts = verdicts.into_inner().expect("Failed to collect verdicts");
+        verdicts.sort_by_key(|verdict| verdict.index);
+
+        if let Some(results) = &self.results {
+            write_results(results, &runs, &verdicts)?;
+        }
+
+        let failed: Vec<&str> = verdicts
+            .iter()
+            .filter(|verdict| verdict.result.is_err())
+            .map(|verdict| runs[verdict.index].title.as_str())
+            .collect();
+        println!(
+            "> Scenarios passed: {}, failed: {}",
+            runs.len() - failed.len(),
+            failed.len()
+        );
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Failed scenarios: {}", failed.join(", ")))
+        }
+    }
+
+    fn selects(&self, name: &str) -> bool {
+        name == self.pattern
+            || name
+                .strip_prefix(self.pattern.as_str())
+                .is_some_and(|rest| rest.starts_with('.'))
+            || glob_match(self.pattern.as_bytes(), name.as_bytes())
+    }
+
+    fn scenarios(&self, executable: &Path) -> Result<Vec<String>, String> {
+        let output = Command::new(executable)
+            .arg("--list-scenarios")
+            .output()
+            .map_err(|e| format!("failed to list scenarios: {}", e))?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|name| !name.is_empty() && self.selects(name))
+            .map(String::from)
+            .collect())
+    }
+
+    /// Runs of selected scenarios, with `--input` if given, otherwise with inputs of their declared cases.
+    fn runs(&self, scenarios: &[String]) -> Result<Vec<Run>, String> {
+        if let Some(input) = &self.input {
+            let input: Value = serde_json::from_str(input).map_err(|e| format!("--input is not valid JSON: {}", e))?;
+            return Ok(scenarios
+                .iter()
+                .map(|name| {
+                    let (outcome, timeout) = expectation_of(name, &input);
+                    Run {
+                        title: name.clone(),
+                        name: name.clone(),
+                        input: input.clone(),
+                        outcome,
+                        timeout,
+                    }
+                })
+                .collect());
+        }
+
+        let cases = cases();
+        let undeclared: Vec<&str> = scenarios
+            .iter()
+            .filter(|name| !cases.iter().any(|case| case.name == name.as_str()))
+            .map(String::as_str)
+            .collect();
+        if !undeclared.is_empty() {
+            return Err(format!(
+                "Scenarios without declared case, --input required: {}",
+                undeclared.join(", ")
+            ));
+        }
+
+        Ok(cases
+            .into_iter()
+            .filter(|case| scenarios.iter().any(|name| name == case.name))
+            .map(|case| Run {
+                title: case.title(),
+                name: case.name.to_string(),
+                input: case.input,
+                outcome: case.outcome,
+                timeout: case.timeout,
+            })
+            .collect())
+    }
+
+    /// Runs scenario in a child process, returns its verdict, results it reported and captured output.
+    fn run_scenario(&self, executable: &Path, index: usize, run: &Run) -> (Result<(), String>, Option<Value>, String) {
+        let log = temp_path(index, "log");
+        let reported = self.results.as_ref().map(|_| temp_path(index, "json"));
+        let result = self.run_scenario_to_log(executable, run, &log, reported.as_deref());
+        let output = fs::read_to_string(&log).unwrap_or_default();
+        let _ = fs::remove_file(&log);
+
+        let reported = reported.and_then(|path| {
+            let reported = fs::read_to_string(&path).ok();
+            let _ = fs::remove_file(&path);
+            reported.and_then(|reported| serde_json::from_str(&reported).ok())
+        });
+        (result, reported, output)
+    }
+
+    fn run_scenario_to_log(
+        &self,
+        executable: &Path,
+        run: &Run,
+        log: &Path,
+        reported: Option<&Path>,
+    ) -> Result<(), String> {
+        let file = fs::File::create(log).map_err(|e| format!("failed to create log {}: {}", log.display(), e))?;
+        let file_err = file.try_clone().map_err(|e| e.to_string())?;
+        let mut command = Command::new(executable);
+        command
+            .args(["--name", run.name.as_str()])
+            .arg("--input")
+            .arg(run.input.to_string())
+            .args(&self.arguments);
+        if let Some(reported) = reported {
+            command.arg("--results").arg(reported);
+        }
+        let mut child = command
+            .stdout(Stdio::from(file))
+            .stderr(Stdio::from(file_err))
+            .spawn()
+            .map_err(|e| format!("failed to start: {}", e))?;
+
+        let (outcome, timeout) = (run.outcome, run.timeout);
+        let start = Instant::now();
+        let status = loop {
+            if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+                break status;
+            }
+
+            if start.elapsed() > timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                return match outcome {
+                    Outcome::Endless => Ok(()),
+                    _ => Err(format!("timed out after {:?}", timeout)),
+                };
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        match (outcome, status.code()) {
+            (Outcome::Success, Some(0)) => Ok(()),
+            (Outcome::Failure, Some(101) | None) => Ok(()),
+            (_, code) => Err(format!("unexpected exit code {:?}", code)),
+        }
+    }
+}
+
+/// Takes `option` and its value out of the arguments. Returns `None` if the option is not present and `Some(None)`
+/// if its value is missing.
+fn take_option(arguments: &mut Vec<String>, option: &str) -> Option<Option<String>> {
+    let index = arguments.iter().position(|arg| arg == option)?;
+    arguments.remove(index);
+    Some((index < arguments.len()).then(|| arguments.remove(index)))
+}
+
+/// Writes JSON array of `name`, `input`, `passed`, `reason` and `scenario` entries, `scenario` holds the results
+/// reported by the scenario with `--results` (duration and checkpoints), `null` if it did not report them, e.g. when
+/// aborted.
+fn write_results(file: &str, runs: &[Run], verdicts: &[Verdict]) -> Result<(), String> {
+    let entries: Vec<Value> = verdicts
+        .iter()
+        .map(|verdict| {
+            let run = &runs[verdict.index];
+            json!({
+                "name": run.name,
+                "input": run.input,
+                "passed": verdict.result.is_ok(),
+                "reason": verdict.result.as_ref().err(),
+                "scenario": verdict.reported,
+            })
+        })
+        .collect();
+
+    let results = serde_json::to_string_pretty(&entries).map_err(|e| e.to_string())?;
+    fs::write(file, results).map_err(|e| format!("failed to write results to {}: {}", file, e))?;
+    println!("> Results written to {}", file);
+    Ok(())
+}
+
+fn temp_path(index: usize, extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("test_scenarios_{}_{}.{}", std::process::id(), index, extension))
+}
+
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => glob_match(&pattern[1..], text) || (!text.is_empty() && glob_match(pattern, &text[1..])),
+        (Some(b'?'), Some(_)) => glob_match(&pattern[1..], &text[1..]),
+        (Some(p), Some(t)) if p == t => glob_match(&pattern[1..], &text[1..]),
+        _ => false,
+    }
+}
@@ -0,0 +1,134 @@
+// *******************************************************************************
+// Copyright (c) 2026 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License Version 2.0 which is available at
+// <https://www.apache.org/licenses/LICENSE-2.0>
+//
+// SPDX-License-Identifier: Apache-2.0
+// *******************************************************************************
+use serde_json::{json, Map, Value};
+use std::fmt::Debug;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+type Checkpoints = Arc<Mutex<Vec<Value>>>;
+
+/// Machine readable result of a scenario run, requested with `--results <FILE>` (used by `cargo xtask cit`).
+/// Contains scenario name, duration, pass/fail reason and checkpoints, i.e. events logged with an `id` field.
+pub struct ScenarioResults {
+    file: String,
+    name: String,
+    start: Instant,
+    checkpoints: Checkpoints,
+}
+
+impl ScenarioResults {
+    /// Takes `--results <FILE>` out of the arguments, so they can be passed to the CLI app.
+    pub fn from_args(arguments: &mut Vec<String>) -> Option<Arc<Self>> {
+        let index = arguments.iter().position(|arg| arg == "--results")?;
+        arguments.remove(index);
+        if index >= arguments.len() {
+            return None;
+        }
+        let file = arguments.remove(index);
+
+        let name = arguments
+            .iter()
+            .position(|arg| arg == "--name")
+            .and_then(|index| arguments.get(index + 1))
+            .cloned()
+            .unwrap_or_default();
+
+        Some(Arc::new(Self {
+            file,
+            name,
+            start: Instant::now(),
+            checkpoints: Arc::default(),
+        }))
+    }
+
+    /// Layer collecting checkpoints of the scenario.
+    pub fn checkpoint_layer(&self) -> CheckpointLayer {
+        CheckpointLayer {
+            checkpoints: Arc::clone(&self.checkpoints),
+        }
+    }
+
+    /// Writes the results also if the scenario panics. Aborted scenarios do not write any results.
+    pub fn write_on_panic(self: &Arc<Self>) {
+        let results = Arc::clone(self);
+        let previous_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            results.write(Err(format!("panicked: {info}")));
+            previous_hook(info);
+        }));
+    }
+
+    pub fn write(&self, result: Result<(), String>) {
+        let checkpoints = match self.checkpoints.lock() {
+            Ok(checkpoints) => checkpoints.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        let results = json!({
+            "name": self.name,
+            "duration_ms": self.start.elapsed().as_millis(),
+            "passed": result.is_ok(),
+            "reason": result.err(),
+            "checkpoints": checkpoints,
+        });
+
+        if let Err(e) = fs::write(&self.file, results.to_string()) {
+            eprintln!("Failed to write results to {}: {}", self.file, e);
+        }
+    }
+}
+
+/// Records events with an `id` field as checkpoints, all fields of the event are kept.
+pub struct CheckpointLayer {
+    checkpoints: Checkpoints,
+}
+
+impl<S: Subscriber> Layer<S> for CheckpointLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut fields = FieldsVisitor(Map::new());
+        event.record(&mut fields);
+
+        if fields.0.contains_key("id") {
+            if let Ok(mut checkpoints) = self.checkpoints.lock() {
+                checkpoints.push(Value::Object(fields.0));
+            }
+        }
+    }
+}
+
+struct FieldsVisitor(Map<String, Value>);
+
+impl Visit for FieldsVisitor {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().into(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name().into(), format!("{value:?}").into());
+    }
+}
@@ -15,19 +15,43 @@ mod scenarios;
 
 use test_scenarios_rust::cli::{create_tracing_subscriber, run_cli_app};
 use test_scenarios_rust::test_context::TestContext;
+use tracing_subscriber::layer::SubscriberExt;
 
+use crate::internals::expectations::print_expectations;
+use crate::internals::parallel::ParallelRun;
+use crate::internals::results::ScenarioResults;
 use crate::scenarios::root_scenario_group;
 
 fn main() -> Result<(), String> {
-    let subscriber = create_tracing_subscriber();
-    tracing::subscriber::set_global_default(subscriber).expect("Setting default subscriber failed!");
+    let mut raw_arguments: Vec<String> = std::env::args().collect();
+
+    // Declarations of scenario outcomes checked by `--jobs`, not handled by the CLI app.
+    if raw_arguments.iter().any(|arg| arg == "--list-expectations") {
+        print_expectations();
+        return Ok(());
+    }
 
-    let raw_arguments: Vec<String> = std::env::args().collect();
+    // Selected scenarios in parallel child processes, each running single scenario through the CLI app.
+    // Single runner of scenarios, used also by `cargo xtask cit`.
+    if let Some(parallel_run) = ParallelRun::from_args(&mut raw_arguments) {
+        return parallel_run?.run();
+    }
+
+    let results = ScenarioResults::from_args(&mut raw_arguments);
+    let subscriber = create_tracing_subscriber().with(results.as_ref().map(|results| results.checkpoint_layer()));
+    tracing::subscriber::set_global_default(subscriber).expect("Setting default subscriber failed!");
+    if let Some(results) = &results {
+        results.write_on_panic();
+    }
 
     // Root group.
     let root_group = root_scenario_group();
 
     // Run.
     let test_context = TestContext::new(root_group);
-    run_cli_app(&raw_arguments, &test_context)
+    let result = run_cli_app(&raw_arguments, &test_context);
+    if let Some(results) = &results {
+        results.write(result.clone());
+    }
+    result
 }
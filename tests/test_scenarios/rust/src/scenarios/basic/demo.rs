@@ -11,14 +11,12 @@
 // SPDX-License-Identifier: Apache-2.0
 // *******************************************************************************
 use crate::internals::runtime_helper::Runtime;
-use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use test_scenarios_rust::scenario::Scenario;
 
 use core::time::Duration;
 use kyron_foundation::prelude::*;
-use orchestration::common::tag::Tag;
 
 use super::*;
 use orchestration::{
@@ -39,40 +37,20 @@ impl DemoLogic {
     }
 }
 
-pub struct JustLogAction {
-    base: ActionBaseMeta,
-    name: String,
-}
+/// Action logging that it was executed, built with `CustomActionBuilder`.
+pub struct JustLogAction;
 
 impl JustLogAction {
-    fn new(name: impl Into<String>) -> Box<JustLogAction> {
-        const DEFAULT_TAG: &str = "integration::tests::just_log_action";
-
-        Box::new(Self {
-            base: ActionBaseMeta {
-                tag: Tag::from_str_static(DEFAULT_TAG),
-                reusable_future_pool: ReusableBoxFuturePool::for_value(1, Self::execute_impl("JustLogAction".into())),
-            },
-            name: name.into(),
+    fn new(name: impl Into<String>, design: &Design) -> Box<dyn ActionTrait> {
+        let name = name.into();
+        CustomActionBuilder::new("JustLogAction", move || {
+            let name = name.clone();
+            async move {
+                info!("{name} was executed");
+                Ok(())
+            }
         })
-    }
-    async fn execute_impl(name: String) -> ActionResult {
-        info!("{name} was executed");
-        Ok(())
-    }
-}
-
-impl ActionTrait for JustLogAction {
-    fn name(&self) -> &'static str {
-        "JustLogAction"
-    }
-    fn dbg_fmt(&self, _nest: usize, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
-    }
-    fn try_execute(&mut self) -> ReusableBoxFutureResult {
-        self.base
-            .reusable_future_pool
-            .next(JustLogAction::execute_impl(self.name.clone()))
+        .build(design)
     }
 }
 
@@ -85,15 +63,18 @@ fn acc_design() -> Result<Design, CommonErrors> {
 
     design.add_program("acc", move |design, builder| {
         builder
-            .with_start_action(JustLogAction::new("StartACC"))
+            .with_start_action(JustLogAction::new("StartACC", design))
             .with_run_action(
                 SequenceBuilder::new()
                     .with_step(SyncBuilder::from_design("trigger_acc", design))
-                    .with_step(JustLogAction::new("RunACC"))
+                    .with_step(JustLogAction::new("RunACC", design))
                     .with_step(TriggerBuilder::from_design("trigger_s2m", design))
                     .build(),
             )
-            .with_stop_action(JustLogAction::new("StopACC"), std::time::Duration::from_secs(10));
+            .with_stop_action(
+                JustLogAction::new("StopACC", design),
+                std::time::Duration::from_secs(10),
+            );
 
         Ok(())
     });
@@ -110,15 +91,15 @@ fn m2s_design() -> Result<Design, CommonErrors> {
 
     design.add_program("m2s", move |design, builder| {
         builder
-            .with_start_action(JustLogAction::new("StartM2S"))
+            .with_start_action(JustLogAction::new("StartM2S", design))
             .with_run_action(
                 SequenceBuilder::new()
                     .with_step(SyncBuilder::from_design("cyclic_evt", design))
-                    .with_step(JustLogAction::new("RunM2S"))
+                    .with_step(JustLogAction::new("RunM2S", design))
                     .with_step(TriggerBuilder::from_design("trigger_acc", design))
                     .build(),
             )
-            .with_stop_action(JustLogAction::new("StopM2S"), Duration::from_secs(10));
+            .with_stop_action(JustLogAction::new("StopM2S", design), Duration::from_secs(10));
 
         Ok(())
     });
@@ -133,14 +114,14 @@ fn s2m_design() -> Result<Design, CommonErrors> {
 
     design.add_program("s2m", move |design, builder| {
         builder
-            .with_start_action(JustLogAction::new("StartS2M"))
+            .with_start_action(JustLogAction::new("StartS2M", design))
             .with_run_action(
                 SequenceBuilder::new()
                     .with_step(SyncBuilder::from_design("trigger_s2m", design))
-                    .with_step(JustLogAction::new("RunS2M"))
+                    .with_step(JustLogAction::new("RunS2M", design))
                     .build(),
             )
-            .with_stop_action(JustLogAction::new("StopS2M"), Duration::from_secs(10));
+            .with_stop_action(JustLogAction::new("StopS2M", design), Duration::from_secs(10));
 
         Ok(())
     });
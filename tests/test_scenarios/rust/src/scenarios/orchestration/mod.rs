@@ -31,10 +31,9 @@ use test_scenarios_rust::scenario::{ScenarioGroup, ScenarioGroupImpl};
 
 use orchestration_double_handler_catch::{CatchDoubleDiffHandlerError, CatchDoubleSameHandlerError};
 
-use kyron::futures::reusable_box_future::ReusableBoxFuturePool;
 use kyron::futures::{sleep, yield_now};
 
-use orchestration::{common::tag::Tag, prelude::*};
+use orchestration::{api::design::Design, common::tag::Tag, prelude::*};
 
 use orchestration_shutdown::{
     GetAllShutdowns, OneProgramNotShut, SingleProgramSingleShutdown, TwoProgramsSingleShutdown, TwoProgramsTwoShutdowns,
@@ -177,40 +176,20 @@ pub fn orchestration_scenario_group() -> Box<dyn ScenarioGroup> {
     ))
 }
 
-pub struct JustLogAction {
-    base: ActionBaseMeta,
-    name: String,
-}
+/// Action logging that it was executed, built with `CustomActionBuilder`.
+pub struct JustLogAction;
 
 impl JustLogAction {
-    fn new(name: impl Into<String>) -> Box<JustLogAction> {
-        const DEFAULT_TAG: &str = "integration::tests::just_log_action";
-
-        Box::new(Self {
-            base: ActionBaseMeta {
-                tag: Tag::from_str_static(DEFAULT_TAG),
-                reusable_future_pool: ReusableBoxFuturePool::for_value(1, Self::execute_impl("JustLogAction".into())),
-            },
-            name: name.into(),
+    fn new(name: impl Into<String>, design: &Design) -> Box<dyn ActionTrait> {
+        let name = name.into();
+        CustomActionBuilder::new("JustLogAction", move || {
+            let name = name.clone();
+            async move {
+                info!("{name} was executed");
+                Ok(())
+            }
         })
-    }
-    async fn execute_impl(name: String) -> ActionResult {
-        info!("{name} was executed");
-        Ok(())
-    }
-}
-
-impl ActionTrait for JustLogAction {
-    fn name(&self) -> &'static str {
-        "JustLogAction"
-    }
-    fn dbg_fmt(&self, _nest: usize, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Ok(())
-    }
-    fn try_execute(&mut self) -> ReusableBoxFutureResult {
-        self.base
-            .reusable_future_pool
-            .next(JustLogAction::execute_impl(self.name.clone()))
+        .build(design)
     }
 }
 
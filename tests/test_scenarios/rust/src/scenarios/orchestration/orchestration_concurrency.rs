@@ -40,7 +40,7 @@ fn single_concurrency_design() -> Result<Design, CommonErrors> {
                         .with_branch(Invoke::from_tag(&t3_tag, design.config()))
                         .build(design),
                 )
-                .with_step(JustLogAction::new("FinishAction"))
+                .with_step(JustLogAction::new("FinishAction", design))
                 .build(),
         );
 
@@ -101,7 +101,7 @@ fn multiple_concurrency_design() -> Result<Design, CommonErrors> {
                         .with_branch(Invoke::from_tag(&t3_tag, design.config()))
                         .build(design),
                 )
-                .with_step(JustLogAction::new("IntermediateAction"))
+                .with_step(JustLogAction::new("IntermediateAction", design))
                 .with_step(
                     ConcurrencyBuilder::new()
                         .with_branch(Invoke::from_tag(&t4_tag, design.config()))
@@ -109,7 +109,7 @@ fn multiple_concurrency_design() -> Result<Design, CommonErrors> {
                         .with_branch(Invoke::from_tag(&t6_tag, design.config()))
                         .build(design),
                 )
-                .with_step(JustLogAction::new("FinishAction"))
+                .with_step(JustLogAction::new("FinishAction", design))
                 .build(),
         );
 
@@ -174,7 +174,7 @@ fn nested_concurrency_design() -> Result<Design, CommonErrors> {
                         .with_branch(Invoke::from_tag(&t4_tag, design.config()))
                         .build(design),
                 )
-                .with_step(JustLogAction::new("FinishAction"))
+                .with_step(JustLogAction::new("FinishAction", design))
                 .build(),
         );
 
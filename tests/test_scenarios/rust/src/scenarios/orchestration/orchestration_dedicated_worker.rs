@@ -28,14 +28,14 @@ fn dedicated_design() -> Result<Design, CommonErrors> {
     let async_tag_2 = design.register_invoke_async("async2".into(), generic_async_test_func!("async2"))?;
 
     // Create a program with actions
-    design.add_program(file!(), move |_design_instance, builder| {
+    design.add_program(file!(), move |design_instance, builder| {
         builder.with_run_action(
             ConcurrencyBuilder::new()
-                .with_branch(Invoke::from_tag(&async_tag_1, _design_instance.config()))
-                .with_branch(Invoke::from_tag(&async_tag_2, _design_instance.config()))
-                .with_branch(Invoke::from_tag(&sync_tag_1, _design_instance.config()))
-                .with_branch(Invoke::from_tag(&sync_tag_2, _design_instance.config()))
-                .build(_design_instance),
+                .with_branch(Invoke::from_tag(&async_tag_1, design_instance.config()))
+                .with_branch(Invoke::from_tag(&async_tag_2, design_instance.config()))
+                .with_branch(Invoke::from_tag(&sync_tag_1, design_instance.config()))
+                .with_branch(Invoke::from_tag(&sync_tag_2, design_instance.config()))
+                .build(design_instance),
         );
 
         Ok(())
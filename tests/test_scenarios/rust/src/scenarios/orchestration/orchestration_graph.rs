@@ -39,8 +39,8 @@ impl GraphHandler {
         let mut design = Design::new("GraphTwoNodes".into(), DesignConfig::default());
         design.add_program("GraphTwoNodesProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
             builder.with_run_action(graph_builder.add_edges(n0, &[n1]).build(design_instance));
             Ok(())
         });
@@ -51,8 +51,8 @@ impl GraphHandler {
         let mut design = Design::new("GraphNoEdges".into(), DesignConfig::default());
         design.add_program("GraphNoEdgesProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            graph_builder.add_node(JustLogAction::new("node1"));
-            graph_builder.add_node(JustLogAction::new("node0"));
+            graph_builder.add_node(JustLogAction::new("node1", design_instance));
+            graph_builder.add_node(JustLogAction::new("node0", design_instance));
             builder.with_run_action(graph_builder.build(design_instance));
             Ok(())
         });
@@ -63,7 +63,7 @@ impl GraphHandler {
         let mut design = Design::new("GraphOneNode".into(), DesignConfig::default());
         design.add_program("GraphOneNodeProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            graph_builder.add_node(JustLogAction::new("node0"));
+            graph_builder.add_node(JustLogAction::new("node0", design_instance));
             builder.with_run_action(graph_builder.build(design_instance));
             Ok(())
         });
@@ -74,9 +74,9 @@ impl GraphHandler {
         let mut design = Design::new("GraphEmptyEdges".into(), DesignConfig::default());
         design.add_program("GraphEmptyEdgesProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
-            let n2 = graph_builder.add_node(JustLogAction::new("node2"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
+            let n2 = graph_builder.add_node(JustLogAction::new("node2", design_instance));
             builder.with_run_action(
                 graph_builder
                     .add_edges(n0, &[])
@@ -93,11 +93,11 @@ impl GraphHandler {
         let mut design = Design::new("GraphMultipleEdges".into(), DesignConfig::default());
         design.add_program("GraphMultipleEdgesProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
-            let n2 = graph_builder.add_node(JustLogAction::new("node2"));
-            let n3 = graph_builder.add_node(JustLogAction::new("node3"));
-            let n4 = graph_builder.add_node(JustLogAction::new("node4"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
+            let n2 = graph_builder.add_node(JustLogAction::new("node2", design_instance));
+            let n3 = graph_builder.add_node(JustLogAction::new("node3", design_instance));
+            let n4 = graph_builder.add_node(JustLogAction::new("node4", design_instance));
             builder.with_run_action(
                 graph_builder
                     .add_edges(n0, &[n1, n2, n3, n4])
@@ -115,14 +115,14 @@ impl GraphHandler {
         let mut design = Design::new("GraphCube".into(), DesignConfig::default());
         design.add_program("GraphCubeProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
-            let n2 = graph_builder.add_node(JustLogAction::new("node2"));
-            let n3 = graph_builder.add_node(JustLogAction::new("node3"));
-            let n4 = graph_builder.add_node(JustLogAction::new("node4"));
-            let n5 = graph_builder.add_node(JustLogAction::new("node5"));
-            let n6 = graph_builder.add_node(JustLogAction::new("node6"));
-            let n7 = graph_builder.add_node(JustLogAction::new("node7"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
+            let n2 = graph_builder.add_node(JustLogAction::new("node2", design_instance));
+            let n3 = graph_builder.add_node(JustLogAction::new("node3", design_instance));
+            let n4 = graph_builder.add_node(JustLogAction::new("node4", design_instance));
+            let n5 = graph_builder.add_node(JustLogAction::new("node5", design_instance));
+            let n6 = graph_builder.add_node(JustLogAction::new("node6", design_instance));
+            let n7 = graph_builder.add_node(JustLogAction::new("node7", design_instance));
             builder.with_run_action(
                 graph_builder
                     .add_edges(n0, &[n1, n2, n4])
@@ -143,12 +143,12 @@ impl GraphHandler {
         let mut design = Design::new("GraphParallelFlows".into(), DesignConfig::default());
         design.add_program("GraphParallelFlowsProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
-            let n2 = graph_builder.add_node(JustLogAction::new("node2"));
-            let n3 = graph_builder.add_node(JustLogAction::new("node3"));
-            let n4 = graph_builder.add_node(JustLogAction::new("node4"));
-            let n5 = graph_builder.add_node(JustLogAction::new("node5"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
+            let n2 = graph_builder.add_node(JustLogAction::new("node2", design_instance));
+            let n3 = graph_builder.add_node(JustLogAction::new("node3", design_instance));
+            let n4 = graph_builder.add_node(JustLogAction::new("node4", design_instance));
+            let n5 = graph_builder.add_node(JustLogAction::new("node5", design_instance));
             builder.with_run_action(
                 graph_builder
                     .add_edges(n0, &[n1])
@@ -167,8 +167,8 @@ impl GraphHandler {
         design.add_program("GraphLoopProgram", move |design_instance, builder| {
             builder.with_run_action({
                 let mut graph_builder = LocalGraphActionBuilder::new();
-                let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-                let n1 = graph_builder.add_node(JustLogAction::new("node1"));
+                let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+                let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
                 graph_builder
                     .add_edges(n0, &[n1])
                     .add_edges(n1, &[n0])
@@ -183,8 +183,8 @@ impl GraphHandler {
         let mut design = Design::new("GraphSelfLoop".into(), DesignConfig::default());
         design.add_program("GraphSelfLoopProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
             builder.with_run_action(
                 graph_builder
                     .add_edges(n0, &[n1])
@@ -200,7 +200,7 @@ impl GraphHandler {
         let mut design = Design::new("GraphNotEnoughNodes".into(), DesignConfig::default());
         design.add_program("GraphNotEnoughNodesProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
             let n1 = 1_usize;
             builder.with_run_action(graph_builder.add_edges(n0, &[n1]).build(design_instance));
             Ok(())
@@ -213,8 +213,8 @@ impl GraphHandler {
         design.add_program("GraphInvalidNodeProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
 
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
             let n2 = 2_usize;
             builder.with_run_action(graph_builder.add_edges(n2, &[n0, n1]).build(design_instance));
             Ok(())
@@ -226,8 +226,8 @@ impl GraphHandler {
         let mut design = Design::new("GraphInvalidEdge".into(), DesignConfig::default());
         design.add_program("GraphInvalidEdgeProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
             let n2 = 2_usize;
             builder.with_run_action(graph_builder.add_edges(n0, &[n1, n2]).build(design_instance));
             Ok(())
@@ -239,9 +239,9 @@ impl GraphHandler {
         let mut design = Design::new("GraphDuplicatedEdge".into(), DesignConfig::default());
         design.add_program("GraphDuplicatedEdgeProgram", move |design_instance, builder| {
             let mut graph_builder = LocalGraphActionBuilder::new();
-            let n0 = graph_builder.add_node(JustLogAction::new("node0"));
-            let n1 = graph_builder.add_node(JustLogAction::new("node1"));
-            let n2 = graph_builder.add_node(JustLogAction::new("node2"));
+            let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
+            let n1 = graph_builder.add_node(JustLogAction::new("node1", design_instance));
+            let n2 = graph_builder.add_node(JustLogAction::new("node2", design_instance));
             builder.with_run_action(
                 graph_builder
                     .add_edges(n0, &[n1])
@@ -261,15 +261,15 @@ impl GraphHandler {
                 SequenceBuilder::new()
                     .with_step({
                         let mut graph_builder1 = LocalGraphActionBuilder::new();
-                        let n0 = graph_builder1.add_node(JustLogAction::new("node0"));
-                        let n1 = graph_builder1.add_node(JustLogAction::new("node1"));
+                        let n0 = graph_builder1.add_node(JustLogAction::new("node0", design_instance));
+                        let n1 = graph_builder1.add_node(JustLogAction::new("node1", design_instance));
 
                         graph_builder1.add_edges(n0, &[n1]).build(design_instance)
                     })
                     .with_step({
                         let mut graph_builder2 = LocalGraphActionBuilder::new();
-                        let n2 = graph_builder2.add_node(JustLogAction::new("node2"));
-                        let n3 = graph_builder2.add_node(JustLogAction::new("node3"));
+                        let n2 = graph_builder2.add_node(JustLogAction::new("node2", design_instance));
+                        let n3 = graph_builder2.add_node(JustLogAction::new("node3", design_instance));
 
                         graph_builder2.add_edges(n2, &[n3]).build(design_instance)
                     })
@@ -290,16 +290,16 @@ impl GraphHandler {
                     .with_branch({
                         let mut graph_builder1 = LocalGraphActionBuilder::new();
 
-                        let n0 = graph_builder1.add_node(JustLogAction::new("node0"));
-                        let n1 = graph_builder1.add_node(JustLogAction::new("node1"));
+                        let n0 = graph_builder1.add_node(JustLogAction::new("node0", design_instance));
+                        let n1 = graph_builder1.add_node(JustLogAction::new("node1", design_instance));
 
                         graph_builder1.add_edges(n0, &[n1]).build(design_instance)
                     })
                     .with_branch({
                         let mut graph_builder2 = LocalGraphActionBuilder::new();
-                        let n2 = graph_builder2.add_node(JustLogAction::new("node2"));
-                        let n3 = graph_builder2.add_node(JustLogAction::new("node3"));
-                        let n4 = graph_builder2.add_node(JustLogAction::new("node4"));
+                        let n2 = graph_builder2.add_node(JustLogAction::new("node2", design_instance));
+                        let n3 = graph_builder2.add_node(JustLogAction::new("node3", design_instance));
+                        let n4 = graph_builder2.add_node(JustLogAction::new("node4", design_instance));
                         graph_builder2
                             .add_edges(n2, &[n3])
                             .add_edges(n3, &[n4])
@@ -321,8 +321,8 @@ impl GraphHandler {
             builder.with_run_action({
                 let mut graph_builder1 = LocalGraphActionBuilder::new();
 
-                let n0 = graph_builder1.add_node(JustLogAction::new("node0"));
-                let n1 = graph_builder1.add_node(JustLogAction::new("node1"));
+                let n0 = graph_builder1.add_node(JustLogAction::new("node0", design_instance));
+                let n1 = graph_builder1.add_node(JustLogAction::new("node1", design_instance));
 
                 graph_builder1.add_edges(n0, &[n1]).build(design_instance)
             });
@@ -331,9 +331,9 @@ impl GraphHandler {
         design.add_program("GraphDesignProgram2", move |design_instance, builder| {
             builder.with_run_action({
                 let mut graph_builder2 = LocalGraphActionBuilder::new();
-                let n2 = graph_builder2.add_node(JustLogAction::new("node2"));
-                let n3 = graph_builder2.add_node(JustLogAction::new("node3"));
-                let n4 = graph_builder2.add_node(JustLogAction::new("node4"));
+                let n2 = graph_builder2.add_node(JustLogAction::new("node2", design_instance));
+                let n3 = graph_builder2.add_node(JustLogAction::new("node3", design_instance));
+                let n4 = graph_builder2.add_node(JustLogAction::new("node4", design_instance));
                 graph_builder2
                     .add_edges(n2, &[n3])
                     .add_edges(n3, &[n4])
@@ -358,7 +358,7 @@ impl GraphHandler {
             builder.with_run_action({
                 let mut graph_builder = LocalGraphActionBuilder::new();
 
-                let n0 = graph_builder.add_node(JustLogAction::new("node0"));
+                let n0 = graph_builder.add_node(JustLogAction::new("node0", design_instance));
                 let n1 = graph_builder.add_node(Invoke::from_tag(&sync_tag_1, design_instance.config()));
                 let n2 = graph_builder.add_node(Invoke::from_tag(&sync_tag_2, design_instance.config()));
                 let n3 = graph_builder.add_node(Invoke::from_tag(&sync_tag_3, design_instance.config()));
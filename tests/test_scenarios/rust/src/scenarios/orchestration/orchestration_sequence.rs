@@ -25,12 +25,12 @@ fn single_sequence_design() -> Result<Design, CommonErrors> {
     let mut design = Design::new("SingleSequence".into(), DesignConfig::default());
 
     // Create a program with actions
-    design.add_program(file!(), move |_design_instance, builder| {
+    design.add_program(file!(), move |design_instance, builder| {
         builder.with_run_action(
             SequenceBuilder::new()
-                .with_step(JustLogAction::new("Action1"))
-                .with_step(JustLogAction::new("Action2"))
-                .with_step(JustLogAction::new("Action3"))
+                .with_step(JustLogAction::new("Action1", design_instance))
+                .with_step(JustLogAction::new("Action2", design_instance))
+                .with_step(JustLogAction::new("Action3", design_instance))
                 .build(),
         );
 
@@ -75,17 +75,17 @@ fn nested_sequence_design() -> Result<Design, CommonErrors> {
     let mut design = Design::new("NestedSequence".into(), DesignConfig::default());
 
     // Create a program with actions
-    design.add_program(file!(), move |_design_instance, builder| {
+    design.add_program(file!(), move |design_instance, builder| {
         builder.with_run_action(
             SequenceBuilder::new()
-                .with_step(JustLogAction::new("OuterAction1"))
+                .with_step(JustLogAction::new("OuterAction1", design_instance))
                 .with_step(
                     SequenceBuilder::new()
-                        .with_step(JustLogAction::new("InnerAction1"))
-                        .with_step(JustLogAction::new("InnerAction2"))
+                        .with_step(JustLogAction::new("InnerAction1", design_instance))
+                        .with_step(JustLogAction::new("InnerAction2", design_instance))
                         .build(),
                 )
-                .with_step(JustLogAction::new("OuterAction2"))
+                .with_step(JustLogAction::new("OuterAction2", design_instance))
                 .build(),
         );
 
@@ -135,26 +135,26 @@ fn awaited_sequence_design() -> Result<Design, CommonErrors> {
     design.add_program(file!(), move |design, builder| {
         builder.with_run_action(
             SequenceBuilder::new()
-                .with_step(JustLogAction::new("Action1"))
+                .with_step(JustLogAction::new("Action1", design))
                 .with_step(
                     ConcurrencyBuilder::new()
                         .with_branch(
                             SequenceBuilder::new()
-                                .with_step(JustLogAction::new("Action2"))
-                                .with_step(JustLogAction::new("Action3"))
+                                .with_step(JustLogAction::new("Action2", design))
+                                .with_step(JustLogAction::new("Action3", design))
                                 .build(),
                         )
                         .with_branch(
                             SequenceBuilder::new()
-                                .with_step(JustLogAction::new("Action4"))
+                                .with_step(JustLogAction::new("Action4", design))
                                 .with_step(SyncBuilder::from_tag(&evt1, design.config()))
                                 .with_step(TriggerBuilder::from_tag(&evt1, design.config()))
-                                .with_step(JustLogAction::new("Action5"))
+                                .with_step(JustLogAction::new("Action5", design))
                                 .build(),
                         )
                         .build(design),
                 )
-                .with_step(JustLogAction::new("FinishAction"))
+                .with_step(JustLogAction::new("FinishAction", design))
                 .build(),
         );
 
@@ -68,17 +68,17 @@ fn shutdown_design_with_counter(name: &str, shutdown_tag: Tag, counter: ActionCo
     })?;
 
     // Create a program with actions
-    design.add_program(file!(), move |_design_instance, builder| {
+    design.add_program(file!(), move |design_instance, builder| {
         builder
             .with_run_action(
                 SequenceBuilder::new()
-                    .with_step(JustLogAction::new(action_name.clone()))
-                    .with_step(Invoke::from_tag(&execution_tag, _design_instance.config()))
+                    .with_step(JustLogAction::new(action_name.clone(), design_instance))
+                    .with_step(Invoke::from_tag(&execution_tag, design_instance.config()))
                     .build(),
             )
             .with_shutdown_event(shutdown_tag)
             .with_stop_action(
-                Invoke::from_tag(&stop_tag, _design_instance.config()),
+                Invoke::from_tag(&stop_tag, design_instance.config()),
                 std::time::Duration::from_secs(1),
             );
         Ok(())
@@ -92,15 +92,18 @@ fn shutdown_design(name: &str, shutdown_tag: Tag) -> Result<Design, CommonErrors
     let action_name = name.to_owned() + "::Action1";
     let stop_action_name = name.to_owned() + "::StopAction";
     // Create a program with actions
-    design.add_program(file!(), move |_design_instance, builder| {
+    design.add_program(file!(), move |design_instance, builder| {
         builder
             .with_run_action(
                 SequenceBuilder::new()
-                    .with_step(JustLogAction::new(action_name))
+                    .with_step(JustLogAction::new(action_name, design_instance))
                     .build(),
             )
             .with_shutdown_event(shutdown_tag)
-            .with_stop_action(JustLogAction::new(stop_action_name), std::time::Duration::from_secs(1));
+            .with_stop_action(
+                JustLogAction::new(stop_action_name, design_instance),
+                std::time::Duration::from_secs(1),
+            );
 
         Ok(())
     });
@@ -115,11 +118,11 @@ fn infinite_design() -> Result<Design, CommonErrors> {
         design.register_invoke_async("PendingIndefinitely".into(), async || ::core::future::pending().await)?;
 
     // Create a program with actions
-    design.add_program(file!(), move |_design_instance, builder| {
+    design.add_program(file!(), move |design_instance, builder| {
         builder.with_run_action(
             SequenceBuilder::new()
-                .with_step(JustLogAction::new("InfiniteDesign::Action1"))
-                .with_step(Invoke::from_tag(&pending_tag, _design_instance.config()))
+                .with_step(JustLogAction::new("InfiniteDesign::Action1", design_instance))
+                .with_step(Invoke::from_tag(&pending_tag, design_instance.config()))
                 .build(),
         );
 
@@ -113,25 +113,25 @@ fn sleep_under_load(sleep_duration_ms: u64, cpu_load: String) -> Result<Design,
     design.add_program(file!(), move |design, builder| {
         builder.with_run_action(
             SequenceBuilder::new()
-                .with_step(JustLogAction::new("StartAction"))
+                .with_step(JustLogAction::new("StartAction", design))
                 .with_step(
                     ConcurrencyBuilder::new()
-                        .with_branch(JustLogAction::new("Action1"))
+                        .with_branch(JustLogAction::new("Action1", design))
                         .with_branch(Invoke::from_tag(&sleep1_tag, design.config()))
                         .with_branch(Invoke::from_tag(&cpu_tag, design.config()))
                         .with_branch(Invoke::from_tag(&sleep2_tag, design.config()))
                         .build(design),
                 )
-                .with_step(JustLogAction::new("IntermediateAction"))
+                .with_step(JustLogAction::new("IntermediateAction", design))
                 .with_step(
                     ConcurrencyBuilder::new()
-                        .with_branch(JustLogAction::new("Action2"))
+                        .with_branch(JustLogAction::new("Action2", design))
                         .with_branch(Invoke::from_tag(&sleep3_tag, design.config()))
                         .with_branch(Invoke::from_tag(&sleep4_tag, design.config()))
                         .build(design),
                 )
                 .with_step(Invoke::from_tag(&sleep5_tag, design.config()))
-                .with_step(JustLogAction::new("FinishAction"))
+                .with_step(JustLogAction::new("FinishAction", design))
                 .build(),
         );
         Ok(())